@@ -0,0 +1,81 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{
+	types::{ReporterOf, ValueOf},
+	Pallet as TellorRemote,
+};
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::{ensure, traits::EnsureOrigin, BoundedVec};
+use sp_std::vec;
+use tellor::QueryId;
+
+type RuntimeOrigin<T> = <T as frame_system::Config>::RuntimeOrigin;
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn tellor<T: Config>() -> Result<RuntimeOrigin<T>, BenchmarkError> {
+	// The successful origin must resolve to the configured location of the Tellor pallet
+	let origin =
+		T::TellorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let location =
+		T::TellorOrigin::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+	ensure!(location == T::TellorLocation::get(), BenchmarkError::Weightless);
+	Ok(origin)
+}
+
+benchmarks! {
+	report_value {
+		// Maximum value length
+		let v in 1..T::MaxValueLength::get();
+		let caller = tellor::<T>()?;
+		let query_id = QueryId::repeat_byte(1);
+		let reporter = account::<ReporterOf<T>>("account", 1, SEED);
+		// Worst case: a previous value is checked for ordering
+		TellorRemote::<T>::report_value(
+			caller.clone(),
+			query_id,
+			1,
+			BoundedVec::try_from(vec![1u8; v as usize]).unwrap(),
+			reporter.clone())?;
+		let value: ValueOf<T> = BoundedVec::try_from(vec![1u8; v as usize]).unwrap();
+	}: _<RuntimeOrigin<T>>(caller, query_id, 2, value.clone(), reporter.clone())
+	verify {
+		assert_last_event::<T>(Event::ValueReported { query_id, timestamp: 2, value, reporter }.into());
+	}
+
+	remove_value {
+		let caller = tellor::<T>()?;
+		let query_id = QueryId::repeat_byte(1);
+		let reporter = account::<ReporterOf<T>>("account", 1, SEED);
+		TellorRemote::<T>::report_value(
+			caller.clone(),
+			query_id,
+			1,
+			BoundedVec::try_from(vec![1u8; T::MaxValueLength::get() as usize]).unwrap(),
+			reporter)?;
+	}: _<RuntimeOrigin<T>>(caller, query_id, 1)
+	verify {
+		assert_last_event::<T>(Event::ValueRemoved { query_id, timestamp: 1 }.into());
+	}
+
+	impl_benchmark_test_suite!(TellorRemote, crate::mock::new_test_ext(), crate::mock::Test);
+}
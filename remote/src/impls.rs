@@ -0,0 +1,319 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::types::{ReporterOf, ValueOf};
+use frame_support::{
+	ensure,
+	pallet_prelude::DispatchResult,
+	traits::{EnsureOrigin, UnixTime},
+};
+use frame_system::pallet_prelude::OriginFor;
+use sp_runtime::traits::{Convert, Get, Saturating};
+use sp_std::vec::Vec;
+use tellor::{
+	traits::UsingTellor, BytesToU256, QueryHealth, QueryId, ReadPolicy, ReadRejection, ReadValue,
+	SafeValue, Timestamp, U256,
+};
+
+impl<T: Config> Pallet<T> {
+	/// Ensures that the origin is the Tellor pallet at the configured location.
+	/// # Arguments
+	/// * `origin` - The origin of the call.
+	/// # Returns
+	/// An error if the origin is not the Tellor pallet.
+	pub(super) fn ensure_tellor(origin: OriginFor<T>) -> DispatchResult {
+		let location = T::TellorOrigin::ensure_origin(origin)?;
+		ensure!(location == T::TellorLocation::get(), Error::<T>::InvalidOrigin);
+		Ok(())
+	}
+
+	/// Retrieves the latest value for the query identifier before the specified timestamp.
+	/// # Arguments
+	/// * `query_id` - The query identifier to look up the value for.
+	/// * `timestamp` - The timestamp before which to search for the latest value.
+	/// # Returns
+	/// The value retrieved and its timestamp, if found.
+	pub fn get_data_before(
+		query_id: QueryId,
+		timestamp: Timestamp,
+	) -> Option<(ValueOf<T>, Timestamp)> {
+		Self::get_index_for_data_before(query_id, timestamp)
+			.and_then(|index| <ReportedValues<T>>::get(query_id, index))
+			.map(|reported| (reported.value, reported.timestamp))
+	}
+
+	/// Retrieves the earliest index of a non-disputed value after the specified timestamp for the
+	/// query identifier.
+	/// # Arguments
+	/// * `query_id` - The query identifier to look up the index for.
+	/// * `timestamp` - The timestamp after which to search for the earliest index.
+	/// # Returns
+	/// The earliest index after the specified timestamp, if found within the maximum sequential
+	/// disputed values.
+	pub fn get_index_for_data_after(query_id: QueryId, timestamp: Timestamp) -> Option<u32> {
+		let count = <ReportedTimestampCount<T>>::get(query_id);
+		let start = Self::partition(query_id, count, |t| t <= timestamp);
+		// Iterate forward until a non-disputed value is found, skipping at most the maximum
+		// sequential disputed values
+		let end = start
+			.saturating_add(T::MaxDisputedTimeSeries::get().saturating_add(1))
+			.min(count);
+		(start..end).find(|index| !Self::is_disputed_at(query_id, *index))
+	}
+
+	/// Retrieves the latest index of a non-disputed value before the specified timestamp for the
+	/// query identifier.
+	/// # Arguments
+	/// * `query_id` - The query identifier to look up the index for.
+	/// * `timestamp` - The timestamp before which to search for the latest index.
+	/// # Returns
+	/// The latest index before the specified timestamp, if found within the maximum sequential
+	/// disputed values.
+	pub fn get_index_for_data_before(query_id: QueryId, timestamp: Timestamp) -> Option<u32> {
+		let count = <ReportedTimestampCount<T>>::get(query_id);
+		let end = Self::partition(query_id, count, |t| t < timestamp);
+		// Iterate backward until a non-disputed value is found, skipping at most the maximum
+		// sequential disputed values
+		let start = end.saturating_sub(T::MaxDisputedTimeSeries::get().saturating_add(1));
+		(start..end).rev().find(|index| !Self::is_disputed_at(query_id, *index))
+	}
+
+	/// Retrieves the latest value for the query identifier which satisfies the supplied read policy.
+	/// Open disputes are not known to this parachain, so `max_open_disputes` is not enforced, with
+	/// disputed values instead excluded once their removal is received.
+	/// # Arguments
+	/// * `query_id` - The query identifier to look up the value for.
+	/// * `policy` - The dispute buffer, maximum age and minimum distinct reporters the value must
+	/// satisfy.
+	/// # Returns
+	/// The value along with its timestamp and reporter, or the reason the value was rejected.
+	pub fn get_safe_value(
+		query_id: QueryId,
+		policy: ReadPolicy,
+	) -> Result<SafeValue<ReporterOf<T>>, ReadRejection> {
+		// Retrieve data at least as old as the dispute buffer to allow time for disputes
+		let now = Self::now();
+		let mut index =
+			Self::get_index_for_data_before(query_id, now.saturating_sub(policy.dispute_buffer))
+				.ok_or(ReadRejection::NoValue)?;
+		let reported = <ReportedValues<T>>::get(query_id, index).ok_or(ReadRejection::NoValue)?;
+		ensure!(
+			now.saturating_sub(reported.timestamp) < policy.max_age,
+			ReadRejection::Stale { timestamp: reported.timestamp }
+		);
+
+		// Count distinct reporters of non-disputed values within the window
+		let oldest = now.saturating_sub(policy.max_age);
+		let mut reporters = Vec::from([reported.reporter.clone()]);
+		let mut inspected = 0;
+		while (reporters.len() as u32) < policy.min_reporters
+			&& index > 0
+			&& inspected < MAX_READ_POLICY_VALUES
+		{
+			index.saturating_dec();
+			inspected.saturating_inc();
+			let Some(previous) = <ReportedValues<T>>::get(query_id, index) else {
+				break;
+			};
+			if previous.timestamp <= oldest {
+				break;
+			}
+			if !previous.is_disputed && !reporters.contains(&previous.reporter) {
+				reporters.push(previous.reporter);
+			}
+		}
+		ensure!(
+			reporters.len() as u32 >= policy.min_reporters,
+			ReadRejection::InsufficientReporters { reporters: reporters.len() as u32 }
+		);
+
+		Ok(SafeValue {
+			value: reported.value.into_inner(),
+			timestamp: reported.timestamp,
+			reporter: reported.reporter,
+		})
+	}
+
+	/// Returns whether the value at an index has been disputed.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the value.
+	/// * `index` - The index of the value.
+	/// # Returns
+	/// Whether the value has been disputed.
+	fn is_disputed_at(query_id: QueryId, index: u32) -> bool {
+		<ReportedValues<T>>::get(query_id, index).map_or(false, |reported| reported.is_disputed)
+	}
+
+	/// Returns the duration since UNIX_EPOCH, in seconds.
+	pub(super) fn now() -> Timestamp {
+		// Use seconds to match the Tellor pallet
+		T::Time::now().as_secs()
+	}
+
+	/// Binary searches the values received for the query identifier, which are ordered by
+	/// timestamp.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the values.
+	/// * `count` - The number of values received.
+	/// * `predicate` - Predicate which holds for the timestamps of all values before the partition
+	/// point, and none after.
+	/// # Returns
+	/// The index of the first value for which the predicate does not hold.
+	fn partition(query_id: QueryId, count: u32, predicate: impl Fn(Timestamp) -> bool) -> u32 {
+		let (mut start, mut end) = (0, count);
+		while start < end {
+			let middle = start.saturating_add(end.saturating_sub(start) / 2);
+			match <ReportedValues<T>>::get(query_id, middle) {
+				Some(reported) if predicate(reported.timestamp) => start = middle.saturating_add(1),
+				_ => end = middle,
+			}
+		}
+		start
+	}
+}
+
+impl<T: Config> UsingTellor<ReporterOf<T>> for Pallet<T> {
+	fn bytes_to_uint(bytes: Vec<u8>) -> Option<U256> {
+		BytesToU256::convert(bytes)
+	}
+
+	fn get_data_after(query_id: QueryId, timestamp: Timestamp) -> Option<(Vec<u8>, Timestamp)> {
+		Self::get_index_for_data_after(query_id, timestamp)
+			.and_then(|index| <ReportedValues<T>>::get(query_id, index))
+			.map(|reported| (reported.value.into_inner(), reported.timestamp))
+	}
+
+	fn get_data_before(query_id: QueryId, timestamp: Timestamp) -> Option<(Vec<u8>, Timestamp)> {
+		Self::get_data_before(query_id, timestamp).map(|(v, t)| (v.into_inner(), t))
+	}
+
+	fn get_derived_value(
+		_query_id: QueryId,
+		_policy: ReadPolicy,
+	) -> Result<(Vec<u8>, Timestamp), ReadRejection> {
+		// Derived feeds are not pushed by the Tellor pallet
+		Err(ReadRejection::NotDerivedFeed)
+	}
+
+	fn get_finalized_value_before(
+		query_id: QueryId,
+		timestamp: Timestamp,
+	) -> Option<(Vec<u8>, Timestamp)> {
+		// Values are final once they can no longer be disputed, provided any removal has been
+		// received
+		let finalized = Self::now().saturating_sub(T::FinalityPeriod::get());
+		Self::get_data_before(query_id, timestamp.min(finalized.saturating_add(1)))
+			.map(|(v, t)| (v.into_inner(), t))
+	}
+
+	fn get_first_retained_index(_query_id: QueryId) -> u32 {
+		// Values received are never pruned
+		0
+	}
+
+	fn get_index_for_data_after(query_id: QueryId, timestamp: Timestamp) -> Option<u32> {
+		Self::get_index_for_data_after(query_id, timestamp)
+	}
+
+	fn get_index_for_data_before(query_id: QueryId, timestamp: Timestamp) -> Option<u32> {
+		Self::get_index_for_data_before(query_id, timestamp)
+	}
+
+	fn get_multiple_values_before(
+		query_id: QueryId,
+		timestamp: Timestamp,
+		max_age: Timestamp,
+		max_count: u32,
+	) -> Vec<(Vec<u8>, Timestamp)> {
+		let Some(mut index) = Self::get_index_for_data_before(query_id, timestamp) else {
+			// no value before timestamp
+			return Vec::default();
+		};
+		let after = timestamp.saturating_sub(max_age);
+		let max_count = max_count as usize;
+		let mut result = Vec::with_capacity(max_count);
+		// generate array of non-disputed values within range
+		while result.len() < max_count {
+			let Some(reported) = <ReportedValues<T>>::get(query_id, index) else {
+				break;
+			};
+			if reported.timestamp <= after {
+				break;
+			}
+			if !reported.is_disputed {
+				result.push((reported.value.into_inner(), reported.timestamp));
+			}
+			let Some(previous) = index.checked_sub(1) else {
+				break;
+			};
+			index = previous;
+		}
+		// reverse order, oldest first
+		result.reverse();
+		result
+	}
+
+	fn get_new_value_count_by_query_id(query_id: QueryId) -> u32 {
+		<ReportedTimestampCount<T>>::get(query_id)
+	}
+
+	fn get_query_health(_query_id: QueryId) -> QueryHealth {
+		// Health thresholds are not pushed by the Tellor pallet
+		QueryHealth::Healthy
+	}
+
+	fn get_reporter_by_timestamp(query_id: QueryId, timestamp: Timestamp) -> Option<ReporterOf<T>> {
+		<Reports<T>>::get(query_id, timestamp)
+			.and_then(|index| <ReportedValues<T>>::get(query_id, index))
+			.map(|reported| reported.reporter)
+	}
+
+	fn get_safe_value(
+		query_id: QueryId,
+		policy: ReadPolicy,
+	) -> Result<SafeValue<ReporterOf<T>>, ReadRejection> {
+		Self::get_safe_value(query_id, policy)
+	}
+
+	fn get_safe_value_or_emergency(
+		query_id: QueryId,
+		policy: ReadPolicy,
+	) -> Result<ReadValue<ReporterOf<T>>, ReadRejection> {
+		// Emergency values are not pushed by the Tellor pallet
+		Self::get_safe_value(query_id, policy).map(ReadValue::Reported)
+	}
+
+	fn get_timestamp_by_query_id_and_index(query_id: QueryId, index: u32) -> Option<Timestamp> {
+		<ReportedValues<T>>::get(query_id, index).map(|reported| reported.timestamp)
+	}
+
+	fn is_in_dispute(query_id: QueryId, timestamp: Timestamp) -> bool {
+		<Reports<T>>::get(query_id, timestamp)
+			.map_or(false, |index| Self::is_disputed_at(query_id, index))
+	}
+
+	fn now() -> Timestamp {
+		Self::now()
+	}
+
+	fn retrieve_data(query_id: QueryId, timestamp: Timestamp) -> Option<Vec<u8>> {
+		<Reports<T>>::get(query_id, timestamp)
+			.and_then(|index| <ReportedValues<T>>::get(query_id, index))
+			.filter(|reported| !reported.is_disputed)
+			.map(|reported| reported.value.into_inner())
+	}
+}
@@ -0,0 +1,194 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+pub use types::ReportedValue;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod impls;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+mod types;
+pub mod weights;
+
+/// The maximum number of values inspected when counting distinct reporters within a read window.
+pub(crate) const MAX_READ_POLICY_VALUES: u32 = 50;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::{
+		types::{ReportedValue, ReportedValueOf, ReporterOf, ValueOf},
+		weights::WeightInfo,
+	};
+	use frame_support::{pallet_prelude::*, traits::UnixTime};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::ArithmeticError;
+	use tellor::{QueryId, Timestamp};
+	use xcm::latest::prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The amount of time after which a value can no longer be disputed on the Tellor parachain,
+		/// being its reporting lock.
+		#[pallet::constant]
+		type FinalityPeriod: Get<Timestamp>;
+		/// The maximum number of sequential disputed values skipped when searching for a value,
+		/// e.g. the maximum sequential disputed timestamps of the Tellor parachain.
+		#[pallet::constant]
+		type MaxDisputedTimeSeries: Get<u32>;
+		/// The maximum length of an individual value received.
+		#[pallet::constant]
+		type MaxValueLength: Get<u32>;
+		/// The type identifying reporters of values received, being the `AccountId` type of the
+		/// Tellor parachain.
+		type Reporter: Parameter + MaxEncodedLen;
+		/// The location of the Tellor pallet, from which values are accepted, e.g.
+		/// `(1, X2(Parachain(id), PalletInstance(index)))`.
+		#[pallet::constant]
+		type TellorLocation: Get<MultiLocation>;
+		/// Origin resolving the location of calls dispatched via XCM, e.g. `pallet_xcm::EnsureXcm`.
+		type TellorOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = MultiLocation>;
+		/// The on-chain time provider.
+		type Time: UnixTime;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Mapping of received timestamp count by query identifier.
+	#[pallet::storage]
+	pub(super) type ReportedTimestampCount<T> = StorageMap<_, Identity, QueryId, u32, ValueQuery>;
+	/// Mapping of received values, along with their timestamps, reporters and whether disputed, by
+	/// query identifier and index.
+	#[pallet::storage]
+	pub(super) type ReportedValues<T> =
+		StorageDoubleMap<_, Identity, QueryId, Twox64Concat, u32, ReportedValueOf<T>>;
+	/// Mapping of the indices of received values by query identifier and timestamp.
+	#[pallet::storage]
+	pub(super) type Reports<T> =
+		StorageDoubleMap<_, Identity, QueryId, Blake2_128Concat, Timestamp, u32>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Emitted when a value is received from the Tellor parachain.
+		ValueReported {
+			query_id: QueryId,
+			timestamp: Timestamp,
+			value: ValueOf<T>,
+			reporter: ReporterOf<T>,
+		},
+		/// Emitted when a value is removed, having been disputed on the Tellor parachain.
+		ValueRemoved { query_id: QueryId, timestamp: Timestamp },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The call was not dispatched by the Tellor pallet.
+		InvalidOrigin,
+		/// The value is older than the latest value received.
+		ValueOutOfOrder,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Receives a value pushed by the Tellor pallet to a subscription of this parachain.
+		///
+		/// - `query_id`: The query identifier of the value.
+		/// - `timestamp`: The timestamp of the value.
+		/// - `value`: The value reported.
+		/// - `reporter`: The reporter of the value.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::report_value(value.len() as u32))]
+		pub fn report_value(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			timestamp: Timestamp,
+			value: ValueOf<T>,
+			reporter: ReporterOf<T>,
+		) -> DispatchResult {
+			Self::ensure_tellor(origin)?;
+			// Ignore values already received, such as those pushed to multiple subscriptions
+			if <Reports<T>>::contains_key(query_id, timestamp) {
+				return Ok(());
+			}
+			let index = <ReportedTimestampCount<T>>::get(query_id);
+			if let Some(last) =
+				index.checked_sub(1).and_then(|i| <ReportedValues<T>>::get(query_id, i))
+			{
+				ensure!(timestamp > last.timestamp, Error::<T>::ValueOutOfOrder);
+			}
+			<ReportedValues<T>>::insert(
+				query_id,
+				index,
+				ReportedValue {
+					timestamp,
+					is_disputed: false,
+					value: value.clone(),
+					reporter: reporter.clone(),
+				},
+			);
+			<Reports<T>>::insert(query_id, timestamp, index);
+			<ReportedTimestampCount<T>>::insert(
+				query_id,
+				index.checked_add(1).ok_or(ArithmeticError::Overflow)?,
+			);
+			Self::deposit_event(Event::ValueReported { query_id, timestamp, value, reporter });
+			Ok(())
+		}
+
+		/// Removes a value pushed by the Tellor pallet, having been disputed on the Tellor parachain.
+		///
+		/// - `query_id`: The query identifier of the value.
+		/// - `timestamp`: The timestamp of the value.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_value())]
+		pub fn remove_value(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			timestamp: Timestamp,
+		) -> DispatchResult {
+			Self::ensure_tellor(origin)?;
+			// Ignore removals of values not received, such as those skipped by the subscription
+			let Some(index) = <Reports<T>>::get(query_id, timestamp) else {
+				return Ok(());
+			};
+			let removed = <ReportedValues<T>>::mutate(query_id, index, |reported| match reported {
+				Some(reported) if !reported.is_disputed => {
+					reported.is_disputed = true;
+					reported.value = BoundedVec::default();
+					true
+				},
+				_ => false,
+			});
+			if removed {
+				Self::deposit_event(Event::ValueRemoved { query_id, timestamp });
+			}
+			Ok(())
+		}
+	}
+}
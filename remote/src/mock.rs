@@ -0,0 +1,162 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use crate as tellor_remote;
+use frame_support::{
+	assert_ok,
+	traits::{ConstU16, ConstU32, ConstU64, EnsureOrigin, OnFinalize, UnixTime},
+};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use xcm::latest::prelude::*;
+
+pub(crate) type AccountId = u64;
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) const TELLOR_PALLET_INDEX: u8 = 3;
+pub(crate) const TELLOR_PARA_ID: u32 = 3000;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		TellorRemote: tellor_remote,
+	}
+);
+
+impl system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+frame_support::parameter_types! {
+	pub TellorLocation: MultiLocation =
+		MultiLocation::new(1, X2(Parachain(TELLOR_PARA_ID), PalletInstance(TELLOR_PALLET_INDEX)));
+}
+
+impl tellor_remote::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type FinalityPeriod = ConstU64<{ 12 * 60 * 60 }>;
+	type MaxDisputedTimeSeries = ConstU32<2>;
+	type MaxValueLength = ConstU32<256>;
+	type Reporter = AccountId;
+	type TellorLocation = TellorLocation;
+	type TellorOrigin = EnsureLocation;
+	type Time = Timestamp;
+	type WeightInfo = ();
+}
+
+/// Maps signed origins to the Tellor pallet on the parachain with the account as identifier,
+/// simulating calls dispatched via XCM.
+pub struct EnsureLocation;
+impl EnsureOrigin<RuntimeOrigin> for EnsureLocation {
+	type Success = MultiLocation;
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		let origin: Result<system::RawOrigin<AccountId>, RuntimeOrigin> = o.into();
+		origin.and_then(|o| match o {
+			system::RawOrigin::Signed(account) => Ok(MultiLocation::new(
+				1,
+				X2(Parachain(account as u32), PalletInstance(TELLOR_PALLET_INDEX)),
+			)),
+			r => Err(RuntimeOrigin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::signed(TELLOR_PARA_ID.into()))
+	}
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+}
+
+/// Starts a new block, executing the supplied closure thereafter.
+pub(crate) fn with_block<R>(execute: impl FnOnce() -> R) -> R {
+	with_block_after(0, execute)
+}
+
+/// Starts a new block after some time, executing the supplied closure thereafter.
+pub(crate) fn with_block_after<R>(time_in_secs: u64, execute: impl FnOnce() -> R) -> R {
+	let block = System::block_number();
+	match block {
+		0 => {
+			System::set_block_number(1);
+			assert_ok!(Timestamp::set(
+				RuntimeOrigin::none(),
+				SystemTime::now()
+					.duration_since(UNIX_EPOCH)
+					.expect("Current time is always after unix epoch; qed")
+					.as_millis() as u64
+			));
+		},
+		_ => {
+			Timestamp::on_finalize(block);
+			System::set_block_number(block + 1);
+			assert_ok!(Timestamp::set(
+				RuntimeOrigin::none(),
+				(<Timestamp as UnixTime>::now() + Duration::from_secs(1 + time_in_secs)).as_millis()
+					as u64
+			));
+		},
+	}
+	let result = execute();
+	// Reset events after block executed, ensuring we only receive events for current block
+	System::reset_events();
+	result
+}
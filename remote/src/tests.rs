@@ -0,0 +1,307 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, Error, Event};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_core::H256;
+use sp_runtime::traits::BadOrigin;
+use tellor::{
+	traits::UsingTellor, QueryHealth, QueryId, ReadPolicy, ReadRejection, ReadValue, SafeValue,
+	Timestamp, HOURS, MINUTES,
+};
+
+const QUERY_ID: QueryId = H256([1; 32]);
+
+fn tellor() -> RuntimeOrigin {
+	RuntimeOrigin::signed(TELLOR_PARA_ID.into())
+}
+
+fn report(timestamp: Timestamp, value: u8, reporter: AccountId) {
+	assert_ok!(TellorRemote::report_value(
+		tellor(),
+		QUERY_ID,
+		timestamp,
+		BoundedVec::truncate_from(vec![value]),
+		reporter
+	));
+}
+
+fn now() -> Timestamp {
+	<TellorRemote as UsingTellor<AccountId>>::now()
+}
+
+#[test]
+fn report_value() {
+	new_test_ext().execute_with(|| {
+		with_block(|| {
+			let timestamp = now();
+			assert_ok!(TellorRemote::report_value(
+				tellor(),
+				QUERY_ID,
+				timestamp,
+				BoundedVec::truncate_from(vec![1]),
+				1
+			));
+			System::assert_last_event(
+				Event::ValueReported {
+					query_id: QUERY_ID,
+					timestamp,
+					value: BoundedVec::truncate_from(vec![1]),
+					reporter: 1,
+				}
+				.into(),
+			);
+			assert_eq!(TellorRemote::get_new_value_count_by_query_id(QUERY_ID), 1);
+			assert_eq!(TellorRemote::retrieve_data(QUERY_ID, timestamp), Some(vec![1]));
+			assert_eq!(TellorRemote::get_reporter_by_timestamp(QUERY_ID, timestamp), Some(1));
+			assert_eq!(
+				TellorRemote::get_timestamp_by_query_id_and_index(QUERY_ID, 0),
+				Some(timestamp)
+			);
+
+			// Values already received are ignored
+			System::reset_events();
+			report(timestamp, 2, 2);
+			assert!(System::events().is_empty());
+			assert_eq!(TellorRemote::get_new_value_count_by_query_id(QUERY_ID), 1);
+			assert_eq!(TellorRemote::retrieve_data(QUERY_ID, timestamp), Some(vec![1]));
+
+			// Values older than the latest value are rejected
+			assert_noop!(
+				TellorRemote::report_value(
+					tellor(),
+					QUERY_ID,
+					timestamp - 1,
+					BoundedVec::truncate_from(vec![2]),
+					2
+				),
+				Error::<Test>::ValueOutOfOrder
+			);
+		});
+	});
+}
+
+#[test]
+fn report_value_requires_tellor() {
+	new_test_ext().execute_with(|| {
+		with_block(|| {
+			let value = BoundedVec::truncate_from(vec![1]);
+			assert_noop!(
+				TellorRemote::report_value(
+					RuntimeOrigin::signed(TELLOR_PARA_ID as AccountId + 1),
+					QUERY_ID,
+					now(),
+					value.clone(),
+					1
+				),
+				Error::<Test>::InvalidOrigin
+			);
+			assert_noop!(
+				TellorRemote::report_value(RuntimeOrigin::root(), QUERY_ID, now(), value, 1),
+				BadOrigin
+			);
+			assert_noop!(
+				TellorRemote::remove_value(
+					RuntimeOrigin::signed(TELLOR_PARA_ID as AccountId + 1),
+					QUERY_ID,
+					now()
+				),
+				Error::<Test>::InvalidOrigin
+			);
+		});
+	});
+}
+
+#[test]
+fn remove_value() {
+	new_test_ext().execute_with(|| {
+		with_block(|| {
+			let timestamp = now();
+			report(timestamp - 2, 1, 1);
+			report(timestamp - 1, 2, 2);
+			report(timestamp, 3, 3);
+
+			assert_ok!(TellorRemote::remove_value(tellor(), QUERY_ID, timestamp));
+			System::assert_last_event(Event::ValueRemoved { query_id: QUERY_ID, timestamp }.into());
+			assert!(TellorRemote::is_in_dispute(QUERY_ID, timestamp));
+			assert_eq!(TellorRemote::retrieve_data(QUERY_ID, timestamp), None);
+			assert_eq!(TellorRemote::get_reporter_by_timestamp(QUERY_ID, timestamp), Some(3));
+			assert_eq!(TellorRemote::get_new_value_count_by_query_id(QUERY_ID), 3);
+			assert_eq!(
+				<TellorRemote as UsingTellor<AccountId>>::get_data_before(QUERY_ID, timestamp + 1),
+				Some((vec![2], timestamp - 1))
+			);
+
+			// Removals of values already removed or not received are ignored
+			System::reset_events();
+			assert_ok!(TellorRemote::remove_value(tellor(), QUERY_ID, timestamp));
+			assert_ok!(TellorRemote::remove_value(tellor(), QUERY_ID, timestamp + 1));
+			assert!(System::events().is_empty());
+		});
+	});
+}
+
+#[test]
+fn get_data_before_and_after() {
+	new_test_ext().execute_with(|| {
+		with_block(|| {
+			for (timestamp, value) in [(10, 1), (20, 2), (30, 3), (40, 4), (50, 5)] {
+				report(timestamp, value, value as AccountId);
+			}
+			assert_ok!(TellorRemote::remove_value(tellor(), QUERY_ID, 30));
+			assert_ok!(TellorRemote::remove_value(tellor(), QUERY_ID, 40));
+
+			assert_eq!(TellorRemote::get_index_for_data_before(QUERY_ID, 10), None);
+			assert_eq!(TellorRemote::get_index_for_data_before(QUERY_ID, 11), Some(0));
+			assert_eq!(TellorRemote::get_index_for_data_before(QUERY_ID, 45), Some(1));
+			assert_eq!(TellorRemote::get_index_for_data_before(QUERY_ID, 51), Some(4));
+			assert_eq!(
+				<TellorRemote as UsingTellor<AccountId>>::get_data_before(QUERY_ID, 50),
+				Some((vec![2], 20))
+			);
+
+			assert_eq!(TellorRemote::get_index_for_data_after(QUERY_ID, 0), Some(0));
+			assert_eq!(TellorRemote::get_index_for_data_after(QUERY_ID, 20), Some(4));
+			assert_eq!(TellorRemote::get_index_for_data_after(QUERY_ID, 50), None);
+			assert_eq!(
+				<TellorRemote as UsingTellor<AccountId>>::get_data_after(QUERY_ID, 10),
+				Some((vec![2], 20))
+			);
+
+			assert_eq!(
+				TellorRemote::get_multiple_values_before(QUERY_ID, 51, 45, 10),
+				vec![(vec![1], 10), (vec![2], 20), (vec![5], 50)]
+			);
+			assert_eq!(
+				TellorRemote::get_multiple_values_before(QUERY_ID, 51, 40, 10),
+				vec![(vec![2], 20), (vec![5], 50)]
+			);
+			assert_eq!(
+				TellorRemote::get_multiple_values_before(QUERY_ID, 50, 45, 1),
+				vec![(vec![2], 20)]
+			);
+			assert_eq!(TellorRemote::get_multiple_values_before(QUERY_ID, 10, 10, 10), vec![]);
+
+			// At most the maximum sequential disputed values are skipped
+			assert_ok!(TellorRemote::remove_value(tellor(), QUERY_ID, 20));
+			assert_eq!(TellorRemote::get_index_for_data_before(QUERY_ID, 45), None);
+			assert_eq!(TellorRemote::get_index_for_data_before(QUERY_ID, 51), Some(4));
+			assert_eq!(TellorRemote::get_index_for_data_after(QUERY_ID, 10), None);
+			assert_eq!(TellorRemote::get_index_for_data_after(QUERY_ID, 0), Some(0));
+		});
+	});
+}
+
+#[test]
+fn get_finalized_value_before() {
+	new_test_ext().execute_with(|| {
+		let timestamp = with_block(|| {
+			report(now(), 1, 1);
+			now()
+		});
+		with_block_after(12 * HOURS - 2, || {
+			report(now(), 2, 2);
+			assert_eq!(TellorRemote::get_finalized_value_before(QUERY_ID, now() + 1), None);
+		});
+		with_block(|| {
+			assert_eq!(
+				TellorRemote::get_finalized_value_before(QUERY_ID, now() + 1),
+				Some((vec![1], timestamp))
+			);
+			assert_eq!(TellorRemote::get_finalized_value_before(QUERY_ID, timestamp), None);
+		});
+	});
+}
+
+#[test]
+fn get_safe_value() {
+	new_test_ext().execute_with(|| {
+		with_block(|| {
+			let now = now();
+			report(now - 3 * HOURS, 1, 1);
+			report(now - 2 * HOURS, 2, 2);
+			report(now - HOURS, 3, 1);
+			report(now - MINUTES, 4, 3);
+
+			let policy = ReadPolicy {
+				dispute_buffer: 30 * MINUTES,
+				max_age: 4 * HOURS,
+				min_reporters: 1,
+				max_open_disputes: 0,
+			};
+			let safe_value = SafeValue { value: vec![3], timestamp: now - HOURS, reporter: 1 };
+			assert_eq!(TellorRemote::get_safe_value(QUERY_ID, policy), Ok(safe_value.clone()));
+			assert_eq!(
+				TellorRemote::get_safe_value_or_emergency(QUERY_ID, policy),
+				Ok(ReadValue::Reported(safe_value))
+			);
+			assert_eq!(
+				TellorRemote::get_safe_value(QUERY_ID, ReadPolicy { max_age: HOURS, ..policy }),
+				Err(ReadRejection::Stale { timestamp: now - HOURS })
+			);
+			assert_eq!(
+				TellorRemote::get_safe_value(QUERY_ID, ReadPolicy { min_reporters: 2, ..policy }),
+				Ok(SafeValue { value: vec![3], timestamp: now - HOURS, reporter: 1 })
+			);
+			assert_eq!(
+				TellorRemote::get_safe_value(
+					QUERY_ID,
+					ReadPolicy { max_age: 90 * MINUTES, min_reporters: 2, ..policy }
+				),
+				Err(ReadRejection::InsufficientReporters { reporters: 1 })
+			);
+
+			// Disputed values are excluded
+			assert_ok!(TellorRemote::remove_value(tellor(), QUERY_ID, now - 2 * HOURS));
+			assert_eq!(
+				TellorRemote::get_safe_value(QUERY_ID, ReadPolicy { min_reporters: 2, ..policy }),
+				Err(ReadRejection::InsufficientReporters { reporters: 1 })
+			);
+			assert_ok!(TellorRemote::remove_value(tellor(), QUERY_ID, now - HOURS));
+			assert_eq!(
+				TellorRemote::get_safe_value(QUERY_ID, policy),
+				Ok(SafeValue { value: vec![1], timestamp: now - 3 * HOURS, reporter: 1 })
+			);
+			assert_eq!(
+				TellorRemote::get_safe_value(
+					QUERY_ID,
+					ReadPolicy { dispute_buffer: 4 * HOURS, ..policy }
+				),
+				Err(ReadRejection::NoValue)
+			);
+		});
+	});
+}
+
+#[test]
+fn unsupported_features() {
+	new_test_ext().execute_with(|| {
+		with_block(|| {
+			let policy = ReadPolicy {
+				dispute_buffer: 0,
+				max_age: HOURS,
+				min_reporters: 1,
+				max_open_disputes: 0,
+			};
+			assert_eq!(
+				TellorRemote::get_derived_value(QUERY_ID, policy),
+				Err(ReadRejection::NotDerivedFeed)
+			);
+			assert_eq!(TellorRemote::get_first_retained_index(QUERY_ID), 0);
+			assert_eq!(TellorRemote::get_query_health(QUERY_ID), QueryHealth::Healthy);
+		});
+	});
+}
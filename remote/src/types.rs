@@ -0,0 +1,38 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{BoundedVec, RuntimeDebug};
+use scale_info::TypeInfo;
+use tellor::Timestamp;
+
+pub(crate) type ReportedValueOf<T> = ReportedValue<ReporterOf<T>, ValueOf<T>>;
+pub(crate) type ReporterOf<T> = <T as crate::Config>::Reporter;
+pub(crate) type ValueOf<T> = BoundedVec<u8, <T as crate::Config>::MaxValueLength>;
+
+/// A value received from the Tellor parachain along with its timestamp, reporter and whether it has
+/// been disputed, stored by index.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ReportedValue<AccountId, Value> {
+	/// The time the value was reported.
+	pub(crate) timestamp: Timestamp,
+	/// Whether the reported value has been disputed.
+	pub(crate) is_disputed: bool,
+	/// The reported value, which is cleared when disputed.
+	pub(crate) value: Value,
+	/// The reporter of the value.
+	pub(crate) reporter: AccountId,
+}
@@ -0,0 +1,107 @@
+//! Autogenerated weights for `tellor_remote`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-09-20, STEPS: `25`, REPEAT: `10`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `PAR03017`, CPU: `<UNKNOWN>`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+
+// Executed Command:
+// ./target/release/parachain-template-node
+// benchmark
+// pallet
+// --chain=dev
+// --execution=wasm
+// --wasm-execution
+// compiled
+// --pallet=tellor_remote
+// --extrinsic=*
+// --steps=25
+// --repeat=10
+// --output=../tellor-pallet/remote/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `tellor_remote`.
+pub trait WeightInfo {
+	fn report_value(v: u32, ) -> Weight;
+	fn remove_value() -> Weight;
+}
+
+/// Weights for `tellor_remote` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: TellorRemote Reports (r:1 w:1)
+	/// Proof: TellorRemote Reports (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TellorRemote ReportedTimestampCount (r:1 w:1)
+	/// Proof: TellorRemote ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: TellorRemote ReportedValues (r:1 w:1)
+	/// Proof: TellorRemote ReportedValues (max_values: None, max_size: Some(343), added: 2818, mode: MaxEncodedLen)
+	/// The range of component `v` is `[1, 256]`.
+	fn report_value(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `391`
+		//  Estimated: `3808`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_106_283, 3808)
+			// Standard Error: 1_020
+			.saturating_add(Weight::from_parts(1_941, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: TellorRemote Reports (r:1 w:0)
+	/// Proof: TellorRemote Reports (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TellorRemote ReportedValues (r:1 w:1)
+	/// Proof: TellorRemote ReportedValues (max_values: None, max_size: Some(343), added: 2818, mode: MaxEncodedLen)
+	fn remove_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463`
+		//  Estimated: `3808`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3808)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: TellorRemote Reports (r:1 w:1)
+	/// Proof: TellorRemote Reports (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TellorRemote ReportedTimestampCount (r:1 w:1)
+	/// Proof: TellorRemote ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: TellorRemote ReportedValues (r:1 w:1)
+	/// Proof: TellorRemote ReportedValues (max_values: None, max_size: Some(343), added: 2818, mode: MaxEncodedLen)
+	/// The range of component `v` is `[1, 256]`.
+	fn report_value(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `391`
+		//  Estimated: `3808`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_106_283, 3808)
+			// Standard Error: 1_020
+			.saturating_add(Weight::from_parts(1_941, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: TellorRemote Reports (r:1 w:0)
+	/// Proof: TellorRemote Reports (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TellorRemote ReportedValues (r:1 w:1)
+	/// Proof: TellorRemote ReportedValues (max_values: None, max_size: Some(343), added: 2818, mode: MaxEncodedLen)
+	fn remove_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463`
+		//  Estimated: `3808`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3808)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,189 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interfaces serving the archived history of Tellor oracle values from offchain storage, and
+//! reported values together with storage proofs.
+//!
+//! Reports, along with values noted as preimages for large value queries, are written to offchain
+//! storage by the pallet via offchain indexing, so remain available after being pruned from the
+//! oracle. The node must be started with offchain indexing enabled
+//! (`--enable-offchain-indexing=true`).
+
+use codec::Decode;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{Deserialize, Serialize};
+use sp_core::{
+	offchain::{storage::OffchainDb, DbExternalities, OffchainStorage, StorageKind},
+	Bytes,
+};
+use std::marker::PhantomData;
+use tellor::{
+	indexing::{preimage_key, report_key},
+	ArchivedReport, PreimageHash, QueryId, Timestamp,
+};
+
+pub use proofs::{TellorProofApiClient, TellorProofApiServer, TellorProofs, ValueWithProof};
+
+mod proofs;
+#[cfg(test)]
+mod tests;
+
+/// The maximum number of reports returned by a single request.
+pub const MAX_REPORTS: u32 = 1_000;
+
+/// Error code returned when an archived report cannot be decoded.
+const DECODE_ERROR: i32 = 1;
+/// Error code returned when too many reports are requested.
+const LIMIT_ERROR: i32 = 2;
+/// Error code returned when a storage proof cannot be generated.
+const PROOF_ERROR: i32 = 3;
+/// Error code returned when the runtime cannot be queried.
+const RUNTIME_ERROR: i32 = 4;
+
+/// A reported value archived to offchain storage.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Report<AccountId> {
+	/// The respective index of the reported value.
+	pub index: u32,
+	/// The time the value was reported.
+	pub timestamp: Timestamp,
+	/// The reported value.
+	pub value: Bytes,
+	/// The reporter of the value.
+	pub reporter: AccountId,
+	/// Whether the reported value has been disputed.
+	pub is_disputed: bool,
+}
+
+#[rpc(client, server)]
+pub trait TellorArchiveApi<AccountId> {
+	/// Returns an archived report.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported value.
+	/// * `index` - The index of the reported value.
+	/// # Returns
+	/// The archived report, if found.
+	#[method(name = "tellor_getArchivedReport")]
+	fn get_archived_report(
+		&self,
+		query_id: QueryId,
+		index: u32,
+	) -> RpcResult<Option<Report<AccountId>>>;
+
+	/// Returns consecutive archived reports, starting from an index.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported values.
+	/// * `start` - The index of the first reported value.
+	/// * `count` - The maximum number of reports to return, up to [`MAX_REPORTS`].
+	/// # Returns
+	/// The archived reports, ordered by index, ending at the first index not found.
+	#[method(name = "tellor_getArchivedReports")]
+	fn get_archived_reports(
+		&self,
+		query_id: QueryId,
+		start: u32,
+		count: u32,
+	) -> RpcResult<Vec<Report<AccountId>>>;
+
+	/// Returns an archived value noted as a preimage, as referenced by values reported for large
+	/// value queries.
+	/// # Arguments
+	/// * `hash` - The hash of the noted value.
+	/// # Returns
+	/// The archived value, if found.
+	#[method(name = "tellor_getArchivedPreimage")]
+	fn get_archived_preimage(&self, hash: PreimageHash) -> RpcResult<Option<Bytes>>;
+}
+
+/// Serves archived reports from the offchain storage of the node.
+pub struct TellorArchive<S, AccountId> {
+	storage: S,
+	_marker: PhantomData<AccountId>,
+}
+
+impl<S, AccountId> TellorArchive<S, AccountId> {
+	/// Creates a new instance, reading from the supplied offchain storage.
+	pub fn new(storage: S) -> Self {
+		Self { storage, _marker: PhantomData }
+	}
+}
+
+impl<S: OffchainStorage, AccountId: Decode> TellorArchive<S, AccountId> {
+	fn report(&self, query_id: QueryId, index: u32) -> RpcResult<Option<Report<AccountId>>> {
+		let Some(encoded) = OffchainDb::new(self.storage.clone())
+			.local_storage_get(StorageKind::PERSISTENT, &report_key(query_id, index))
+		else {
+			return Ok(None);
+		};
+		let report = ArchivedReport::<AccountId, Vec<u8>>::decode(&mut encoded.as_slice())
+			.map_err(|e| error(DECODE_ERROR, "Unable to decode archived report", e))?;
+		Ok(Some(Report {
+			index,
+			timestamp: report.timestamp,
+			value: report.value.into(),
+			reporter: report.reporter,
+			is_disputed: report.is_disputed,
+		}))
+	}
+}
+
+impl<S, AccountId> TellorArchiveApiServer<AccountId> for TellorArchive<S, AccountId>
+where
+	S: OffchainStorage + 'static,
+	AccountId: Decode + Serialize + Send + Sync + 'static,
+{
+	fn get_archived_report(
+		&self,
+		query_id: QueryId,
+		index: u32,
+	) -> RpcResult<Option<Report<AccountId>>> {
+		self.report(query_id, index)
+	}
+
+	fn get_archived_reports(
+		&self,
+		query_id: QueryId,
+		start: u32,
+		count: u32,
+	) -> RpcResult<Vec<Report<AccountId>>> {
+		if count > MAX_REPORTS {
+			return Err(error(LIMIT_ERROR, "Too many reports requested", count));
+		}
+		let mut reports = Vec::new();
+		for index in start..start.saturating_add(count) {
+			match self.report(query_id, index)? {
+				Some(report) => reports.push(report),
+				None => break,
+			}
+		}
+		Ok(reports)
+	}
+
+	fn get_archived_preimage(&self, hash: PreimageHash) -> RpcResult<Option<Bytes>> {
+		Ok(OffchainDb::new(self.storage.clone())
+			.local_storage_get(StorageKind::PERSISTENT, &preimage_key(hash))
+			.map(Into::into))
+	}
+}
+
+fn error(code: i32, message: &str, data: impl ToString) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(code, message, Some(data.to_string()))).into()
+}
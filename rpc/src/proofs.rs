@@ -0,0 +1,183 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface serving reported values together with storage proofs against the state root, so
+//! that other chains can verify a value without trusting the node serving it.
+//!
+//! Values are read via the runtime API of the `tellor-runtime-api` crate, after which a proof of the
+//! storage keys of the value is generated at the same block, along with the last reported value and
+//! any values reported after the value up to the requested timestamp, so that the value is proven
+//! to be the latest. Proofs are verified using `tellor::proofs::verify`, which recomputes the
+//! storage keys from the query identifier and timestamps.
+
+use crate::{error, PROOF_ERROR, RUNTIME_ERROR};
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sc_client_api::ProofProvider;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+use std::{marker::PhantomData, sync::Arc};
+use tellor::{
+	proofs::{
+		indexed_value_key, last_reported_key, legacy_reported_value_key, legacy_timestamp_key,
+		migration_in_progress_key, report_key, reported_value_key,
+	},
+	QueryId, Timestamp,
+};
+use tellor_runtime_api::TellorOracle;
+
+/// A reported value along with a storage proof of the value.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValueWithProof<BlockHash, AccountId> {
+	/// The hash of the block at which the proof was generated, against whose state root the proof
+	/// is verified.
+	pub at: BlockHash,
+	/// The reported value.
+	pub value: Bytes,
+	/// The time the value was reported.
+	pub timestamp: Timestamp,
+	/// The reporter of the value.
+	pub reporter: AccountId,
+	/// The trie nodes of the storage proof of the report and reported value, along with the values
+	/// reported after it up to the requested timestamp.
+	pub proof: Vec<Bytes>,
+}
+
+#[rpc(client, server)]
+pub trait TellorProofApi<BlockHash, AccountId> {
+	/// Retrieves the latest value for the query identifier before the specified timestamp, along
+	/// with a storage proof that the value is the latest.
+	/// # Arguments
+	/// * `query_id` - The query identifier whose value is to be retrieved.
+	/// * `timestamp` - The timestamp before which to search for the latest value.
+	/// * `at` - The hash of the block at which to retrieve the value, defaulting to the best block.
+	/// # Returns
+	/// The value retrieved, along with its timestamp, reporter and storage proof, if found.
+	#[method(name = "tellor_getDataBeforeWithProof")]
+	fn get_data_before_with_proof(
+		&self,
+		query_id: QueryId,
+		timestamp: Timestamp,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ValueWithProof<BlockHash, AccountId>>>;
+}
+
+/// Serves reported values along with storage proofs, using the runtime API of the client.
+pub struct TellorProofs<C, Block, AccountId, BlockNumber, StakeInfo, Value> {
+	client: Arc<C>,
+	pallet_name: Vec<u8>,
+	_marker: PhantomData<(Block, AccountId, BlockNumber, StakeInfo, Value)>,
+}
+
+impl<C, Block, AccountId, BlockNumber, StakeInfo, Value>
+	TellorProofs<C, Block, AccountId, BlockNumber, StakeInfo, Value>
+{
+	/// Creates a new instance.
+	/// # Arguments
+	/// * `client` - The client used to read values and generate proofs.
+	/// * `pallet_name` - The name of the pallet within the runtime, from which storage keys are
+	///   derived.
+	pub fn new(client: Arc<C>, pallet_name: &str) -> Self {
+		Self { client, pallet_name: pallet_name.as_bytes().to_vec(), _marker: PhantomData }
+	}
+}
+
+impl<C, Block, AccountId, BlockNumber, StakeInfo, Value>
+	TellorProofApiServer<Block::Hash, AccountId>
+	for TellorProofs<C, Block, AccountId, BlockNumber, StakeInfo, Value>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>
+		+ HeaderBackend<Block>
+		+ ProofProvider<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+	C::Api: TellorOracle<Block, AccountId, BlockNumber, StakeInfo, Value>,
+	AccountId: Codec + Serialize + Send + Sync + 'static,
+	BlockNumber: Codec + Send + Sync + 'static,
+	StakeInfo: Codec + Send + Sync + 'static,
+	Value: Codec + Into<Vec<u8>> + Send + Sync + 'static,
+{
+	fn get_data_before_with_proof(
+		&self,
+		query_id: QueryId,
+		timestamp: Timestamp,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<ValueWithProof<Block::Hash, AccountId>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let api = self.client.runtime_api();
+		let runtime_error = |e| error(RUNTIME_ERROR, "Unable to query runtime", e);
+		let before = timestamp;
+		let Some((value, timestamp)) =
+			api.get_data_before(at, query_id, before).map_err(runtime_error)?
+		else {
+			return Ok(None);
+		};
+		let (Some(reporter), Some(index)) = (
+			api.get_reporter_by_timestamp(at, query_id, timestamp).map_err(runtime_error)?,
+			api.get_timestamp_index_by_timestamp(at, query_id, timestamp)
+				.map_err(runtime_error)?,
+		) else {
+			return Err(error(RUNTIME_ERROR, "Unable to query report", timestamp));
+		};
+
+		// Prove the report, along with the value in either the current or previous storage layout
+		// (which is only read whilst migrating) and the last reported value
+		let mut keys = vec![
+			report_key(&self.pallet_name, query_id, timestamp),
+			indexed_value_key(&self.pallet_name, query_id, index),
+			legacy_timestamp_key(&self.pallet_name, query_id, index),
+			reported_value_key(&self.pallet_name, query_id, index),
+			migration_in_progress_key(&self.pallet_name),
+			legacy_reported_value_key(&self.pallet_name, query_id, timestamp),
+			last_reported_key(&self.pallet_name, query_id),
+		];
+		// Prove the (disputed) values reported after the value, up to the first value reported at or
+		// after the requested timestamp
+		let mut next = index;
+		while let Some(index) = next.checked_add(1) {
+			keys.push(indexed_value_key(&self.pallet_name, query_id, index));
+			keys.push(legacy_timestamp_key(&self.pallet_name, query_id, index));
+			let Some(timestamp) = api
+				.get_timestamp_by_query_id_and_index(at, query_id, index)
+				.map_err(runtime_error)?
+			else {
+				break;
+			};
+			keys.push(report_key(&self.pallet_name, query_id, timestamp));
+			if timestamp >= before {
+				break;
+			}
+			next = index;
+		}
+		let proof = self
+			.client
+			.read_proof(at, &mut keys.iter().map(Vec::as_slice))
+			.map_err(|e| error(PROOF_ERROR, "Unable to generate storage proof", e))?;
+		Ok(Some(ValueWithProof {
+			at,
+			value: value.into().into(),
+			timestamp,
+			reporter,
+			proof: proof.into_iter_nodes().map(Into::into).collect(),
+		}))
+	}
+}
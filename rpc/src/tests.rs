@@ -0,0 +1,124 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use codec::Encode;
+use sp_core::{offchain::testing::TestPersistentOffchainDB, H256};
+
+type AccountId = u64;
+
+fn archive(
+	storage: &TestPersistentOffchainDB,
+	query_id: QueryId,
+	index: u32,
+	report: ArchivedReport<AccountId, Vec<u8>>,
+) {
+	OffchainDb::new(storage.clone()).local_storage_set(
+		StorageKind::PERSISTENT,
+		&report_key(query_id, index),
+		&report.encode(),
+	);
+}
+
+fn archived(timestamp: Timestamp, is_disputed: bool) -> ArchivedReport<AccountId, Vec<u8>> {
+	ArchivedReport { timestamp, value: vec![timestamp as u8], reporter: 1, is_disputed }
+}
+
+#[test]
+fn get_archived_report() {
+	let storage = TestPersistentOffchainDB::new();
+	let query_id = H256::random();
+	archive(&storage, query_id, 0, archived(10, true));
+	let archive = TellorArchive::<_, AccountId>::new(storage);
+
+	assert_eq!(
+		archive.get_archived_report(query_id, 0).unwrap(),
+		Some(Report {
+			index: 0,
+			timestamp: 10,
+			value: vec![10].into(),
+			reporter: 1,
+			is_disputed: true
+		})
+	);
+	assert_eq!(archive.get_archived_report(query_id, 1).unwrap(), None);
+	assert_eq!(archive.get_archived_report(H256::random(), 0).unwrap(), None);
+}
+
+#[test]
+fn get_archived_reports() {
+	let storage = TestPersistentOffchainDB::new();
+	let query_id = H256::random();
+	for index in 0..3 {
+		archive(&storage, query_id, index, archived(10 + index as u64, false));
+	}
+	let archive = TellorArchive::<_, AccountId>::new(storage);
+
+	let reports = archive.get_archived_reports(query_id, 1, 10).unwrap();
+	assert_eq!(
+		reports.iter().map(|r| (r.index, r.timestamp)).collect::<Vec<_>>(),
+		vec![(1, 11), (2, 12)]
+	);
+	assert!(archive.get_archived_reports(query_id, 0, MAX_REPORTS + 1).is_err());
+}
+
+#[test]
+fn get_archived_preimage() {
+	let storage = TestPersistentOffchainDB::new();
+	let hash = H256::random();
+	OffchainDb::new(storage.clone()).local_storage_set(
+		StorageKind::PERSISTENT,
+		&preimage_key(hash),
+		&[1, 2, 3],
+	);
+	let archive = TellorArchive::<_, AccountId>::new(storage);
+
+	assert_eq!(archive.get_archived_preimage(hash).unwrap(), Some(vec![1, 2, 3].into()));
+	assert_eq!(archive.get_archived_preimage(H256::random()).unwrap(), None);
+}
+
+#[test]
+fn serializes_report() {
+	let report = Report {
+		index: 1,
+		timestamp: 10,
+		value: vec![1, 2].into(),
+		reporter: 1,
+		is_disputed: false,
+	};
+	assert_eq!(
+		serde_json::to_string(&report).unwrap(),
+		r#"{"index":1,"timestamp":10,"value":"0x0102","reporter":1,"isDisputed":false}"#
+	);
+}
+
+#[test]
+fn serializes_value_with_proof() {
+	let value = ValueWithProof {
+		at: H256::zero(),
+		value: vec![1, 2].into(),
+		timestamp: 10,
+		reporter: 1,
+		proof: vec![vec![3].into()],
+	};
+	assert_eq!(
+		serde_json::to_string(&value).unwrap(),
+		format!(
+			r#"{{"at":"{:?}","value":"0x0102","timestamp":10,"reporter":1,"proof":["0x03"]}}"#,
+			H256::zero()
+		)
+	);
+}
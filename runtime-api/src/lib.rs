@@ -22,7 +22,12 @@ pub use autopay::{FeedDetailsWithQueryData, SingleTipWithQueryData};
 use codec::Codec;
 pub use governance::VoteInfo;
 use sp_std::vec::Vec;
-use tellor::{DisputeId, Feed, FeedId, QueryId, Timestamp, Tip, Tributes, VoteResult};
+use tellor::{
+	commitments::{Commitment, Leaf, Proof},
+	CommitHash, DerivedFeed, DisputeId, EmergencyValue, Feed, FeedId, PreimageHash, QueryHealth,
+	QueryId, ReadPolicy, ReadRejection, Subscription, SubscriptionId, Timestamp, Tip, Tributes,
+	VoteResult,
+};
 
 mod autopay;
 mod governance;
@@ -137,6 +142,7 @@ sp_api::decl_runtime_apis! {
 		fn get_tips_by_address(user: AccountId) -> Balance;
 	}
 
+	#[api_version(2)]
 	pub trait TellorOracle<AccountId: Codec, BlockNumber: Codec, StakeInfo: Codec, Value: Codec> where
 	{
 		/// Returns the block number at a given timestamp.
@@ -162,6 +168,40 @@ sp_api::decl_runtime_apis! {
 		/// The value retrieved and its timestamp, if found.
 		fn get_data_before(query_id: QueryId, timestamp: Timestamp) -> Option<(Value, Timestamp)>;
 
+		/// Returns the derived feed registered for a synthetic query identifier.
+		/// # Arguments
+		/// * `query_id` - The synthetic query identifier of the derived feed.
+		/// # Returns
+		/// The derived feed, if found.
+		fn get_derived_feed(query_id: QueryId) -> Option<DerivedFeed>;
+
+		/// Derives the value of a derived feed from the latest values of its operands, each of which
+		/// must satisfy the supplied read policy.
+		/// # Arguments
+		/// * `query_id` - The synthetic query identifier of the derived feed.
+		/// * `policy` - The policy the values of both operands must satisfy.
+		/// # Returns
+		/// The derived value (ABI-encoded as an unsigned integer) along with the timestamp of the
+		/// oldest operand, or the reason the value could not be derived.
+		fn get_derived_value(query_id: QueryId, policy: ReadPolicy) -> Result<(Vec<u8>, Timestamp), ReadRejection>;
+
+		/// Returns the emergency value published by governance for a query identifier, provided it has
+		/// not expired. Emergency values are never reported values.
+		/// # Arguments
+		/// * `query_id` - The query identifier to look up the emergency value for.
+		/// # Returns
+		/// The emergency value along with the time published and the time it expires, if found.
+		fn get_emergency_value(query_id: QueryId) -> Option<EmergencyValue<Value>>;
+
+		/// Retrieves the latest finalized value for the query identifier before the specified timestamp,
+		/// being a value whose dispute window has elapsed without dispute.
+		/// # Arguments
+		/// * `query_id` - The query identifier to look up the value for.
+		/// * `timestamp` - The timestamp before which to search for the latest finalized value.
+		/// # Returns
+		/// The finalized value retrieved and its timestamp, if found.
+		fn get_finalized_value_before(query_id: QueryId, timestamp: Timestamp) -> Option<(Value, Timestamp)>;
+
 		/// Counts the number of values that have been submitted for the query identifier.
 		/// # Arguments
 		/// * `query_id` - The query identifier to look up.
@@ -169,6 +209,22 @@ sp_api::decl_runtime_apis! {
 		/// Count of the number of values received for the query identifier.
 		fn get_new_value_count_by_query_id(query_id: QueryId) -> u32;
 
+		/// Returns the unrevealed commitments for a query identifier using commit-reveal, excluding
+		/// those whose reveal window has expired.
+		/// # Arguments
+		/// * `query_id` - The identifier of the specific data feed.
+		/// # Returns
+		/// The reporter, hash and time of each unrevealed commitment, in no particular order.
+		fn get_pending_commits(query_id: QueryId) -> Vec<(AccountId, CommitHash, Timestamp)>;
+
+		/// Retrieves the current health of the query identifier, allowing consumers to halt automatically
+		/// once degraded or frozen.
+		/// # Arguments
+		/// * `query_id` - The query identifier to look up the health for.
+		/// # Returns
+		/// The health of the query identifier, which is always healthy without health thresholds.
+		fn get_query_health(query_id: QueryId) -> QueryHealth;
+
 		/// Returns reporter and whether a value was disputed for a given query identifier and timestamp.
 		/// # Arguments
 		/// * `query_id` - The query identifier to look up.
@@ -195,8 +251,16 @@ sp_api::decl_runtime_apis! {
 		/// Returns the reporting lock time, the amount of time a reporter must wait to submit again.
 		/// # Returns
 		/// The reporting lock time.
+		#[changed_in(2)]
 		fn get_reporting_lock() -> Timestamp;
 
+		/// Returns the reporting lock time, the amount of time a reporter must wait to submit again.
+		/// # Arguments
+		/// * `query_id` - The identifier of the specific data feed.
+		/// # Returns
+		/// The reporting lock time of the risk class of the query identifier.
+		fn get_reporting_lock(query_id: QueryId) -> Timestamp;
+
 		/// Returns the number of values submitted by a specific reporter.
 		/// # Arguments
 		/// * `reporter` - The identifier of the reporter.
@@ -212,6 +276,13 @@ sp_api::decl_runtime_apis! {
 		/// The number of values submitted by the given reporter to the given query identifier.
 		fn get_reports_submitted_by_address_and_query_id(reporter: AccountId, query_id: QueryId) -> u32;
 
+		/// Returns the number of values retracted by a specific reporter.
+		/// # Arguments
+		/// * `reporter` - The identifier of the reporter.
+		/// # Returns
+		/// The number of values retracted by the given reporter.
+		fn get_retractions_by_reporter(reporter: AccountId) -> u32;
+
 		/// Returns the amount required to report oracle values.
 		/// # Returns
 		/// The stake amount.
@@ -224,6 +295,13 @@ sp_api::decl_runtime_apis! {
 		/// All information about a staker, if found.
 		fn get_staker_info(staker: AccountId) -> Option<StakeInfo>;
 
+		/// Returns a subscription of a remote parachain to the values of a query identifier.
+		/// # Arguments
+		/// * `subscription_id` - Identifier of the subscription.
+		/// # Returns
+		/// The subscription, if found.
+		fn get_subscription(subscription_id: SubscriptionId) -> Option<Subscription>;
+
 		/// Returns the timestamp for the last value of any identifier from the oracle.
 		/// # Returns
 		/// The timestamp of the last oracle value.
@@ -263,6 +341,28 @@ sp_api::decl_runtime_apis! {
 		/// The total number of current stakers.
 		fn get_total_stakers() -> u64;
 
+		/// Returns the current commitment to reported values, as deposited in the header of the last
+		/// block in which values were reported or removed.
+		/// # Returns
+		/// The number of leaves and root of the commitment range, if any values have been reported.
+		fn get_value_commitment() -> Option<Commitment>;
+
+		/// Returns a value noted as a preimage, as referenced by values reported for large value queries.
+		/// # Arguments
+		/// * `hash` - The hash of the noted value.
+		/// # Returns
+		/// The noted value, if found.
+		fn get_value_preimage(hash: PreimageHash) -> Option<Vec<u8>>;
+
+		/// Returns a proof of the commitment leaf of a reported value, verifiable against the
+		/// current commitment using `tellor::commitments::verify`.
+		/// # Arguments
+		/// * `query_id` - The query identifier of the reported value.
+		/// * `timestamp` - The timestamp of the reported value.
+		/// # Returns
+		/// The leaf of the reported value and its proof of membership, if found.
+		fn get_value_proof(query_id: QueryId, timestamp: Timestamp) -> Option<(Leaf, Proof)>;
+
 		/// Returns whether a given value is disputed.
 		/// # Arguments
 		/// * `query_id` - Unique identifier of the data feed.
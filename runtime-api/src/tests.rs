@@ -22,20 +22,22 @@ use crate::{
 use codec::Encode;
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU64},
+	traits::{ConstU16, ConstU64, NeverEnsureOrigin},
 	weights::Weight,
 	BoundedVec, PalletId,
 };
 use sp_api::mock_impl_runtime_apis;
 use sp_core::{ConstU128, ConstU32, H256, U256};
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup},
 };
 use std::time::{SystemTime, UNIX_EPOCH};
 use tellor::{
-	DisputeId, EnsureGovernance, EnsureStaking, Feed, FeedId, QueryId, Timestamp, Tip, Tributes,
-	VoteResult,
+	commitments::{Commitment, Leaf, Proof},
+	CommitHash, DerivedFeed, DisputeId, EmergencyValue, EnsureGovernance, EnsureStaking, Feed,
+	FeedId, PreimageHash, QueryHealth, QueryId, ReadPolicy, ReadRejection, Subscription,
+	SubscriptionId, Timestamp, Tip, Tributes, VoteResult,
 };
 use xcm::latest::prelude::*;
 
@@ -123,24 +125,54 @@ impl tellor::Config for Test {
 	type EthereumXcmPalletIndex = ();
 	type Fee = ();
 	type FeeLocation = FeeLocation;
+	type FeelessSubmissions = ();
 	type Governance = ();
 	type GovernanceOrigin = EnsureGovernance;
 	type InitialDisputeFee = ();
+	type MaxCachedValues = ();
 	type MaxClaimTimestamps = ();
 	type MaxDisputedTimeSeries = ();
+	type MaxEmergencyValueDuration = ();
+	type MaxLargeValueLength = ();
+	type MaxPendingRequests = ();
 	type MaxQueryDataLength = ();
+	type MaxReportTimestampOffset = ();
+	type MaxRequestDelay = ();
+	type MaxSubmissionsPerBatch = ();
+	type MaxSubscriptions = ();
 	type MaxValueLength = MaxValueLength;
 	type MaxVotes = ();
 	type MinimumStakeAmount = ();
+	type OnNewReport = ();
+	type OnResponse = ();
+	type OnValueRemoved = ();
 	type PalletId = TellorPalletId;
 	type ParachainId = ();
+	type PreimageBaseDeposit = ();
+	type PreimageByteDeposit = ();
 	type RegisterOrigin = frame_system::EnsureRoot<AccountId>;
 	type Registry = ();
+	type RemoteQueryByteFee = ();
+	type RemoteQueryFee = ();
+	type RemoteQueryOrigin = NeverEnsureOrigin<MultiLocation>;
+	type ReporterPublic = UintAuthorityId;
+	type ReporterSignature = TestSignature;
+	type RequestTimeout = ();
+	type RetractionPenalty = ();
+	type RetractionPeriod = ();
+	type RevealWindow = ();
+	type SovereignAccountOf = ();
 	type StakeAmountCurrencyTarget = ();
 	type StakingOrigin = EnsureStaking;
 	type StakingTokenPriceQueryId = ();
 	type StakingToLocalTokenPriceQueryId = ();
+	type SubscriberOrigin = NeverEnsureOrigin<MultiLocation>;
+	type SubscriptionDeposit = ();
+	type SubscriptionDisputeBuffer = ();
+	type SubscriptionFee = ();
 	type Time = Time;
+	type UnsignedLongevity = ();
+	type UnsignedPriority = ();
 	type UpdateStakeAmountInterval = ();
 	type WeightToFee = ();
 	type Xcm = TestSendXcm;
@@ -262,10 +294,34 @@ mock_impl_runtime_apis! {
 			tellor::Pallet::<Test>::get_data_before(query_id, timestamp)
 		}
 
+		fn get_derived_feed(query_id: QueryId) -> Option<DerivedFeed> {
+			tellor::Pallet::<Test>::get_derived_feed(query_id)
+		}
+
+		fn get_derived_value(query_id: QueryId, policy: ReadPolicy) -> Result<(Vec<u8>, Timestamp), ReadRejection> {
+			tellor::Pallet::<Test>::get_derived_value(query_id, policy)
+		}
+
+		fn get_emergency_value(query_id: QueryId) -> Option<EmergencyValue<Value>> {
+			tellor::Pallet::<Test>::get_emergency_value(query_id)
+		}
+
+		fn get_finalized_value_before(query_id: QueryId, timestamp: Timestamp) -> Option<(Value, Timestamp)>{
+			tellor::Pallet::<Test>::get_finalized_value_before(query_id, timestamp)
+		}
+
 		fn get_new_value_count_by_query_id(query_id: QueryId) -> u32 {
 			tellor::Pallet::<Test>::get_new_value_count_by_query_id(query_id) as u32
 		}
 
+		fn get_pending_commits(query_id: QueryId) -> Vec<(AccountId, CommitHash, Timestamp)> {
+			tellor::Pallet::<Test>::get_pending_commits(query_id)
+		}
+
+		fn get_query_health(query_id: QueryId) -> QueryHealth {
+			tellor::Pallet::<Test>::get_query_health(query_id)
+		}
+
 		fn get_report_details(query_id: QueryId, timestamp: Timestamp) -> Option<(AccountId, bool)>{
 			tellor::Pallet::<Test>::get_report_details(query_id, timestamp)
 		}
@@ -278,8 +334,8 @@ mock_impl_runtime_apis! {
 			tellor::Pallet::<Test>::get_reporter_last_timestamp(reporter)
 		}
 
-		fn get_reporting_lock() -> Timestamp {
-			tellor::Pallet::<Test>::get_reporting_lock()
+		fn get_reporting_lock(query_id: QueryId) -> Timestamp {
+			tellor::Pallet::<Test>::get_reporting_lock(query_id)
 		}
 
 		fn get_reports_submitted_by_address(reporter: AccountId) -> u32 {
@@ -290,6 +346,10 @@ mock_impl_runtime_apis! {
 			tellor::Pallet::<Test>::get_reports_submitted_by_address_and_query_id(reporter, query_id)
 		}
 
+		fn get_retractions_by_reporter(reporter: AccountId) -> u32 {
+			tellor::Pallet::<Test>::get_retractions_by_reporter(&reporter)
+		}
+
 		fn get_stake_amount() -> Tributes {
 			tellor::Pallet::<Test>::get_stake_amount()
 		}
@@ -298,6 +358,10 @@ mock_impl_runtime_apis! {
 			tellor::Pallet::<Test>::get_staker_info(staker)
 		}
 
+		fn get_subscription(subscription_id: SubscriptionId) -> Option<Subscription> {
+			tellor::Pallet::<Test>::get_subscription(subscription_id)
+		}
+
 		fn get_time_of_last_new_value() -> Option<Timestamp> {
 			tellor::Pallet::<Test>::get_time_of_last_new_value()
 		}
@@ -322,6 +386,18 @@ mock_impl_runtime_apis! {
 			tellor::Pallet::<Test>::get_total_stakers()
 		}
 
+		fn get_value_commitment() -> Option<Commitment> {
+			tellor::Pallet::<Test>::get_value_commitment()
+		}
+
+		fn get_value_preimage(hash: PreimageHash) -> Option<Vec<u8>> {
+			tellor::Pallet::<Test>::get_value_preimage(hash)
+		}
+
+		fn get_value_proof(query_id: QueryId, timestamp: Timestamp) -> Option<(Leaf, Proof)> {
+			tellor::Pallet::<Test>::get_value_proof(query_id, timestamp)
+		}
+
 		fn is_in_dispute(query_id: QueryId, timestamp: Timestamp) -> bool{
 			tellor::Pallet::<Test>::is_in_dispute(query_id, timestamp)
 		}
@@ -548,6 +624,46 @@ mod oracle {
 		});
 	}
 
+	#[test]
+	fn get_derived_feed() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Test.get_derived_feed(BLOCKID, QueryId::random()).unwrap(), None);
+		});
+	}
+
+	#[test]
+	fn get_derived_value() {
+		new_test_ext().execute_with(|| {
+			let policy = ReadPolicy {
+				dispute_buffer: 0,
+				max_age: 0,
+				min_reporters: 0,
+				max_open_disputes: 0,
+			};
+			assert_eq!(
+				Test.get_derived_value(BLOCKID, QueryId::random(), policy).unwrap(),
+				Err(ReadRejection::NotDerivedFeed)
+			);
+		});
+	}
+
+	#[test]
+	fn get_emergency_value() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Test.get_emergency_value(BLOCKID, QueryId::random()).unwrap(), None);
+		});
+	}
+
+	#[test]
+	fn get_finalized_value_before() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(
+				Test.get_finalized_value_before(BLOCKID, QueryId::random(), 0).unwrap(),
+				None
+			);
+		});
+	}
+
 	#[test]
 	fn get_new_value_count_by_query_id() {
 		new_test_ext().execute_with(|| {
@@ -558,6 +674,23 @@ mod oracle {
 		});
 	}
 
+	#[test]
+	fn get_pending_commits() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Test.get_pending_commits(BLOCKID, QueryId::random()).unwrap(), vec![]);
+		});
+	}
+
+	#[test]
+	fn get_query_health() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(
+				Test.get_query_health(BLOCKID, QueryId::random()).unwrap(),
+				QueryHealth::Healthy
+			);
+		});
+	}
+
 	#[test]
 	fn get_report_details() {
 		new_test_ext().execute_with(|| {
@@ -588,7 +721,7 @@ mod oracle {
 	#[test]
 	fn get_reporting_lock() {
 		new_test_ext().execute_with(|| {
-			assert_eq!(Test.get_reporting_lock(BLOCKID).unwrap(), 43200);
+			assert_eq!(Test.get_reporting_lock(BLOCKID, QueryId::random()).unwrap(), 43200);
 		});
 	}
 
@@ -617,6 +750,13 @@ mod oracle {
 		});
 	}
 
+	#[test]
+	fn get_retractions_by_reporter() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Test.get_retractions_by_reporter(BLOCKID, AccountId::default()).unwrap(), 0);
+		});
+	}
+
 	#[test]
 	fn get_stake_amount() {
 		new_test_ext().execute_with(|| {
@@ -631,6 +771,13 @@ mod oracle {
 		});
 	}
 
+	#[test]
+	fn get_subscription() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Test.get_subscription(BLOCKID, 0).unwrap(), None);
+		});
+	}
+
 	#[test]
 	fn get_time_of_last_new_value() {
 		new_test_ext().execute_with(|| {
@@ -682,6 +829,27 @@ mod oracle {
 		});
 	}
 
+	#[test]
+	fn get_value_commitment() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Test.get_value_commitment(BLOCKID).unwrap(), None);
+		});
+	}
+
+	#[test]
+	fn get_value_preimage() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Test.get_value_preimage(BLOCKID, PreimageHash::random()).unwrap(), None);
+		});
+	}
+
+	#[test]
+	fn get_value_proof() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Test.get_value_proof(BLOCKID, QueryId::random(), 0).unwrap(), None);
+		});
+	}
+
 	#[test]
 	fn is_in_dispute() {
 		new_test_ext().execute_with(|| {
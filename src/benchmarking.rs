@@ -21,13 +21,29 @@ use ethabi::{Token, Uint};
 
 #[allow(unused)]
 use crate::Pallet as Tellor;
-use crate::{constants::DECIMALS, traits::BenchmarkHelper, types::QueryDataOf};
+use crate::{
+	commitments,
+	constants::{
+		DECIMALS, HEALTH_WINDOW, MAX_FINALIZED_VALUES_PER_BLOCK, MAX_HEALTH_CHECKS_PER_BLOCK,
+		MAX_HEALTH_FEEDS,
+	},
+	migrations::v1::{self, v0},
+	traits::BenchmarkHelper,
+	types::{LargeValueOf, QueryDataOf},
+};
+use ::xcm::latest::prelude::Here;
 use codec::alloc::vec;
 use frame_benchmarking::{account, benchmarks, BenchmarkError};
-use frame_support::traits::OnInitialize;
+use frame_support::{
+	traits::{OnIdle, OnInitialize},
+	weights::Weight,
+};
 use frame_system::RawOrigin;
-use sp_core::bounded::BoundedVec;
-use sp_runtime::traits::{Hash, Keccak256};
+use sp_core::{bounded::BoundedVec, H256};
+use sp_runtime::{
+	traits::{Hash, Keccak256, One},
+	Percent, Permill,
+};
 use types::{Address, Timestamp};
 
 type RuntimeOrigin<T> = <T as frame_system::Config>::RuntimeOrigin;
@@ -111,6 +127,90 @@ fn create_feed<T: Config>(
 	]))
 }
 
+// Helper function for funding the feeds and reporting the values inspected when assessing health
+#[allow(clippy::result_large_err)]
+fn report_health_values<T: Config>(
+	query_data: QueryDataOf<T>,
+	first_reporter: u32,
+) -> Result<(), BenchmarkError> {
+	let query_id = Keccak256::hash(query_data.as_ref());
+	let feed_creator = account::<AccountIdOf<T>>("feed_creator", 0, SEED);
+
+	T::BenchmarkHelper::set_balance(feed_creator.clone(), token::<T>(1_000u16));
+	for i in 0..MAX_HEALTH_FEEDS {
+		create_feed::<T>(
+			feed_creator.clone(),
+			query_id,
+			token::<T>(1u64),
+			T::Time::now().as_secs(),
+			HOURS + Timestamp::from(i),
+			600,
+			0,
+			token::<T>(0u64),
+			query_data.clone(),
+			token::<T>(10u64),
+		);
+	}
+	for i in 0..HEALTH_WINDOW {
+		let reporter = account::<AccountIdOf<T>>("account", first_reporter + i, SEED);
+		deposit_stake::<T>(reporter.clone(), trb(100), Address::zero())?;
+		T::BenchmarkHelper::set_time(MINUTES);
+		Tellor::<T>::submit_value(
+			RawOrigin::Signed(reporter).into(),
+			query_id,
+			uint_value::<T>(1_000u64),
+			0,
+			query_data.clone(),
+		)?;
+	}
+	Ok(())
+}
+
+// Helper function for filling the commitment range, so that the last of the supplied number of
+// leaves to be appended completes a peak of the maximum height, as does any update of that leaf
+fn fill_commitment_range<T: Config>(leaves: u64) {
+	let leaf_count = (1u64 << 63) - leaves;
+	for (height, index) in commitments::peaks(leaf_count) {
+		<CommitmentNodes<T>>::insert(height, index, H256::repeat_byte(height));
+	}
+	<CommitmentLeafCount<T>>::set(leaf_count);
+}
+
+// Helper function for reporting maximum length values and enabling the value cache, so that the
+// cache is seeded with the latest of them
+#[allow(clippy::result_large_err)]
+fn report_cached_values<T: Config>(
+	reporter: AccountIdOf<T>,
+	query_data: QueryDataOf<T>,
+	count: u32,
+) -> Result<(), BenchmarkError> {
+	let query_id = Keccak256::hash(query_data.as_ref());
+	let caller =
+		T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	for _ in 0..count {
+		T::BenchmarkHelper::set_time(REPORTING_LOCK);
+		Tellor::<T>::submit_value(
+			RawOrigin::Signed(reporter.clone()).into(),
+			query_id,
+			BoundedVec::try_from(vec![1u8; T::MaxValueLength::get() as usize]).unwrap(),
+			0,
+			query_data.clone(),
+		)?;
+	}
+	Tellor::<T>::set_value_cache(caller, query_id, true)?;
+	Ok(())
+}
+
+fn health_thresholds() -> HealthThresholds {
+	let limits = HealthLimits {
+		open_disputes: 1,
+		disputed_ratio: Percent::from_percent(50),
+		stale_intervals: 2,
+		min_reporters: HEALTH_WINDOW,
+	};
+	HealthThresholds { degraded: limits, frozen: limits }
+}
+
 fn dispute_id(para_id: u32, query_id: QueryId, timestamp: Timestamp) -> DisputeId {
 	Keccak256::hash(&ethabi::encode(&[
 		Token::Uint(para_id.into()),
@@ -147,7 +247,7 @@ benchmarks! {
 				0,
 				query_data.clone()
 			)?;
-			timestamps.try_push(<ReportedTimestampsByIndex<T>>::get(query_id, i - 1).unwrap().into()).unwrap();
+			timestamps.try_push(Tellor::<T>::get_timestamp_by_query_id_and_index(query_id, i - 1).unwrap().into()).unwrap();
 		}
 		T::BenchmarkHelper::set_time(12 * HOURS);
 	}: _(RawOrigin::Signed(reporter), query_id, timestamps)
@@ -188,7 +288,7 @@ benchmarks! {
 				uint_value::<T>(i * 1_000),
 				0,
 				query_data.clone())?;
-			timestamps.try_push(<ReportedTimestampsByIndex<T>>::get(query_id, i - 1).unwrap().into()).unwrap();
+			timestamps.try_push(Tellor::<T>::get_timestamp_by_query_id_and_index(query_id, i - 1).unwrap().into()).unwrap();
 		}
 		T::BenchmarkHelper::set_time(12 * HOURS);
 	}: _(RawOrigin::Signed(reporter), feed_id, query_id, timestamps)
@@ -267,10 +367,17 @@ benchmarks! {
 		let address = Address::zero();
 		// report deposit stake
 		deposit_stake::<T>(reporter.clone(), trb(1_200), address)?;
-		T::BenchmarkHelper::set_time(HOURS);
-	}: _(RawOrigin::Signed(reporter.clone()), query_id, value, 0, query_data)
+		// Full value cache, so that the oldest cached value is evicted
+		report_cached_values::<T>(reporter.clone(), query_data.clone(), T::MaxCachedValues::get())?;
+		fill_commitment_range::<T>(1);
+		T::BenchmarkHelper::set_time(REPORTING_LOCK);
+	}: _(RawOrigin::Signed(reporter.clone()), query_id, value.clone(), 0, query_data)
 	verify {
 		assert!(<StakerDetails<T>>::get(reporter).is_some());
+		let cached = <ValueCaches<T>>::get(query_id).unwrap();
+		assert_eq!(cached.len() as u32, T::MaxCachedValues::get());
+		assert_eq!(cached.last().map(|(_, cached)| cached), Some(&value));
+		assert_eq!(<CommitmentLeafCount<T>>::get(), 1 << 63);
 	}
 
 	update_stake_amount {
@@ -304,7 +411,7 @@ benchmarks! {
 			if i > 1 && i < reports {
 				Tellor::<T>::begin_dispute(RawOrigin::Signed(disputer.clone()).into(),
 					staking_token_price_query_id,
-					<ReportedTimestampsByIndex<T>>::get(staking_token_price_query_id, i - 1).unwrap(),
+					Tellor::<T>::get_timestamp_by_query_id_and_index(staking_token_price_query_id, i - 1).unwrap(),
 					Some(address))?;
 			}
 			T::BenchmarkHelper::set_time(12 * HOURS);
@@ -321,7 +428,7 @@ benchmarks! {
 			if i > 1 && i < reports {
 				Tellor::<T>::begin_dispute(RawOrigin::Signed(disputer.clone()).into(),
 					staking_to_local_token_query_id,
-					<ReportedTimestampsByIndex<T>>::get(staking_to_local_token_query_id, i - 1).unwrap(),
+					Tellor::<T>::get_timestamp_by_query_id_and_index(staking_to_local_token_query_id, i - 1).unwrap(),
 					Some(address))?;
 			}
 			T::BenchmarkHelper::set_time(12 * HOURS);
@@ -338,13 +445,14 @@ benchmarks! {
 
 		T::BenchmarkHelper::set_time(REPORTING_LOCK);
 
-		// Create initial timestamp for later dispute in extrinsic call
+		// Create initial timestamps for later dispute in extrinsic call, filling the value cache so
+		// that the disputed value is replaced by the value preceding the oldest cached value
 		let reporter = account::<AccountIdOf<T>>("account", 0, SEED);
 		deposit_stake::<T>(reporter.clone(), stake_amount, Address::zero())?;
 		T::BenchmarkHelper::set_balance(reporter.clone(), dispute_fees);
-		Tellor::<T>::submit_value(
-			RawOrigin::Signed(reporter.clone()).into(), query_id, uint_value::<T>(10), 0, query_data.clone()
-		)?;
+		let cached = T::MaxCachedValues::get();
+		fill_commitment_range::<T>((cached + 1).into());
+		report_cached_values::<T>(reporter.clone(), query_data.clone(), cached + 1)?;
 
 		// Create series of disputed timestamps, using new accounts to avoid reporting lock
 		for i in 2..d {
@@ -357,15 +465,22 @@ benchmarks! {
 			)?;
 			Tellor::<T>::begin_dispute(RawOrigin::Signed(reporter).into(),
 				query_id,
-				<ReportedTimestampsByIndex<T>>::get(query_id, i - 1).unwrap(),
+				Tellor::<T>::get_timestamp_by_query_id_and_index(query_id, cached + i - 1).unwrap(),
 				None)?;
 		}
 
-		let timestamp = <ReportedTimestampsByIndex<T>>::get(query_id, 0).unwrap();
+		let timestamp = Tellor::<T>::get_timestamp_by_query_id_and_index(query_id, cached).unwrap();
 	}: _(RawOrigin::Signed(reporter), query_id, timestamp, None)
 	verify {
 		let governance_contract = T::Governance::get();
-		assert!(<LastReportedTimestamp<T>>::get(query_id).is_none());
+		assert_eq!(<LastReported<T>>::get(query_id).map(|(index, _)| index), cached.checked_sub(1));
+		let values = <ValueCaches<T>>::get(query_id).unwrap();
+		assert!(values.iter().all(|(cached, _)| *cached != timestamp));
+		assert_eq!(values.len() as u32, cached);
+		assert_eq!(
+			<CommitmentLeaves<T>>::get(query_id, timestamp).map(|(index, leaf)| (index, leaf.disputed)),
+			Some(((1 << 63) - 1, true))
+		);
 		assert_last_event::<T>(
 				Event::NewDisputeSent { para_id: governance_contract.para_id, contract_address: governance_contract.address.into()}.into(),
 			);
@@ -597,6 +712,132 @@ benchmarks! {
 		T::BenchmarkHelper::set_time(DAYS);
 	}: _<RuntimeOrigin<T>>(caller, dispute_id)
 
+	set_commit_reveal {
+		let query_id = Keccak256::hash(&[0u8; 1]);
+		let caller = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<RuntimeOrigin<T>>(caller, query_id, true)
+	verify {
+		assert!(<CommitRevealQueries<T>>::contains_key(query_id));
+	}
+
+	commit_value {
+		let query_data: QueryDataOf<T> = BoundedVec::try_from(vec![0u8; T::MaxQueryDataLength::get() as usize]).unwrap();
+		let query_id = Keccak256::hash(query_data.as_ref());
+		let reporter = account::<AccountIdOf<T>>("account", 1, SEED);
+		let caller = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		deposit_stake::<T>(reporter.clone(), trb(1_200), Address::zero())?;
+		Tellor::<T>::set_commit_reveal(caller, query_id, true)?;
+		T::BenchmarkHelper::set_time(HOURS);
+	}: _(RawOrigin::Signed(reporter.clone()), query_id, Keccak256::hash(&[1u8; 1]))
+	verify {
+		assert!(<Commits<T>>::contains_key(query_id, reporter));
+	}
+
+	reveal_value {
+		// Maximum value for query data in order to measure the maximum weight
+		let q in 1..T::MaxQueryDataLength::get();
+		// Maximum length for value in order to measure the maximum weight
+		let v in 1..T::MaxValueLength::get();
+		let query_data: QueryDataOf<T> = BoundedVec::try_from(vec![1u8; q as usize]).unwrap();
+		let query_id = Keccak256::hash(query_data.as_ref());
+		let value: ValueOf<T> = BoundedVec::try_from(vec![1u8; v as usize]).unwrap();
+		let salt = H256::repeat_byte(1);
+		let reporter = account::<AccountIdOf<T>>("account", 1, SEED);
+		let caller = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		deposit_stake::<T>(reporter.clone(), trb(1_200), Address::zero())?;
+		Tellor::<T>::set_commit_reveal(caller, query_id, true)?;
+		T::BenchmarkHelper::set_time(HOURS);
+		Tellor::<T>::commit_value(
+			RawOrigin::Signed(reporter.clone()).into(),
+			query_id,
+			Tellor::<T>::commitment(&reporter, query_id, &value, salt))?;
+		frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + One::one());
+		T::BenchmarkHelper::set_time(MINUTES);
+		fill_commitment_range::<T>(1);
+	}: _(RawOrigin::Signed(reporter.clone()), query_id, value, salt, 0, query_data)
+	verify {
+		assert!(!<Commits<T>>::contains_key(query_id, reporter));
+	}
+
+	submit_values {
+		// Maximum number of submissions in order to measure the maximum weight
+		let n in 1..T::MaxSubmissionsPerBatch::get();
+		// Maximum value for query data in order to measure the maximum weight
+		let q in 1..T::MaxQueryDataLength::get();
+		// Maximum length for value in order to measure the maximum weight
+		let v in 1..T::MaxValueLength::get();
+		let mut submissions = BoundedVec::default();
+		for i in 0..n {
+			let mut query_data = vec![1u8; q as usize];
+			query_data[0] = i as u8;
+			let query_data: QueryDataOf<T> = BoundedVec::try_from(query_data).unwrap();
+			let query_id = Keccak256::hash(query_data.as_ref());
+			let value: ValueOf<T> = BoundedVec::try_from(vec![1u8; v as usize]).unwrap();
+			submissions.try_push((query_id, value, 0, query_data)).unwrap();
+		}
+		let reporter = account::<AccountIdOf<T>>("account", 1, SEED);
+		deposit_stake::<T>(reporter.clone(), trb(1_200), Address::zero())?;
+		fill_commitment_range::<T>(n.into());
+		T::BenchmarkHelper::set_time(HOURS);
+	}: _(RawOrigin::Signed(reporter.clone()), submissions)
+	verify {
+		assert_eq!(<StakerDetails<T>>::get(reporter).unwrap().reports_submitted, n);
+	}
+
+	set_risk_class {
+		let caller = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let risk_class = RiskClass { reporting_lock: REPORTING_LOCK, stake_multiple: 2 };
+	}: _<RuntimeOrigin<T>>(caller, 1, Some(risk_class))
+	verify {
+		assert_eq!(<RiskClasses<T>>::get(1), Some(risk_class));
+	}
+
+	set_query_risk_class {
+		let query_id = Keccak256::hash(&[0u8; 1]);
+		let caller = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Tellor::<T>::set_risk_class(
+			caller.clone(),
+			1,
+			Some(RiskClass { reporting_lock: REPORTING_LOCK, stake_multiple: 2 }))?;
+	}: _<RuntimeOrigin<T>>(caller, query_id, Some(1))
+	verify {
+		assert_eq!(<QueryRiskClass<T>>::get(query_id), Some(1));
+	}
+
+	submit_value_unsigned {
+		// Maximum value for query data in order to measure the maximum weight
+		let q in 1..T::MaxQueryDataLength::get();
+		// Maximum length for value in order to measure the maximum weight
+		let v in 1..T::MaxValueLength::get();
+		let query_data: QueryDataOf<T> = BoundedVec::try_from(vec![1u8; q as usize]).unwrap();
+		let query_id = Keccak256::hash(query_data.as_ref());
+		let reporter = account::<AccountIdOf<T>>("account", 1, SEED);
+		let address = Address::zero();
+		// report deposit stake
+		deposit_stake::<T>(reporter.clone(), trb(1_200), address)?;
+		T::BenchmarkHelper::set_time(HOURS);
+		let payload = ReportPayload {
+			reporter: reporter.clone(),
+			query_id,
+			value: BoundedVec::try_from(vec![1u8; v as usize]).unwrap(),
+			nonce: 0,
+			valid_until: Tellor::<T>::now() + HOURS,
+		};
+		let signature = T::BenchmarkHelper::sign(&reporter, &Tellor::<T>::report_payload_message(&payload));
+		fill_commitment_range::<T>(1);
+	}: _(RawOrigin::None, payload, query_data, signature)
+	verify {
+		assert_eq!(Tellor::<T>::get_reports_submitted_by_address(&reporter), 1);
+	}
+
+	set_retention_period {
+		let query_id = Keccak256::hash(&[0u8; 1]);
+		let caller = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<RuntimeOrigin<T>>(caller, query_id, Some(MIN_RETENTION_PERIOD))
+	verify {
+		assert_eq!(<RetentionPeriods<T>>::get(query_id), Some(MIN_RETENTION_PERIOD));
+	}
+
 	on_initialize {
 		// Maximum number of binary search iterations for staking token price
 		let s in 2..12;
@@ -635,7 +876,7 @@ benchmarks! {
 				// Remove value rather than dispute to avoid adding state to PendingVotes which interferes with v complexity parameter
 				Tellor::<T>::remove_value(
 					staking_token_price_query_id,
-					<ReportedTimestampsByIndex<T>>::get(staking_token_price_query_id, i - 1).unwrap()
+					Tellor::<T>::get_timestamp_by_query_id_and_index(staking_token_price_query_id, i - 1).unwrap()
 				)?;
 			}
 			T::BenchmarkHelper::set_time(12 * HOURS);
@@ -653,7 +894,7 @@ benchmarks! {
 				// Remove value rather than dispute to avoid adding state to PendingVotes which interferes with v complexity parameter
 				Tellor::<T>::remove_value(
 					staking_to_local_token_query_id,
-					<ReportedTimestampsByIndex<T>>::get(staking_to_local_token_query_id, i - 1).unwrap(),
+					Tellor::<T>::get_timestamp_by_query_id_and_index(staking_to_local_token_query_id, i - 1).unwrap(),
 				)?;
 			}
 			T::BenchmarkHelper::set_time(12 * HOURS);
@@ -678,5 +919,503 @@ benchmarks! {
 		Tellor::<T>::on_initialize(T::BlockNumber::zero())
 	}
 
+	on_idle {
+		// Maximum number of pending requests delivered
+		let r in 1..T::MaxPendingRequests::get();
+		let query_data: QueryDataOf<T> = BoundedVec::try_from(vec![0u8; T::MaxQueryDataLength::get() as usize]).unwrap();
+		let query_id = Keccak256::hash(query_data.as_ref());
+		let reporter = account::<AccountIdOf<T>>("account", 1, SEED);
+		let requester = account::<AccountIdOf<T>>("account", 2, SEED);
+
+		deposit_stake::<T>(reporter.clone(), trb(100), Address::zero())?;
+		T::BenchmarkHelper::set_time(HOURS);
+		for callback in 0..r {
+			<Tellor<T> as RequestTellor<_, _, _>>::request_data(requester.clone(), query_id, 0, HOURS, callback)?;
+		}
+		Tellor::<T>::submit_value(
+			RawOrigin::Signed(reporter).into(),
+			query_id,
+			uint_value::<T>(1_000u64),
+			0,
+			query_data)?;
+		T::BenchmarkHelper::set_time(HOURS);
+	}: {
+		Tellor::<T>::on_idle(T::BlockNumber::zero(), Weight::MAX)
+	}
+	verify {
+		assert_eq!(<PendingRequests<T>>::count(), 0);
+	}
+
+	prune_values {
+		// Number of query identifiers with a retention period inspected
+		let q in 1..100;
+		// Number of values pruned
+		let p in 1..100;
+		let reporter = account::<AccountIdOf<T>>("account", 1, SEED);
+		let caller = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		deposit_stake::<T>(reporter.clone(), trb(100), Address::zero())?;
+		T::BenchmarkHelper::set_time(HOURS);
+		let mut query_ids = Vec::new();
+		for i in 0..q {
+			let query_data: QueryDataOf<T> = BoundedVec::try_from(i.to_be_bytes().to_vec()).unwrap();
+			let query_id = Keccak256::hash(query_data.as_ref());
+			Tellor::<T>::set_retention_period(caller.clone(), query_id, Some(MIN_RETENTION_PERIOD))?;
+			// Values are pruned from the first query identifier, retaining only the latest value of each
+			let reports = if i == 0 { p + 1 } else { 1 };
+			for _ in 0..reports {
+				Tellor::<T>::submit_value(
+					RawOrigin::Signed(reporter.clone()).into(),
+					query_id,
+					uint_value::<T>(1_000u64),
+					0,
+					query_data.clone())?;
+				T::BenchmarkHelper::set_time(REPORTING_LOCK);
+			}
+			// Pruned values are evicted from the value cache
+			Tellor::<T>::set_value_cache(caller.clone(), query_id, true)?;
+			query_ids.push(query_id);
+		}
+		T::BenchmarkHelper::set_time(MIN_RETENTION_PERIOD);
+	}: {
+		Tellor::<T>::prune_values(Tellor::<T>::now(), Weight::MAX)
+	}
+	verify {
+		assert_eq!(<FirstRetainedIndex<T>>::get(query_ids[0]), p);
+		assert_eq!(<ValueCaches<T>>::get(query_ids[0]).unwrap().len(), 1);
+	}
+
+	prune_commits {
+		// Number of commitments pruned
+		let c in 1..1_000;
+		let query_id = Keccak256::hash(&[1u8; 1]);
+		for i in 0..c {
+			let reporter = account::<AccountIdOf<T>>("account", i, SEED);
+			<Commits<T>>::insert(query_id, reporter, CommitOf::<T> {
+				hash: Keccak256::hash(&i.to_be_bytes()),
+				timestamp: Tellor::<T>::now(),
+				block_number: frame_system::Pallet::<T>::block_number(),
+			});
+		}
+		// Commitments of query identifiers no longer using commit-reveal are pruned
+	}: {
+		Tellor::<T>::prune_commits(Tellor::<T>::now(), Weight::MAX)
+	}
+	verify {
+		assert_eq!(<Commits<T>>::iter_prefix(query_id).count(), 0);
+	}
+
+	migrate_values {
+		// Number of entries migrated
+		let n in 0..MAX_MIGRATED_VALUES_PER_BLOCK;
+		let query_data: QueryDataOf<T> = BoundedVec::try_from(vec![0u8; T::MaxQueryDataLength::get() as usize]).unwrap();
+		let query_id = Keccak256::hash(query_data.as_ref());
+		let reporter = account::<AccountIdOf<T>>("account", 1, SEED);
+
+		deposit_stake::<T>(reporter.clone(), trb(100), Address::zero())?;
+		for _ in 0..n {
+			T::BenchmarkHelper::set_time(REPORTING_LOCK);
+			Tellor::<T>::submit_value(
+				RawOrigin::Signed(reporter.clone()).into(),
+				query_id,
+				uint_value::<T>(1_000u64),
+				0,
+				query_data.clone())?;
+		}
+		// Revert reported values to the previous storage layout
+		for (index, reported) in <ReportedValues<T>>::drain_prefix(query_id) {
+			v0::ReportedTimestampsByIndex::<T>::insert(query_id, index, reported.timestamp);
+			if let Some(value) = <ReportedValueData<T>>::take(query_id, index) {
+				v0::ReportedValuesByTimestamp::<T>::insert(query_id, reported.timestamp, value);
+			}
+		}
+		if let Some((_, timestamp)) = <LastReported<T>>::take(query_id) {
+			v0::LastReportedTimestamp::<T>::insert(query_id, timestamp);
+		}
+		<MigrationInProgress<T>>::set(true);
+	}: {
+		v1::migrate::<T>(n)
+	}
+	verify {
+		assert_eq!(<ReportedValues<T>>::iter_prefix(query_id).count() as u32, n);
+		assert_eq!(<ReportedValueData<T>>::iter_prefix(query_id).count() as u32, n);
+	}
+
+	get_data_before {
+		// Number of binary search steps, over 2^s reported values
+		let s in 1..10;
+		let query_data: QueryDataOf<T> = BoundedVec::try_from(vec![0u8; T::MaxQueryDataLength::get() as usize]).unwrap();
+		let query_id = Keccak256::hash(query_data.as_ref());
+		let reporter = account::<AccountIdOf<T>>("account", 1, SEED);
+
+		deposit_stake::<T>(reporter.clone(), trb(100), Address::zero())?;
+		for _ in 0..2u32.saturating_pow(s) {
+			T::BenchmarkHelper::set_time(REPORTING_LOCK);
+			Tellor::<T>::submit_value(
+				RawOrigin::Signed(reporter.clone()).into(),
+				query_id,
+				BoundedVec::try_from(vec![1u8; T::MaxValueLength::get() as usize]).unwrap(),
+				0,
+				query_data.clone())?;
+		}
+		// Search for the earliest value, requiring the maximum number of search steps
+		let timestamp = Tellor::<T>::get_timestamp_by_query_id_and_index(query_id, 0).unwrap() + 1;
+	}: {
+		assert!(Tellor::<T>::get_data_before(query_id, timestamp).is_some())
+	}
+
+	get_multiple_values_before {
+		// Number of values read
+		let n in 1..100;
+		let query_data: QueryDataOf<T> = BoundedVec::try_from(vec![0u8; T::MaxQueryDataLength::get() as usize]).unwrap();
+		let query_id = Keccak256::hash(query_data.as_ref());
+		let reporter = account::<AccountIdOf<T>>("account", 1, SEED);
+
+		deposit_stake::<T>(reporter.clone(), trb(100), Address::zero())?;
+		for _ in 0..n {
+			T::BenchmarkHelper::set_time(REPORTING_LOCK);
+			Tellor::<T>::submit_value(
+				RawOrigin::Signed(reporter.clone()).into(),
+				query_id,
+				BoundedVec::try_from(vec![1u8; T::MaxValueLength::get() as usize]).unwrap(),
+				0,
+				query_data.clone())?;
+		}
+		T::BenchmarkHelper::set_time(MINUTES);
+		let timestamp = Tellor::<T>::now();
+	}: {
+		assert_eq!(
+			<Tellor<T> as UsingTellor<AccountIdOf<T>>>::get_multiple_values_before(query_id, timestamp, timestamp, n).len() as u32,
+			n
+		)
+	}
+
+	set_value_cache {
+		// Number of existing values cached
+		let n in 0..T::MaxCachedValues::get();
+		let query_data: QueryDataOf<T> = BoundedVec::try_from(vec![0u8; T::MaxQueryDataLength::get() as usize]).unwrap();
+		let query_id = Keccak256::hash(query_data.as_ref());
+		let reporter = account::<AccountIdOf<T>>("account", 1, SEED);
+		let caller = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		deposit_stake::<T>(reporter.clone(), trb(100), Address::zero())?;
+		for _ in 0..n {
+			T::BenchmarkHelper::set_time(REPORTING_LOCK);
+			Tellor::<T>::submit_value(
+				RawOrigin::Signed(reporter.clone()).into(),
+				query_id,
+				BoundedVec::try_from(vec![1u8; T::MaxValueLength::get() as usize]).unwrap(),
+				0,
+				query_data.clone())?;
+		}
+	}: _<RuntimeOrigin<T>>(caller, query_id, true)
+	verify {
+		assert_eq!(<ValueCaches<T>>::get(query_id).unwrap().len() as u32, n);
+	}
+
+	set_large_values {
+		let query_data: QueryDataOf<T> = BoundedVec::try_from(vec![0u8; T::MaxQueryDataLength::get() as usize]).unwrap();
+		let query_id = Keccak256::hash(query_data.as_ref());
+		let caller = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<RuntimeOrigin<T>>(caller, query_id, true)
+	verify {
+		assert!(<LargeValueQueries<T>>::contains_key(query_id));
+	}
+
+	note_value {
+		// Length of value
+		let n in 1..T::MaxLargeValueLength::get();
+		let reporter = account::<AccountIdOf<T>>("account", 1, SEED);
+		let value: LargeValueOf<T> = BoundedVec::try_from(vec![1u8; n as usize]).unwrap();
+		let hash = Keccak256::hash(value.as_ref());
+
+		T::BenchmarkHelper::set_balance(reporter.clone(), token::<T>(1_000u16));
+		deposit_stake::<T>(reporter.clone(), trb(100), Address::zero())?;
+	}: _(RawOrigin::Signed(reporter), value)
+	verify {
+		assert!(<PreimageValues<T>>::contains_key(hash));
+	}
+
+	retract_value {
+		// Maximum number of sequential disputed timestamps
+		let d in 1..T::MaxDisputedTimeSeries::get();
+		let query_data: QueryDataOf<T> = BoundedVec::try_from(vec![0u8; T::MaxQueryDataLength::get() as usize]).unwrap();
+		let query_id = Keccak256::hash(query_data.as_ref());
+		let stake_amount = <StakeAmount<T>>::get();
+		let dispute_fees = token::<T>(10u8);
+
+		T::BenchmarkHelper::set_time(REPORTING_LOCK);
+
+		// Create initial timestamp for later retraction in extrinsic call
+		let reporter = account::<AccountIdOf<T>>("account", 0, SEED);
+		deposit_stake::<T>(reporter.clone(), stake_amount, Address::zero())?;
+		T::BenchmarkHelper::set_balance(reporter.clone(), T::RetractionPenalty::get().saturating_add(dispute_fees));
+		fill_commitment_range::<T>(1);
+		Tellor::<T>::submit_value(
+			RawOrigin::Signed(reporter.clone()).into(), query_id, uint_value::<T>(10), 0, query_data.clone()
+		)?;
+
+		// Create series of disputed timestamps, using new accounts to avoid reporting lock
+		for i in 2..d {
+			T::BenchmarkHelper::set_time(1);
+			let reporter = account::<AccountIdOf<T>>("account", i, SEED);
+			deposit_stake::<T>(reporter.clone(), stake_amount, Address::zero())?;
+			T::BenchmarkHelper::set_balance(reporter.clone(), dispute_fees);
+			Tellor::<T>::submit_value(
+				RawOrigin::Signed(reporter.clone()).into(), query_id, uint_value::<T>(i * 10), 0, query_data.clone()
+			)?;
+			Tellor::<T>::begin_dispute(RawOrigin::Signed(reporter).into(),
+				query_id,
+				Tellor::<T>::get_timestamp_by_query_id_and_index(query_id, i - 1).unwrap(),
+				None)?;
+		}
+
+		let timestamp = Tellor::<T>::get_timestamp_by_query_id_and_index(query_id, 0).unwrap();
+	}: _(RawOrigin::Signed(reporter.clone()), query_id, timestamp)
+	verify {
+		assert_eq!(<RetractionsByReporter<T>>::get(&reporter), 1);
+		assert_last_event::<T>(
+			Event::ValueRetracted { query_id, timestamp, reporter, penalty: T::RetractionPenalty::get() }.into()
+		);
+	}
+
+	finalize_values {
+		// Number of values finalized
+		let n in 0..MAX_FINALIZED_VALUES_PER_BLOCK;
+		let query_data: QueryDataOf<T> = BoundedVec::try_from(vec![0u8; T::MaxQueryDataLength::get() as usize]).unwrap();
+		let query_id = Keccak256::hash(query_data.as_ref());
+		let reporter = account::<AccountIdOf<T>>("account", 1, SEED);
+
+		deposit_stake::<T>(reporter.clone(), trb(100), Address::zero())?;
+		for _ in 0..n {
+			T::BenchmarkHelper::set_time(REPORTING_LOCK);
+			Tellor::<T>::submit_value(
+				RawOrigin::Signed(reporter.clone()).into(),
+				query_id,
+				uint_value::<T>(1_000u64),
+				0,
+				query_data.clone())?;
+		}
+		T::BenchmarkHelper::set_time(REPORTING_LOCK);
+		let timestamp = Tellor::<T>::now();
+	}: {
+		Tellor::<T>::finalize_values(timestamp, n)
+	}
+	verify {
+		assert_eq!(<FinalityQueueRange<T>>::iter().count(), 0);
+	}
+
+	set_health_thresholds {
+		let query_data: QueryDataOf<T> = BoundedVec::try_from(vec![0u8; T::MaxQueryDataLength::get() as usize]).unwrap();
+		let query_id = Keccak256::hash(query_data.as_ref());
+		let caller = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		report_health_values::<T>(query_data, 0)?;
+		let thresholds = health_thresholds();
+	}: _<RuntimeOrigin<T>>(caller, query_id, Some(thresholds))
+	verify {
+		assert_eq!(<QueryHealthThresholds<T>>::get(query_id), Some(thresholds));
+	}
+
+	check_health {
+		// Number of query identifiers checked
+		let n in 0..MAX_HEALTH_CHECKS_PER_BLOCK;
+		let caller = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let mut query_ids = Vec::new();
+		for i in 0..n {
+			let query_data: QueryDataOf<T> = BoundedVec::try_from(vec![i as u8; T::MaxQueryDataLength::get() as usize]).unwrap();
+			let query_id = Keccak256::hash(query_data.as_ref());
+			// Frozen until values reported, so that health changes when checked
+			Tellor::<T>::set_health_thresholds(caller.clone(), query_id, Some(health_thresholds()))?;
+			report_health_values::<T>(query_data, i * HEALTH_WINDOW)?;
+			query_ids.push(query_id);
+		}
+	}: {
+		Tellor::<T>::check_health(Tellor::<T>::now(), n)
+	}
+	verify {
+		for query_id in query_ids {
+			assert_eq!(<QueryHealthStates<T>>::get(query_id), QueryHealth::Healthy);
+		}
+	}
+
+	set_emergency_value {
+		let query_id = Keccak256::hash(&[0u8; 1]);
+		let caller = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let value: ValueOf<T> = BoundedVec::try_from(vec![1u8; T::MaxValueLength::get() as usize]).unwrap();
+	}: _<RuntimeOrigin<T>>(caller, query_id, Some(value), T::MaxEmergencyValueDuration::get())
+	verify {
+		assert!(<EmergencyValues<T>>::contains_key(query_id));
+	}
+
+	set_derived_feed {
+		let caller = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let feed = DerivedFeed {
+			query_id_a: Keccak256::hash(&[0u8; 1]),
+			decimals_a: 18,
+			operation: DerivedOperation::Divide,
+			query_id_b: Keccak256::hash(&[1u8; 1]),
+			decimals_b: 18,
+			decimals: 18,
+		};
+	}: _<RuntimeOrigin<T>>(caller, feed, true)
+	verify {
+		assert_eq!(<DerivedFeeds<T>>::get(feed.query_id()), Some(feed));
+	}
+
+	subscribe {
+		let caller = T::SubscriberOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let location = T::SubscriberOrigin::ensure_origin(caller.clone()).map_err(|_| BenchmarkError::Weightless)?;
+		let subscriber = <T::SovereignAccountOf as xcm_executor::traits::Convert<_, _>>::convert(location)
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let query_id = Keccak256::hash(&[0u8; 1]);
+		let subscription_id = <SubscriptionCount<T>>::get();
+		T::BenchmarkHelper::set_balance(subscriber, token::<T>(1_000u16));
+	}: _<RuntimeOrigin<T>>(
+		caller,
+		query_id,
+		HOURS,
+		Permill::from_percent(1),
+		[0, 0],
+		Some([0, 1]),
+		Weight::from_parts(1_000_000_000, 65_536),
+		Box::new((Here, 1_000_000_000u128).into())
+	)
+	verify {
+		assert!(<Subscriptions<T>>::contains_key(subscription_id));
+		assert_eq!(<SubscriptionDeposits<T>>::get(subscription_id), Some(T::SubscriptionDeposit::get()));
+	}
+
+	unsubscribe {
+		let caller = T::SubscriberOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let location = T::SubscriberOrigin::ensure_origin(caller.clone()).map_err(|_| BenchmarkError::Weightless)?;
+		let subscriber = <T::SovereignAccountOf as xcm_executor::traits::Convert<_, _>>::convert(location)
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let query_id = Keccak256::hash(&[0u8; 1]);
+		let subscription_id = <SubscriptionCount<T>>::get();
+		T::BenchmarkHelper::set_balance(subscriber, token::<T>(1_000u16));
+		Tellor::<T>::subscribe(
+			caller.clone(),
+			query_id,
+			HOURS,
+			Permill::from_percent(1),
+			[0, 0],
+			Some([0, 1]),
+			Weight::from_parts(1_000_000_000, 65_536),
+			Box::new((Here, 1_000_000_000u128).into()))?;
+	}: _<RuntimeOrigin<T>>(caller, subscription_id)
+	verify {
+		assert!(!<Subscriptions<T>>::contains_key(subscription_id));
+		assert!(!<SubscriptionDeposits<T>>::contains_key(subscription_id));
+	}
+
+	push_values {
+		// Number of subscriptions pushed a value
+		let s in 0..T::MaxSubscriptions::get();
+		let query_data: QueryDataOf<T> = BoundedVec::try_from(vec![0u8; T::MaxQueryDataLength::get() as usize]).unwrap();
+		let query_id = Keccak256::hash(query_data.as_ref());
+		let reporter = account::<AccountIdOf<T>>("account", 1, SEED);
+		let caller = T::SubscriberOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let location = T::SubscriberOrigin::ensure_origin(caller.clone()).map_err(|_| BenchmarkError::Weightless)?;
+		let subscriber = <T::SovereignAccountOf as xcm_executor::traits::Convert<_, _>>::convert(location)
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		let deposits = T::SubscriptionDeposit::get().saturating_mul(s.into());
+		T::BenchmarkHelper::set_balance(subscriber.clone(), token::<T>(1_000u16).saturating_add(deposits));
+		for _ in 0..s {
+			Tellor::<T>::subscribe(
+				caller.clone(),
+				query_id,
+				0,
+				Permill::zero(),
+				[0, 0],
+				Some([0, 1]),
+				Weight::from_parts(1_000_000_000, 65_536),
+				Box::new((Here, 1_000_000_000u128).into()))?;
+		}
+		// Each subscription also pushes the removal of a previously pushed value
+		<Removals<T>>::insert(query_id, 0, 0);
+		<RemovalCount<T>>::insert(query_id, 1);
+		<Subscriptions<T>>::translate(|_, mut subscription: Subscription| {
+			subscription.last_pushed = Some((0, None));
+			Some(subscription)
+		});
+		deposit_stake::<T>(reporter.clone(), trb(100), Address::zero())?;
+		T::BenchmarkHelper::set_time(HOURS);
+		Tellor::<T>::submit_value(
+			RawOrigin::Signed(reporter).into(),
+			query_id,
+			BoundedVec::try_from(vec![1u8; T::MaxValueLength::get() as usize]).unwrap(),
+			0,
+			query_data)?;
+		T::BenchmarkHelper::set_time(T::SubscriptionDisputeBuffer::get());
+		let timestamp = Tellor::<T>::now();
+	}: {
+		Tellor::<T>::process_subscriptions(timestamp, Weight::MAX)
+	}
+	verify {
+		for (_, subscription) in <Subscriptions<T>>::iter() {
+			assert_eq!(subscription.removals, 1);
+			assert!(subscription.last_pushed.map_or(false, |(timestamp, _)| timestamp > 0));
+		}
+	}
+
+	query_value {
+		// Length of value
+		let n in 1..T::MaxLargeValueLength::get();
+		let query_data: QueryDataOf<T> = BoundedVec::try_from(vec![0u8; T::MaxQueryDataLength::get() as usize]).unwrap();
+		let query_id = Keccak256::hash(query_data.as_ref());
+		let reporter = account::<AccountIdOf<T>>("account", 1, SEED);
+		let governance = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let caller = T::RemoteQueryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let location = T::RemoteQueryOrigin::ensure_origin(caller.clone()).map_err(|_| BenchmarkError::Weightless)?;
+		let para_id = Tellor::<T>::sibling(&location).ok_or(BenchmarkError::Weightless)?;
+		let querier = <T::SovereignAccountOf as xcm_executor::traits::Convert<_, _>>::convert(location)
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		T::BenchmarkHelper::set_balance(querier, token::<T>(1_000u16));
+		T::BenchmarkHelper::set_balance(reporter.clone(), token::<T>(1_000u16));
+		deposit_stake::<T>(reporter.clone(), trb(100), Address::zero())?;
+		// Reply with a large value of length n
+		Tellor::<T>::set_large_values(governance, query_id, true)?;
+		let value: LargeValueOf<T> = BoundedVec::try_from(vec![1u8; n as usize]).unwrap();
+		let reference = ValueReference { hash: Keccak256::hash(value.as_ref()), len: n };
+		Tellor::<T>::note_value(RawOrigin::Signed(reporter.clone()).into(), value)?;
+		T::BenchmarkHelper::set_time(HOURS);
+		Tellor::<T>::submit_value(
+			RawOrigin::Signed(reporter).into(),
+			query_id,
+			BoundedVec::try_from(reference.encode()).unwrap(),
+			0,
+			query_data)?;
+		let timestamp = Tellor::<T>::now();
+		T::BenchmarkHelper::set_time(MINUTES);
+	}: _<RuntimeOrigin<T>>(
+		caller,
+		query_id,
+		Tellor::<T>::now(),
+		HOURS,
+		0,
+		[0, 0],
+		Weight::from_parts(1_000_000_000, 65_536),
+		Box::new((Here, 1_000_000_000u128).into())
+	)
+	verify {
+		assert_last_event::<T>(Event::RemoteQueryAnswered { para_id, query_id, timestamp: Some(timestamp) }.into());
+	}
+
+	unnote_value {
+		let reporter = account::<AccountIdOf<T>>("account", 1, SEED);
+		let value: LargeValueOf<T> = BoundedVec::try_from(vec![1u8; T::MaxLargeValueLength::get() as usize]).unwrap();
+		let hash = Keccak256::hash(value.as_ref());
+
+		T::BenchmarkHelper::set_balance(reporter.clone(), token::<T>(1_000u16));
+		deposit_stake::<T>(reporter.clone(), trb(100), Address::zero())?;
+		Tellor::<T>::note_value(RawOrigin::Signed(reporter.clone()).into(), value)?;
+	}: _(RawOrigin::Signed(reporter.clone()), hash)
+	verify {
+		assert!(!<PreimageValues<T>>::contains_key(hash));
+		assert_last_event::<T>(Event::ValueUnnoted { hash, reporter }.into());
+	}
+
 	impl_benchmark_test_suite!(Tellor, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -0,0 +1,176 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+//! Commitments to reported values, allowing external chains and light clients to verify a value
+//! reported on this chain against a block header.
+//!
+//! Each report appends a [`Leaf`] to a Merkle Mountain Range (MMR) when it is submitted, which is
+//! updated in place to mark it as disputed when it is removed by a dispute, so that the range
+//! commits to the current state of each value rather than to its history. The resulting
+//! [`Commitment`] is deposited as a digest item prefixed with [`DIGEST_PREFIX`] in each block in
+//! which leaves were appended or updated, and a [`Proof`] of the leaf of a value can be obtained
+//! via the runtime API. The verifier is `no_std` and depends only on SCALE encoding and Keccak-256
+//! hashing, so that it can also be used by other parachains or contracts.
+//!
+//! Nodes of the range are addressed by height and index, where the node at height `h` and index
+//! `i` commits to leaves `i * 2^h` up to (but excluding) `(i + 1) * 2^h`. As each reported value
+//! has a single leaf, the range holds fewer than twice as many nodes as values ever reported, each
+//! a 32-byte hash, with heights below 64. Appending or updating a leaf rewrites at most one node
+//! per height. The leaves of pruned values are no longer tracked and so can no longer be updated,
+//! but their nodes are retained, as they remain part of the proofs of later leaves.
+
+use crate::types::{QueryId, Timestamp};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::{hashing::keccak_256, H256};
+use sp_runtime::{DigestItem, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// The prefix of the digest items containing value commitments.
+pub const DIGEST_PREFIX: [u8; 4] = *b"TLLR";
+
+/// A commitment to all leaves appended to the range, deposited as a digest item.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Commitment {
+	/// The number of leaves in the range.
+	pub leaf_count: u64,
+	/// The root of the range.
+	pub root: H256,
+}
+
+impl Commitment {
+	/// Returns the commitment from a digest item.
+	/// # Arguments
+	/// * `item` - A digest item of a block header.
+	/// # Returns
+	/// The commitment, if the digest item contains one.
+	pub fn from_digest_item(item: &DigestItem) -> Option<Self> {
+		match item {
+			DigestItem::Other(data) => match <([u8; 4], Self)>::decode(&mut data.as_slice()) {
+				Ok((DIGEST_PREFIX, commitment)) => Some(commitment),
+				_ => None,
+			},
+			_ => None,
+		}
+	}
+
+	/// Returns the digest item containing the commitment.
+	pub fn to_digest_item(&self) -> DigestItem {
+		DigestItem::Other((DIGEST_PREFIX, self).encode())
+	}
+}
+
+/// A leaf committing to the current state of a reported value.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Leaf {
+	/// The query identifier of the reported value.
+	pub query_id: QueryId,
+	/// The timestamp of the reported value.
+	pub timestamp: Timestamp,
+	/// The Keccak-256 hash of the reported value (or of the value reference of large values).
+	pub value_hash: H256,
+	/// Whether the reported value has been disputed.
+	pub disputed: bool,
+}
+
+impl Leaf {
+	/// Returns the hash of the leaf.
+	pub fn hash(&self) -> H256 {
+		keccak_256(&self.encode()).into()
+	}
+}
+
+/// A proof of membership of a leaf within the range.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Proof {
+	/// The index of the leaf.
+	pub leaf_index: u64,
+	/// The number of leaves in the range when the proof was generated.
+	pub leaf_count: u64,
+	/// The sibling hashes from the leaf up to its peak, starting at the leaf.
+	pub siblings: Vec<H256>,
+	/// The hashes of all peaks of the range, highest first.
+	pub peaks: Vec<H256>,
+}
+
+/// Returns the hash of a parent node.
+/// # Arguments
+/// * `left` - The hash of the left child.
+/// * `right` - The hash of the right child.
+/// # Returns
+/// The Keccak-256 hash of both child hashes.
+pub fn node_hash(left: &H256, right: &H256) -> H256 {
+	keccak_256(&[left.as_bytes(), right.as_bytes()].concat()).into()
+}
+
+/// Returns the positions of the peaks of a range.
+/// # Arguments
+/// * `leaf_count` - The number of leaves in the range.
+/// # Returns
+/// The height and index of each peak, highest first.
+pub fn peaks(leaf_count: u64) -> Vec<(u8, u64)> {
+	(0..u64::BITS as u8)
+		.rev()
+		.filter(|height| leaf_count & (1 << height) != 0)
+		.map(|height| (height, (leaf_count >> height) - 1))
+		.collect()
+}
+
+/// Returns the root of a range.
+/// # Arguments
+/// * `leaf_count` - The number of leaves in the range.
+/// * `peaks` - The hashes of all peaks of the range, highest first.
+/// # Returns
+/// The Keccak-256 hash of the leaf count and peaks.
+pub fn root(leaf_count: u64, peaks: &[H256]) -> H256 {
+	keccak_256(&(leaf_count, peaks).encode()).into()
+}
+
+/// Verifies that a leaf is a member of a range.
+/// # Arguments
+/// * `commitment` - The commitment to the range, as deposited in a block header.
+/// * `leaf` - The leaf to be verified.
+/// * `proof` - The proof of membership of the leaf.
+/// # Returns
+/// Whether the leaf is a member of the committed range.
+pub fn verify(commitment: &Commitment, leaf: &Leaf, proof: &Proof) -> bool {
+	if proof.leaf_count != commitment.leaf_count || proof.leaf_index >= proof.leaf_count {
+		return false;
+	}
+	let positions = peaks(proof.leaf_count);
+	if positions.len() != proof.peaks.len() {
+		return false;
+	}
+	// Locate the peak committing to the leaf
+	let Some((peak, (height, _))) = positions
+		.iter()
+		.enumerate()
+		.find(|(_, (height, index))| proof.leaf_index >> height == *index)
+	else {
+		return false;
+	};
+	if proof.siblings.len() != *height as usize {
+		return false;
+	}
+	// Compute the peak from the leaf and its siblings
+	let mut index = proof.leaf_index;
+	let mut hash = leaf.hash();
+	for sibling in &proof.siblings {
+		hash = if index & 1 == 0 { node_hash(&hash, sibling) } else { node_hash(sibling, &hash) };
+		index >>= 1;
+	}
+	hash == proof.peaks[peak] && root(proof.leaf_count, &proof.peaks) == commitment.root
+}
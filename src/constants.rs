@@ -24,14 +24,52 @@ pub const WEEKS: Timestamp = 7 * DAYS;
 /// The number of decimals of the TRB token.
 pub(crate) const DECIMALS: u32 = 18;
 
+/// The maximum number of reported values finalized per block.
+pub(crate) const MAX_FINALIZED_VALUES_PER_BLOCK: u32 = 100;
+
+/// The number of recent values inspected when assessing the health of a query identifier.
+pub(crate) const HEALTH_WINDOW: u32 = 10;
+
+/// The maximum number of feeds, funded or not, read when assessing the staleness of a query identifier.
+pub(crate) const MAX_HEALTH_FEEDS: u32 = 10;
+
+/// The maximum number of query identifiers whose health is checked per block.
+pub(crate) const MAX_HEALTH_CHECKS_PER_BLOCK: u32 = 10;
+
 /// The maximum number of iterations for a binary search.
 pub(super) const MAX_ITERATIONS: u32 = 32;
 
+/// The maximum number of reported values migrated to the current storage layout per block.
+pub(crate) const MAX_MIGRATED_VALUES_PER_BLOCK: u32 = 100;
+
+/// The maximum number of pending data requests processed per block, before any idle weight is used.
+pub(crate) const MAX_REQUESTS_PROCESSED_PER_BLOCK: u32 = 10;
+
+/// The maximum number of attempts to push a value to a subscriber before it is skipped.
+pub(crate) const MAX_PUSH_ATTEMPTS: u8 = 3;
+
+/// The maximum number of removals of disputed values retained per query identifier for delivery to
+/// subscribers, with older removals no longer pushed.
+pub(crate) const MAX_PENDING_REMOVALS: u32 = 100;
+
+/// The maximum number of values inspected when counting distinct reporters within a read window.
+pub(super) const MAX_READ_POLICY_VALUES: u32 = 50;
+
 /// The maximum number of aggregate votes on disputes sent to the governance controller contract per block.
 pub(super) const MAX_AGGREGATE_VOTES_SENT_PER_BLOCK: u8 = 3;
 
+/// The amount of time after a value is reported within which data feed rewards can be claimed.
+pub(crate) const CLAIM_PERIOD: Timestamp = 4 * WEEKS;
+
+/// The minimum retention period of reported values, ensuring values remain available throughout the dispute
+/// and data feed claim windows of later values. Extended by any longer reporting lock of a risk class.
+pub(crate) const MIN_RETENTION_PERIOD: Timestamp = CLAIM_PERIOD + REPORTING_LOCK;
+
 /// The maximum number of vote rounds per dispute.
 pub const MAX_VOTE_ROUNDS: u8 = 20;
 
+/// The tag prefixed to signed value reports, separating them from other signed messages.
+pub(crate) const REPORT_PAYLOAD_TAG: &[u8] = b"tellor:report";
+
 /// Base amount of time before a reporter is able to submit a value again.
 pub(crate) const REPORTING_LOCK: Timestamp = 12 * HOURS;
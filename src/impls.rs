@@ -15,12 +15,24 @@
 // along with Tellor. If not, see <http://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::constants::DECIMALS;
-use ::xcm::prelude::Parachain;
-use frame_support::traits::{fungible::Inspect, tokens::Preservation};
+use crate::{
+	constants::{
+		CLAIM_PERIOD, DECIMALS, HEALTH_WINDOW, MAX_PENDING_REMOVALS, MAX_PUSH_ATTEMPTS,
+		MIN_RETENTION_PERIOD, REPORT_PAYLOAD_TAG,
+	},
+	contracts::Abi,
+	traits::{OnNewReport, OnValueRemoved},
+};
+use ::xcm::prelude::{MultiLocation, Parachain, X1};
+use codec::DecodeAll;
+use frame_support::{
+	pallet_prelude::{InvalidTransaction, TransactionValidity, ValidTransaction},
+	traits::{fungible::Inspect, tokens::Preservation, PalletInfoAccess},
+	weights::Weight,
+};
 use sp_runtime::{
-	traits::{CheckedAdd, CheckedMul, CheckedSub, Hash},
-	ArithmeticError, SaturatedConversion,
+	traits::{CheckedAdd, CheckedMul, CheckedSub, Hash, Verify},
+	ArithmeticError, PerThing, Percent, Permill, SaturatedConversion,
 };
 use sp_std::cmp::Ordering;
 
@@ -33,6 +45,81 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account_truncating()
 	}
 
+	/// Assesses the health of a query identifier against its health thresholds.
+	/// # Arguments
+	/// * `query_id` - Identifier of the specific data feed.
+	/// * `thresholds` - The thresholds at which the query identifier is considered degraded or frozen.
+	/// * `timestamp` - The current time.
+	/// # Returns
+	/// The health of the query identifier.
+	pub(super) fn assess_health(
+		query_id: QueryId,
+		thresholds: &HealthThresholds,
+		timestamp: Timestamp,
+	) -> QueryHealth {
+		let open_disputes = Self::get_open_disputes_on_id(query_id);
+
+		// Inspect the most recent values for disputes and distinct reporters
+		let count = <ReportedTimestampCount<T>>::get(query_id);
+		let first = <FirstRetainedIndex<T>>::get(query_id).max(count.saturating_sub(HEALTH_WINDOW));
+		let mut inspected: u32 = 0;
+		let mut disputed: u32 = 0;
+		let mut reporters = Vec::new();
+		for index in first..count {
+			let Some(reported) = Self::indexed_value(query_id, index) else {
+				continue;
+			};
+			inspected.saturating_inc();
+			if reported.is_disputed {
+				disputed.saturating_inc();
+				continue;
+			}
+			if !reporters.contains(&reported.reporter) {
+				reporters.push(reported.reporter);
+			}
+		}
+		let disputed_ratio = Percent::from_rational(disputed, inspected.max(1));
+
+		// Measure staleness against the shortest interval of the funded feeds of the query identifier
+		let interval =
+			<FundedFeedIntervals<T>>::get(query_id).first().map(|(interval, _)| *interval);
+		let stale_intervals = match (interval, Self::last_reported(query_id)) {
+			(Some(interval), Some((_, reported))) => timestamp.saturating_sub(reported) / interval,
+			_ => 0,
+		};
+
+		let reached = |limits: &HealthLimits| {
+			(limits.open_disputes > 0 && open_disputes >= limits.open_disputes)
+				|| (limits.disputed_ratio > Percent::zero()
+					&& disputed_ratio >= limits.disputed_ratio)
+				|| (limits.stale_intervals > 0 && stale_intervals >= limits.stale_intervals.into())
+				|| (reporters.len() as u32) < limits.min_reporters
+		};
+		if reached(&thresholds.frozen) {
+			QueryHealth::Frozen
+		} else if reached(&thresholds.degraded) {
+			QueryHealth::Degraded
+		} else {
+			QueryHealth::Healthy
+		}
+	}
+
+	/// Appends a newly reported value to the value cache of a query identifier, if enabled,
+	/// evicting the oldest cached value when full.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported value.
+	/// * `timestamp` - The timestamp of the reported value.
+	/// * `value` - The reported value.
+	pub(super) fn cache_value(query_id: QueryId, timestamp: Timestamp, value: &ValueOf<T>) {
+		let Some(mut cached) = <ValueCaches<T>>::get(query_id) else { return };
+		if cached.is_full() && !cached.is_empty() {
+			cached.remove(0);
+		}
+		if cached.try_push((timestamp, value.clone())).is_ok() {
+			<ValueCaches<T>>::insert(query_id, cached);
+		}
+	}
+
 	/// Calculates the latest dispute fee based on the supplied price.
 	/// # Arguments
 	/// * `price` - The current staking token to local balance price.
@@ -53,6 +140,101 @@ impl<T: Config> Pallet<T> {
 		.map(<U256ToBalance<T>>::convert)
 	}
 
+	/// Checks the health of query identifiers with health thresholds, resuming from the last query
+	/// identifier checked.
+	/// # Arguments
+	/// * `timestamp` - The current time.
+	/// * `limit` - The maximum number of query identifiers to check.
+	/// # Returns
+	/// The number of query identifiers checked.
+	pub(super) fn check_health(timestamp: Timestamp, limit: u32) -> u32 {
+		let thresholds = match <HealthCheckCursor<T>>::get() {
+			Some(query_id) => <QueryHealthThresholds<T>>::iter_from(
+				<QueryHealthThresholds<T>>::hashed_key_for(query_id),
+			),
+			None => <QueryHealthThresholds<T>>::iter(),
+		};
+		let mut checked = 0;
+		let mut cursor = None;
+		for (query_id, thresholds) in thresholds.take(limit as usize) {
+			Self::record_health(query_id, Self::assess_health(query_id, &thresholds, timestamp));
+			checked.saturating_inc();
+			cursor = Some(query_id);
+		}
+		match cursor {
+			// Resume after the last query identifier checked on next pass
+			Some(query_id) if checked == limit => <HealthCheckCursor<T>>::set(Some(query_id)),
+			// Start from the first query identifier on next pass
+			_ => <HealthCheckCursor<T>>::kill(),
+		}
+		checked
+	}
+
+	/// Commits to the current state of a reported value, appending a leaf to the commitment range
+	/// for a newly reported value or updating the existing leaf of the value, and updates the
+	/// commitment to be deposited when the block is finalized.
+	/// # Arguments
+	/// * `leaf` - The leaf committing to the state of the reported value.
+	pub(super) fn commit_value(leaf: commitments::Leaf) {
+		let mut leaf_count = <CommitmentLeafCount<T>>::get();
+		let leaf_index = match <CommitmentLeaves<T>>::get(leaf.query_id, leaf.timestamp) {
+			Some((leaf_index, _)) => leaf_index,
+			None => {
+				leaf_count.saturating_inc();
+				<CommitmentLeafCount<T>>::set(leaf_count);
+				leaf_count.saturating_sub(1)
+			},
+		};
+		// Recompute the nodes from the leaf up to the peak of the range containing it
+		let peak_height = commitments::peaks(leaf_count)
+			.into_iter()
+			.find(|(height, index)| leaf_index >> height == *index)
+			.map_or(0, |(height, _)| height);
+		let mut hash = leaf.hash();
+		<CommitmentNodes<T>>::insert(0, leaf_index, hash);
+		for height in 0..peak_height {
+			let index = leaf_index >> height;
+			let sibling = <CommitmentNodes<T>>::get(height, index ^ 1).unwrap_or_default();
+			hash = if index & 1 == 0 {
+				commitments::node_hash(&hash, &sibling)
+			} else {
+				commitments::node_hash(&sibling, &hash)
+			};
+			<CommitmentNodes<T>>::insert(height.saturating_add(1), index >> 1, hash);
+		}
+		<CommitmentLeaves<T>>::insert(leaf.query_id, leaf.timestamp, (leaf_index, leaf));
+		<PendingCommitment<T>>::set(Self::get_value_commitment());
+	}
+
+	/// Computes the commitment of a reporter to a value for a query identifier using commit-reveal.
+	/// # Arguments
+	/// * `reporter` - The reporter committing to the value.
+	/// * `query_id` - Identifier of the specific data feed.
+	/// * `value` - The value committed to.
+	/// * `salt` - The secret salt, revealed along with the value.
+	/// # Returns
+	/// The keccak256 hash of the SCALE-encoded reporter, query identifier, value and salt.
+	pub fn commitment(
+		reporter: &AccountIdOf<T>,
+		query_id: QueryId,
+		value: &ValueOf<T>,
+		salt: H256,
+	) -> CommitHash {
+		(reporter, query_id, value, salt).using_encoded(Keccak256::hash)
+	}
+
+	/// Returns the peaks of the commitment range.
+	/// # Arguments
+	/// * `leaf_count` - The number of leaves in the range.
+	/// # Returns
+	/// The hashes of all peaks of the range, highest first.
+	pub(super) fn commitment_peaks(leaf_count: u64) -> Vec<H256> {
+		commitments::peaks(leaf_count)
+			.into_iter()
+			.map(|(height, index)| <CommitmentNodes<T>>::get(height, index).unwrap_or_default())
+			.collect()
+	}
+
 	/// Converts a stake amount to a local balance amount.
 	/// # Arguments
 	/// * `stake_amount` - The amount staked.
@@ -109,6 +291,20 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_sub_account_truncating(b"dispute")
 	}
 
+	/// Returns the identifier of a dispute of a reported value.
+	/// # Arguments
+	/// * `query_id` - Identifier of the specific data feed.
+	/// * `timestamp` - Timestamp of the disputed value.
+	/// # Returns
+	/// The dispute identifier.
+	pub(super) fn dispute_id(query_id: QueryId, timestamp: Timestamp) -> DisputeId {
+		Keccak256::hash(&contracts::encode(&[
+			Abi::Uint(T::ParachainId::get().into()),
+			Abi::FixedBytes(query_id.0.into()),
+			Abi::Uint(timestamp.into()),
+		]))
+	}
+
 	/// Funds the staking account with staking rewards from the source account.
 	/// # Arguments
 	/// * `source` - The source account.
@@ -165,6 +361,7 @@ impl<T: Config> Pallet<T> {
 		T::Asset::transfer(&feed_funder, &Self::tips(), amount, Preservation::Expendable)?;
 		// Add to feeds with funding
 		<FeedsWithFunding<T>>::insert(feed_id, ());
+		Self::note_funded_feed(query_id, feed_id, feed.interval);
 		<DataFeeds<T>>::insert(query_id, feed_id, &feed);
 		<UserTipsTotal<T>>::mutate(&feed_funder, |total| total.saturating_accrue(amount));
 		Self::deposit_event(Event::DataFeedFunded {
@@ -190,7 +387,7 @@ impl<T: Config> Pallet<T> {
 		timestamp: Timestamp,
 	) -> Result<BalanceOf<T>, DispatchError> {
 		ensure!(
-			Self::now().checked_sub(timestamp).ok_or(ArithmeticError::Underflow)? < 4 * WEEKS,
+			Self::now().checked_sub(timestamp).ok_or(ArithmeticError::Underflow)? < CLAIM_PERIOD,
 			Error::<T>::ClaimPeriodExpired
 		);
 
@@ -212,14 +409,16 @@ impl<T: Config> Pallet<T> {
 		let mut price_change = 0; // price change from last value to current value
 		if feed.price_threshold != 0 {
 			// v1 is value retrieved at supplied timestamp
-			let value = <ReportedValuesByTimestamp<T>>::get(query_id, timestamp)
-				.ok_or(Error::<T>::InvalidValue)?;
+			let value =
+				Self::reported_value(query_id, report.index).ok_or(Error::<T>::InvalidValue)?;
 			ensure!(value.len() != 0, Error::<T>::InvalidValue);
 			let v1 =
 				BytesToU256::convert(value.into_inner()).ok_or(Error::<T>::ValueConversionError)?;
 			// v2 is latest value retrieved BEFORE supplied timestamp
-			let value_before =
-				<ReportedValuesByTimestamp<T>>::get(query_id, timestamp_before).unwrap_or_default();
+			let value_before = <Reports<T>>::get(query_id, timestamp_before)
+				.filter(|report| !report.is_disputed)
+				.and_then(|report| Self::reported_value(query_id, report.index))
+				.unwrap_or_default();
 			let v2 = BytesToU256::convert(value_before.into_inner())
 				.ok_or(Error::<T>::ValueConversionError)?;
 			if v2 == U256::zero() {
@@ -317,6 +516,83 @@ impl<T: Config> Pallet<T> {
 		Ok(pending_votes_len)
 	}
 
+	/// Adds a tip for a query identifier, transferring the amount from the tipper.
+	/// # Arguments
+	/// * `tipper` - The account funding the tip.
+	/// * `query_id` - Identifier of tipped data.
+	/// * `amount` - Amount to tip.
+	/// * `query_data` - The data used by reporters to fulfil the query.
+	pub(super) fn do_tip(
+		tipper: AccountIdOf<T>,
+		query_id: QueryId,
+		amount: BalanceOf<T>,
+		query_data: QueryDataOf<T>,
+	) -> DispatchResult {
+		ensure!(query_id == Keccak256::hash(query_data.as_ref()), Error::<T>::InvalidQueryId);
+		ensure!(amount > Zero::zero(), Error::<T>::InvalidAmount);
+
+		// Tips only reward values reported after them, which may be timestamped ahead of the current
+		// time when reported within the same second
+		let last_reported_timestamp =
+			Self::last_reported(query_id).map(|(_, t)| t).unwrap_or_default();
+		let timestamp = Self::now()
+			.max(last_reported_timestamp)
+			.checked_add(1u8.into())
+			.ok_or(ArithmeticError::Overflow)?;
+		let tip_count = <TipCount<T>>::get(query_id);
+		if tip_count == 0 {
+			<Tips<T>>::insert(
+				query_id,
+				tip_count,
+				TipOf::<T> { amount, timestamp, cumulative_tips: amount },
+			);
+			<TipCount<T>>::mutate(query_id, |count| count.saturating_inc());
+			Self::store_data(query_id, &query_data);
+		} else {
+			let last_tip = <Tips<T>>::get(
+				query_id,
+				tip_count.checked_sub(1).expect("tip_count is always greater than zero; qed"),
+			);
+			match last_tip {
+				Some(mut last_tip) if last_reported_timestamp < last_tip.timestamp => {
+					last_tip.timestamp = timestamp;
+					last_tip.amount.saturating_accrue(amount);
+					last_tip.cumulative_tips.saturating_accrue(amount);
+					<Tips<T>>::set(
+						query_id,
+						tip_count
+							.checked_sub(1)
+							.expect("tip_count is always greater than zero; qed"),
+						Some(last_tip),
+					);
+				},
+				_ => {
+					let cumulative_tips = last_tip.map_or(Zero::zero(), |t| t.cumulative_tips);
+					<Tips<T>>::insert(
+						query_id,
+						tip_count,
+						Tip {
+							amount,
+							timestamp,
+							cumulative_tips: cumulative_tips
+								.checked_add(&amount)
+								.ok_or(ArithmeticError::Overflow)?,
+						},
+					);
+					<TipCount<T>>::mutate(query_id, |count| count.saturating_inc());
+				},
+			}
+		}
+
+		if Self::get_current_tip(query_id) > Zero::zero() {
+			<QueryIdsWithFunding<T>>::insert(query_id, ());
+		}
+		T::Asset::transfer(&tipper, &Self::tips(), amount, Preservation::Expendable)?;
+		<UserTipsTotal<T>>::mutate(&tipper, |total| total.saturating_accrue(amount));
+		Self::deposit_event(Event::TipAdded { query_id, amount, query_data, tipper });
+		Ok(())
+	}
+
 	// Updates the stake amount after retrieving the latest token price from oracle.
 	pub(super) fn do_update_stake_amount() -> Result<u32, DispatchError> {
 		let (Some((value, _)), iterations) = Self::get_data_before_with_start(
@@ -410,11 +686,80 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Ensures that a reporter is sufficiently staked and abides by the reporting lock of a risk class.
+	/// # Arguments
+	/// * `reporter` - The identifier of the reporter.
+	/// * `risk_class` - The risk class of the query identifier(s) being reported.
+	/// * `timestamp` - The current time.
+	/// # Returns
+	/// The staking info of the reporter if they are able to report.
+	pub(super) fn ensure_can_report(
+		reporter: &AccountIdOf<T>,
+		risk_class: RiskClass,
+		timestamp: Timestamp,
+	) -> Result<StakeInfoOf<T>, DispatchError> {
+		let staker = <StakerDetails<T>>::get(reporter).ok_or(Error::<T>::InsufficientStake)?;
+		let stake_requirement = <StakeAmount<T>>::get()
+			.checked_mul(risk_class.stake_multiple.into())
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(staker.staked_balance >= stake_requirement, Error::<T>::InsufficientStake);
+		ensure!(
+			U256::from(
+				timestamp
+					.checked_sub(staker.reporter_last_timestamp)
+					.ok_or(ArithmeticError::Underflow)?
+			)
+			.checked_mul(1_000.into())
+			.ok_or(ArithmeticError::Overflow)?
+				> (U256::from(risk_class.reporting_lock)
+					.checked_mul(1_000.into())
+					.ok_or(ArithmeticError::Overflow)?)
+				.checked_div(
+					staker
+						.staked_balance
+						.checked_div(stake_requirement)
+						.ok_or(ArithmeticError::DivisionByZero)?
+				)
+				.ok_or(ArithmeticError::DivisionByZero)?,
+			Error::<T>::ReporterTimeLocked
+		);
+		Ok(staker)
+	}
+
+	/// Ensures that a submission is valid for the query identifier.
+	/// # Arguments
+	/// * `query_id` - Identifier of the specific data feed.
+	/// * `value` - Value the reporter submits to the oracle.
+	/// * `nonce` - The current value count for the query identifier.
+	/// * `query_data` - The data used to fulfil the data query.
+	pub(super) fn ensure_valid_submission(
+		query_id: QueryId,
+		value: &ValueOf<T>,
+		nonce: Nonce,
+		query_data: &QueryDataOf<T>,
+	) -> DispatchResult {
+		ensure!(!value.is_empty(), Error::<T>::InvalidValue);
+		ensure!(
+			nonce == <ReportedTimestampCount<T>>::get(query_id) || nonce == 0,
+			Error::<T>::InvalidNonce
+		);
+		ensure!(
+			!<CommitRevealQueries<T>>::contains_key(query_id),
+			Error::<T>::CommitRevealRequired
+		);
+		ensure!(!<DerivedFeeds<T>>::contains_key(query_id), Error::<T>::DerivedFeedQuery);
+		ensure!(query_id == Keccak256::hash(query_data.as_ref()), Error::<T>::InvalidQueryId);
+		Self::value_reference(query_id, value)?;
+		Ok(())
+	}
+
 	/// Executes the vote and transfers corresponding dispute fees to initiator/reporter.
 	/// # Arguments
 	/// * `dispute_id` - The identifier of the dispute.
+	/// # Returns
+	/// The final vote round and the weight consumed by the `OnValueRemoved` handler.
 	#[allow(clippy::identity_op)]
-	pub(super) fn execute_vote(dispute_id: DisputeId) -> Result<u8, DispatchError> {
+	pub(super) fn execute_vote(dispute_id: DisputeId) -> Result<(u8, Weight), DispatchError> {
 		// Ensure validity of dispute id, vote has been executed, and vote must be tallied
 		ensure!(
 			dispute_id != <DisputeId>::default()
@@ -424,10 +769,10 @@ impl<T: Config> Pallet<T> {
 		);
 		let final_vote_round = <VoteRounds<T>>::get(dispute_id);
 		ensure!(final_vote_round > 0, Error::<T>::InvalidVote);
-		let result = <VoteInfo<T>>::try_mutate(
+		let (dispute, result) = <VoteInfo<T>>::try_mutate(
 			dispute_id,
 			final_vote_round,
-			|maybe| -> Result<VoteResult, DispatchError> {
+			|maybe| -> Result<(DisputeOf<T>, VoteResult), DispatchError> {
 				match maybe {
 					None => Err(Error::<T>::InvalidVote.into()),
 					Some(vote) => {
@@ -478,13 +823,68 @@ impl<T: Config> Pallet<T> {
 								Preservation::Protect,
 							)?;
 						}
-						Ok(result)
+						Ok((dispute, result))
 					},
 				}
 			},
 		)?;
 		Self::deposit_event(Event::VoteExecuted { dispute_id, result });
-		Ok(final_vote_round)
+		let hook_weight =
+			T::OnValueRemoved::on_vote_executed(dispute.query_id, dispute.timestamp, result);
+		Ok((final_vote_round, hook_weight))
+	}
+
+	/// Finalizes reported values whose dispute window has elapsed without dispute. Values are queued
+	/// by the length of their dispute window and finalized in the order reported within each queue,
+	/// so that a value only delays the finality of subsequent values with the same dispute window.
+	/// # Arguments
+	/// * `timestamp` - The current time.
+	/// * `limit` - The maximum number of queues and queued values to process.
+	/// # Returns
+	/// The number of queues and queued values processed.
+	pub(super) fn finalize_values(timestamp: Timestamp, limit: u32) -> u32 {
+		let mut processed = 0;
+		let queues: Vec<_> = <FinalityQueueRange<T>>::iter().take(limit as usize).collect();
+		for (reporting_lock, (mut head, tail)) in queues {
+			if processed >= limit {
+				break;
+			}
+			processed.saturating_inc();
+			let start = head;
+			while head < tail && processed < limit {
+				if let Some((query_id, reported, due)) =
+					<FinalityQueue<T>>::get(reporting_lock, head)
+				{
+					if timestamp < due {
+						break;
+					}
+					<FinalityQueue<T>>::remove(reporting_lock, head);
+					// Values removed by a dispute (or since pruned) are never final, whilst values
+					// already final following a shortened reporting lock remain so
+					if let Some(report) =
+						<Reports<T>>::get(query_id, reported).filter(|report| !report.is_disputed)
+					{
+						if <LastFinalized<T>>::get(query_id)
+							.map_or(true, |(_, finalized)| reported > finalized)
+						{
+							<LastFinalized<T>>::insert(query_id, (report.index, reported));
+							Self::deposit_event(Event::ValueFinalized {
+								query_id,
+								timestamp: reported,
+							});
+						}
+					}
+				}
+				head.saturating_inc();
+				processed.saturating_inc();
+			}
+			if head >= tail {
+				<FinalityQueueRange<T>>::remove(reporting_lock);
+			} else if head != start {
+				<FinalityQueueRange<T>>::insert(reporting_lock, (head, tail));
+			}
+		}
+		processed
 	}
 
 	/// Returns the block number at a given timestamp.
@@ -524,7 +924,7 @@ impl<T: Config> Pallet<T> {
 			)
 			.map(|last_tip| {
 				let last_reported_timestamp =
-					<LastReportedTimestamp<T>>::get(query_id).unwrap_or_default();
+					Self::last_reported(query_id).map(|(_, t)| t).unwrap_or_default();
 				if last_reported_timestamp < last_tip.timestamp {
 					last_tip.amount
 				} else {
@@ -541,8 +941,7 @@ impl<T: Config> Pallet<T> {
 	/// # Returns
 	/// The latest submitted value for the given identifier.
 	pub fn get_current_value(query_id: QueryId) -> Option<ValueOf<T>> {
-		<LastReportedTimestamp<T>>::get(query_id)
-			.and_then(|t| <ReportedValuesByTimestamp<T>>::get(query_id, t))
+		Self::last_reported(query_id).and_then(|(index, _)| Self::reported_value(query_id, index))
 	}
 
 	/// Retrieves the latest value for the query identifier before the specified timestamp.
@@ -555,12 +954,14 @@ impl<T: Config> Pallet<T> {
 		query_id: QueryId,
 		timestamp: Timestamp,
 	) -> Option<(ValueOf<T>, Timestamp)> {
+		// Serve from value cache, if enabled and the value is cached
+		if let Some(cached) = <ValueCaches<T>>::get(query_id) {
+			if let Some((t, value)) = cached.into_iter().rev().find(|(t, _)| *t < timestamp) {
+				return Some((value, t));
+			}
+		}
 		Self::get_index_for_data_before(query_id, timestamp)
-			.and_then(|index| Self::get_timestamp_by_query_id_and_index(query_id, index))
-			.and_then(|timestamp_retrieved| {
-				Self::retrieve_data(query_id, timestamp_retrieved)
-					.map(|value| (value, timestamp_retrieved))
-			})
+			.and_then(|index| Self::reported_value_and_timestamp(query_id, index))
 	}
 
 	/// Retrieves the latest value for the query identifier before the specified timestamp.
@@ -578,12 +979,7 @@ impl<T: Config> Pallet<T> {
 		let (index_before, iterations) =
 			Self::get_index_for_data_before_with_start(query_id, timestamp, start);
 		(
-			index_before
-				.and_then(|index| <ReportedTimestampsByIndex<T>>::get(query_id, index))
-				.and_then(|timestamp_retrieved| {
-					<ReportedValuesByTimestamp<T>>::get(query_id, timestamp_retrieved)
-						.map(|value| (value, timestamp_retrieved))
-				}),
+			index_before.and_then(|index| Self::reported_value_and_timestamp(query_id, index)),
 			iterations,
 		)
 	}
@@ -598,6 +994,39 @@ impl<T: Config> Pallet<T> {
 			.and_then(|query_id| <DataFeeds<T>>::get(query_id, feed_id))
 	}
 
+	/// Returns the derived feed registered for a synthetic query identifier.
+	/// # Arguments
+	/// * `query_id` - The synthetic query identifier of the derived feed.
+	/// # Returns
+	/// The derived feed, if found.
+	pub fn get_derived_feed(query_id: QueryId) -> Option<DerivedFeed> {
+		<DerivedFeeds<T>>::get(query_id)
+	}
+
+	/// Derives the value of a derived feed from the latest values of its operands, each of which
+	/// must satisfy the supplied read policy.
+	/// # Arguments
+	/// * `query_id` - The synthetic query identifier of the derived feed.
+	/// * `policy` - The policy the values of both operands must satisfy.
+	/// # Returns
+	/// The derived value (ABI-encoded as an unsigned integer) along with the timestamp of the
+	/// oldest operand, or the reason the value could not be derived.
+	pub fn get_derived_value(
+		query_id: QueryId,
+		policy: ReadPolicy,
+	) -> Result<(Vec<u8>, Timestamp), ReadRejection> {
+		let feed = <DerivedFeeds<T>>::get(query_id).ok_or(ReadRejection::NotDerivedFeed)?;
+		// Both operands are read under the same policy, so that the derived value is only as
+		// fresh as its oldest operand
+		let a = <Self as UsingTellor<AccountIdOf<T>>>::get_safe_value(feed.query_id_a, policy)?;
+		let b = <Self as UsingTellor<AccountIdOf<T>>>::get_safe_value(feed.query_id_b, policy)?;
+		let value = BytesToU256::convert(a.value)
+			.zip(BytesToU256::convert(b.value))
+			.and_then(|(a, b)| feed.derive(a, b))
+			.ok_or(ReadRejection::DerivationFailed)?;
+		Ok((contracts::encode(&[Abi::Uint(value)]), a.timestamp.min(b.timestamp)))
+	}
+
 	/// Get the latest dispute fee.
 	/// # Returns
 	/// The latest dispute fee.
@@ -628,6 +1057,42 @@ impl<T: Config> Pallet<T> {
 		<DisputeIdsByReporter<T>>::iter_key_prefix(reporter).collect()
 	}
 
+	/// Returns the emergency value published by governance for a query identifier, provided it has
+	/// not expired.
+	/// # Arguments
+	/// * `query_id` - Identifier of the specific data feed.
+	/// # Returns
+	/// The emergency value along with the time published and the time it expires, if found.
+	pub fn get_emergency_value(query_id: QueryId) -> Option<EmergencyValueOf<T>> {
+		<EmergencyValues<T>>::get(query_id).filter(|emergency| Self::now() < emergency.expires)
+	}
+
+	/// Retrieves the latest finalized value for the query identifier before the specified
+	/// timestamp, being a value whose dispute window has elapsed without dispute.
+	/// # Arguments
+	/// * `query_id` - The query identifier to look up the value for.
+	/// * `timestamp` - The timestamp before which to search for the latest finalized value.
+	/// # Returns
+	/// The finalized value and its timestamp, if found.
+	pub fn get_finalized_value_before(
+		query_id: QueryId,
+		timestamp: Timestamp,
+	) -> Option<(ValueOf<T>, Timestamp)> {
+		// Values are only finalized once the dispute window of every earlier value has elapsed, so
+		// any undisputed value up to the last finalized value is final
+		let (_, finalized) = <LastFinalized<T>>::get(query_id)?;
+		Self::get_data_before(query_id, timestamp.min(finalized.saturating_add(1)))
+	}
+
+	/// Returns the index of the earliest value for a query identifier which has not been pruned.
+	/// # Arguments
+	/// * `query_id` - Identifier of the specific data feed.
+	/// # Returns
+	/// The index of the earliest retained value, with all prior indices having been pruned.
+	pub fn get_first_retained_index(query_id: QueryId) -> u32 {
+		<FirstRetainedIndex<T>>::get(query_id)
+	}
+
 	/// Read currently funded feed details.
 	/// # Returns
 	/// Details for funded feeds.
@@ -695,15 +1160,15 @@ impl<T: Config> Pallet<T> {
 		start: u32,
 	) -> (Option<u32>, u32) {
 		let mut iterations = 0;
+		// Search cannot start before any pruned values
+		let start = start.max(Self::get_first_retained_index(query_id));
 		// Use closure to simply append iterations to result, whilst retaining clean ? syntax within closure
 		let mut get_index = |query_id, timestamp, mut start| {
-			let last_reported_timestamp = <LastReportedTimestamp<T>>::get(query_id)?;
+			let (mut end, last_reported_timestamp) = Self::last_reported(query_id)?;
 			// Checking Boundaries to short-circuit the algorithm
-			let mut time = <ReportedTimestampsByIndex<T>>::get(query_id, start)?;
-			if time >= timestamp {
+			if Self::indexed_value(query_id, start)?.timestamp >= timestamp {
 				return None;
 			}
-			let mut end = <Reports<T>>::get(query_id, last_reported_timestamp)?.index;
 			if last_reported_timestamp < timestamp {
 				return Some(end);
 			}
@@ -713,40 +1178,34 @@ impl<T: Config> Pallet<T> {
 				iterations.saturating_inc();
 				middle =
 					(end.checked_sub(start)?).checked_div(2)?.checked_add(1)?.checked_add(start)?;
-				time = <ReportedTimestampsByIndex<T>>::get(query_id, middle)?;
-				if time < timestamp {
+				let reported = Self::indexed_value(query_id, middle)?;
+				if reported.timestamp < timestamp {
 					// get immediate next value
-					let next_time = <ReportedTimestampsByIndex<T>>::get(query_id, middle + 1)?;
+					let next_time = Self::indexed_value(query_id, middle + 1)?.timestamp;
 					if next_time >= timestamp {
-						let report = <Reports<T>>::get(query_id, time)?;
-						return if !report.is_disputed {
+						return if !reported.is_disputed {
 							// _time is correct
 							Some(middle)
 						} else {
-							report
-								.previous
-								.and_then(|t| <Reports<T>>::get(query_id, t).map(|r| r.index))
+							reported.previous
 						};
 					} else {
 						// look from middle + 1(next value) to end
 						start = middle.checked_add(1)?;
 					}
 				} else {
-					let previous_time =
-						<ReportedTimestampsByIndex<T>>::get(query_id, middle.checked_sub(1)?)?;
-					if previous_time < timestamp {
-						let report = <Reports<T>>::get(query_id, previous_time)?;
-						return if !report.is_disputed {
+					let previous_index = middle.checked_sub(1)?;
+					let previous = Self::indexed_value(query_id, previous_index)?;
+					if previous.timestamp < timestamp {
+						return if !previous.is_disputed {
 							// previous_time is correct
-							Some(middle.checked_sub(1)?)
+							Some(previous_index)
 						} else {
-							report
-								.previous
-								.and_then(|t| <Reports<T>>::get(query_id, t).map(|r| r.index))
+							previous.previous
 						};
 					} else {
 						// look from start to middle -1(prev value)
-						end = middle.checked_sub(1)?;
+						end = previous_index;
 					}
 				}
 			}
@@ -886,10 +1345,35 @@ impl<T: Config> Pallet<T> {
 		<Tips<T>>::get(query_id, index)
 	}
 
+	/// Returns the unrevealed commitments for a query identifier using commit-reveal, excluding
+	/// those whose reveal window has expired.
+	/// # Arguments
+	/// * `query_id` - Identifier of the specific data feed.
+	/// # Returns
+	/// The reporter, hash and time of each unrevealed commitment, in no particular order.
+	pub fn get_pending_commits(query_id: QueryId) -> Vec<(AccountIdOf<T>, CommitHash, Timestamp)> {
+		let now = Self::now();
+		<Commits<T>>::iter_prefix(query_id)
+			.filter(|(_, commit)| now <= commit.timestamp.saturating_add(T::RevealWindow::get()))
+			.map(|(reporter, commit)| (reporter, commit.hash, commit.timestamp))
+			.collect()
+	}
+
 	pub fn get_query_data(query_id: QueryId) -> Option<QueryDataOf<T>> {
 		<QueryData<T>>::get(query_id)
 	}
 
+	/// Returns the current health of a query identifier, as assessed against its health thresholds.
+	/// # Arguments
+	/// * `query_id` - Identifier of the specific data feed.
+	/// # Returns
+	/// The health of the query identifier, which is always healthy without health thresholds.
+	pub fn get_query_health(query_id: QueryId) -> QueryHealth {
+		<QueryHealthThresholds<T>>::get(query_id)
+			.map(|thresholds| Self::assess_health(query_id, &thresholds, Self::now()))
+			.unwrap_or_default()
+	}
+
 	/// Look up a query identifier from a data feed identifier.
 	/// # Arguments
 	/// * `feed_id` - Data feed unique identifier.
@@ -935,10 +1419,12 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Returns the reporting lock time, the amount of time a reporter must wait to submit again.
+	/// # Arguments
+	/// * `query_id` - Identifier of the specific data feed.
 	/// # Returns
-	/// The reporting lock time.
-	pub fn get_reporting_lock() -> Timestamp {
-		REPORTING_LOCK
+	/// The reporting lock time of the risk class of the query identifier.
+	pub fn get_reporting_lock(query_id: QueryId) -> Timestamp {
+		Self::get_risk_class(query_id).reporting_lock
 	}
 
 	/// Returns the number of values submitted by a specific reporter.
@@ -965,6 +1451,15 @@ impl<T: Config> Pallet<T> {
 		<StakerReportsSubmittedByQueryId<T>>::get(reporter, query_id)
 	}
 
+	/// Returns the number of values retracted by a specific reporter.
+	/// # Arguments
+	/// * `reporter` - The identifier of the reporter.
+	/// # Returns
+	/// The number of values retracted by the given reporter.
+	pub fn get_retractions_by_reporter(reporter: &AccountIdOf<T>) -> u32 {
+		<RetractionsByReporter<T>>::get(reporter)
+	}
+
 	/// Read potential reward for a set of oracle submissions.
 	/// # Arguments
 	/// * `feed_id` - Data feed unique identifier.
@@ -1029,6 +1524,103 @@ impl<T: Config> Pallet<T> {
 			.collect()
 	}
 
+	/// Returns the risk class of a query identifier.
+	/// # Arguments
+	/// * `query_id` - Identifier of the specific data feed.
+	/// # Returns
+	/// The reporter requirements of the assigned risk class, otherwise the default requirements.
+	pub fn get_risk_class(query_id: QueryId) -> RiskClass {
+		<QueryRiskClass<T>>::get(query_id)
+			.and_then(<RiskClasses<T>>::get)
+			.unwrap_or_default()
+	}
+
+	/// Retrieves the latest value for the query identifier which satisfies the supplied read policy.
+	/// # Arguments
+	/// * `query_id` - The query identifier to look up the value for.
+	/// * `policy` - The policy the value must satisfy.
+	/// # Returns
+	/// The value along with its timestamp and reporter, or the reason the value was rejected.
+	pub fn get_safe_value(
+		query_id: QueryId,
+		policy: ReadPolicy,
+	) -> Result<SafeValue<AccountIdOf<T>>, ReadRejection> {
+		let open_disputes = Self::get_open_disputes_on_id(query_id);
+		ensure!(
+			open_disputes <= policy.max_open_disputes,
+			ReadRejection::TooManyOpenDisputes { open_disputes }
+		);
+		// Retrieve data at least as old as the dispute buffer to allow time for disputes
+		let now = Self::now();
+		let mut index =
+			Self::get_index_for_data_before(query_id, now.saturating_sub(policy.dispute_buffer))
+				.ok_or(ReadRejection::NoValue)?;
+		let IndexedValueOf::<T> { timestamp, reporter, .. } =
+			Self::indexed_value(query_id, index).ok_or(ReadRejection::NoValue)?;
+		ensure!(now.saturating_sub(timestamp) < policy.max_age, ReadRejection::Stale { timestamp });
+		let value = Self::reported_value(query_id, index).ok_or(ReadRejection::NoValue)?;
+
+		// Count distinct reporters of non-disputed values within the window
+		let oldest = now.saturating_sub(policy.max_age);
+		let mut reporters = Vec::from([reporter.clone()]);
+		let mut inspected = 0;
+		while (reporters.len() as u32) < policy.min_reporters
+			&& index > 0
+			&& inspected < MAX_READ_POLICY_VALUES
+		{
+			index.saturating_dec();
+			inspected.saturating_inc();
+			let Some(reported) = Self::indexed_value(query_id, index) else {
+				break;
+			};
+			if reported.timestamp <= oldest {
+				break;
+			}
+			if reported.is_disputed {
+				continue;
+			}
+			if !reporters.contains(&reported.reporter) {
+				reporters.push(reported.reporter);
+			}
+		}
+		ensure!(
+			reporters.len() as u32 >= policy.min_reporters,
+			ReadRejection::InsufficientReporters { reporters: reporters.len() as u32 }
+		);
+
+		Ok(SafeValue { value: value.into_inner(), timestamp, reporter })
+	}
+
+	/// Retrieves the latest value for the query identifier which satisfies the supplied read policy,
+	/// falling back to any emergency value published by governance when no healthy reported value
+	/// exists.
+	/// # Arguments
+	/// * `query_id` - The query identifier to look up the value for.
+	/// * `policy` - The policy the reported value must satisfy.
+	/// # Returns
+	/// The value flagged by its source, or the reason the reported value was rejected when no
+	/// emergency value exists.
+	pub fn get_safe_value_or_emergency(
+		query_id: QueryId,
+		policy: ReadPolicy,
+	) -> Result<ReadValue<AccountIdOf<T>>, ReadRejection> {
+		let reported = if Self::get_query_health(query_id) == QueryHealth::Frozen {
+			Err(ReadRejection::QueryFrozen)
+		} else {
+			Self::get_safe_value(query_id, policy)
+		};
+		match reported {
+			Ok(safe_value) => Ok(ReadValue::Reported(safe_value)),
+			Err(rejection) => Self::get_emergency_value(query_id)
+				.map(|emergency| ReadValue::Emergency {
+					value: emergency.value.into_inner(),
+					timestamp: emergency.timestamp,
+					expires: emergency.expires,
+				})
+				.ok_or(rejection),
+		}
+	}
+
 	/// Returns the amount required to report oracle values.
 	/// # Returns
 	/// The stake amount.
@@ -1036,6 +1628,18 @@ impl<T: Config> Pallet<T> {
 		<StakeAmount<T>>::get()
 	}
 
+	/// Returns the amount required to report values for a query identifier, which is also the
+	/// amount slashed on a dispute.
+	/// # Arguments
+	/// * `query_id` - Identifier of the specific data feed.
+	/// # Returns
+	/// The stake amount multiplied by the stake multiple of the risk class of the query identifier.
+	pub fn get_stake_requirement(query_id: QueryId) -> Result<Tributes, DispatchError> {
+		<StakeAmount<T>>::get()
+			.checked_mul(Self::get_risk_class(query_id).stake_multiple.into())
+			.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
 	/// Returns all information about a staker.
 	/// # Arguments
 	/// * `staker` - The identifier of the staker inquiring about.
@@ -1045,6 +1649,15 @@ impl<T: Config> Pallet<T> {
 		<StakerDetails<T>>::get(staker)
 	}
 
+	/// Returns a subscription of a remote parachain.
+	/// # Arguments
+	/// * `subscription_id` - Identifier of the subscription.
+	/// # Returns
+	/// The subscription, if found.
+	pub fn get_subscription(subscription_id: SubscriptionId) -> Option<Subscription> {
+		<Subscriptions<T>>::get(subscription_id)
+	}
+
 	/// Returns the timestamp for the last value of any identifier from the oracle.
 	/// # Returns
 	/// The timestamp of the last oracle value.
@@ -1059,7 +1672,7 @@ impl<T: Config> Pallet<T> {
 	/// # Returns
 	/// A timestamp if found.
 	pub fn get_timestamp_by_query_id_and_index(query_id: QueryId, index: u32) -> Option<Timestamp> {
-		<ReportedTimestampsByIndex<T>>::get(query_id, index)
+		Self::indexed_value(query_id, index).map(|reported| reported.timestamp)
 	}
 
 	/// Returns the index of a reporter timestamp in the timestamp array for a specific query identifier.
@@ -1107,6 +1720,56 @@ impl<T: Config> Pallet<T> {
 		<ReportedTimestampCount<T>>::get(query_id)
 	}
 
+	/// Returns the current commitment to reported values.
+	/// # Returns
+	/// The number of leaves and root of the commitment range, if any values have been reported.
+	pub fn get_value_commitment() -> Option<commitments::Commitment> {
+		let leaf_count = <CommitmentLeafCount<T>>::get();
+		(leaf_count > 0).then(|| commitments::Commitment {
+			leaf_count,
+			root: commitments::root(leaf_count, &Self::commitment_peaks(leaf_count)),
+		})
+	}
+
+	/// Returns a value noted as a preimage.
+	/// # Arguments
+	/// * `hash` - The hash of the noted value.
+	/// # Returns
+	/// The noted value, if found.
+	pub fn get_value_preimage(hash: PreimageHash) -> Option<Vec<u8>> {
+		<PreimageValues<T>>::get(hash).map(|value| value.into_inner())
+	}
+
+	/// Returns a proof of the commitment leaf of a reported value, verifiable against the current
+	/// commitment.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported value.
+	/// * `timestamp` - The timestamp of the reported value.
+	/// # Returns
+	/// The leaf of the reported value and its proof of membership, if found.
+	pub fn get_value_proof(
+		query_id: QueryId,
+		timestamp: Timestamp,
+	) -> Option<(commitments::Leaf, commitments::Proof)> {
+		let (leaf_index, leaf) = <CommitmentLeaves<T>>::get(query_id, timestamp)?;
+		let leaf_count = <CommitmentLeafCount<T>>::get();
+		let (height, _) = commitments::peaks(leaf_count)
+			.into_iter()
+			.find(|(height, index)| leaf_index >> height == *index)?;
+		let siblings = (0..height)
+			.map(|height| <CommitmentNodes<T>>::get(height, (leaf_index >> height) ^ 1))
+			.collect::<Option<Vec<_>>>()?;
+		Some((
+			leaf,
+			commitments::Proof {
+				leaf_index,
+				leaf_count,
+				siblings,
+				peaks: Self::commitment_peaks(leaf_count),
+			},
+		))
+	}
+
 	/// Returns the total number of votes
 	/// # Returns
 	/// The total number of votes.
@@ -1143,6 +1806,81 @@ impl<T: Config> Pallet<T> {
 		<VoteTallyByAddress<T>>::get(voter)
 	}
 
+	/// Returns whether a one-time tip earned by a reported value remains unclaimed.
+	/// # Arguments
+	/// * `query_id` - Identifier of reported data.
+	/// * `timestamp` - Timestamp of the reported value.
+	/// * `tip_count` - The number of tips of the query identifier.
+	/// # Returns
+	/// Whether a tip earned by the value remains unclaimed, along with the number of reads.
+	pub(super) fn has_unclaimed_tip(
+		query_id: QueryId,
+		timestamp: Timestamp,
+		tip_count: u32,
+	) -> (bool, u64) {
+		if tip_count == 0 {
+			return (false, 0);
+		}
+		let mut reads = 1;
+		let Some(report) = <Reports<T>>::get(query_id, timestamp).filter(|r| !r.is_disputed) else {
+			return (false, reads);
+		};
+		// Find the latest tip at or before the timestamp, being the tip claimable by the value
+		let (mut min, mut max) = (0, tip_count);
+		while max.saturating_sub(min) > 1 {
+			let mid = min.saturating_add(max) / 2;
+			reads.saturating_inc();
+			if <Tips<T>>::get(query_id, mid).map_or(0, |t| t.timestamp) > timestamp {
+				max = mid;
+			} else {
+				min = mid;
+			}
+		}
+		reads.saturating_inc();
+		let unclaimed = <Tips<T>>::get(query_id, min).map_or(false, |tip| {
+			tip.timestamp <= timestamp
+				&& tip.timestamp > report.previous.unwrap_or_default()
+				&& tip.amount > Zero::zero()
+		});
+		(unclaimed, reads)
+	}
+
+	/// Returns the timestamp, reporter and dispute status of a reported value, by index.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported value.
+	/// * `index` - The index of the reported value.
+	/// # Returns
+	/// The indexed value, if found.
+	pub(super) fn indexed_value(query_id: QueryId, index: u32) -> Option<IndexedValueOf<T>> {
+		<ReportedValues<T>>::get(query_id, index)
+			.or_else(|| migrations::v1::indexed_value::<T>(query_id, index))
+	}
+
+	/// Converts an error into the reason a transaction is invalid, for rejection from the transaction pool.
+	/// # Arguments
+	/// * `error` - The error encountered while validating the transaction.
+	/// # Returns
+	/// The reason the transaction is invalid.
+	pub(super) fn invalid_transaction(error: DispatchError) -> InvalidTransaction {
+		match error {
+			_ if error == Error::<T>::InsufficientStake.into() => InvalidTransaction::BadSigner,
+			_ if error == Error::<T>::InvalidNonce.into() => InvalidTransaction::Stale,
+			_ if error == Error::<T>::ReporterTimeLocked.into() => InvalidTransaction::Future,
+			_ => InvalidTransaction::Call,
+		}
+	}
+
+	/// Returns whether cached values include every non-disputed value reported after a timestamp.
+	/// # Arguments
+	/// * `cached` - The cached values of a query identifier.
+	/// * `timestamp` - The timestamp after which values are required.
+	/// # Returns
+	/// Whether the cached values are complete after the timestamp.
+	pub(super) fn is_cached_after(cached: &CachedValuesOf<T>, timestamp: Timestamp) -> bool {
+		// A cache which is not full holds all values, having been seeded when enabled
+		!cached.is_full() || cached.first().map_or(false, |(t, _)| *t <= timestamp)
+	}
+
 	/// Returns whether a given value is disputed.
 	/// # Arguments
 	/// * `query_id` - Unique identifier of the data feed.
@@ -1155,41 +1893,686 @@ impl<T: Config> Pallet<T> {
 			.unwrap_or_default()
 	}
 
-	/// Returns the duration since UNIX_EPOCH, in seconds.
+	/// Returns the index and timestamp of the last (non-disputed) reported value.
+	/// # Arguments
+	/// * `query_id` - The query identifier to look up.
 	/// # Returns
-	/// The duration since UNIX_EPOCH, in seconds.
+	/// The index and timestamp of the last reported value, if any.
+	pub(super) fn last_reported(query_id: QueryId) -> Option<(u32, Timestamp)> {
+		<LastReported<T>>::get(query_id).or_else(|| migrations::v1::last_reported::<T>(query_id))
+	}
+
+	/// Returns the latest non-disputed values of a query identifier, by following the previous
+	/// value of each report from the last reported value.
+	/// # Arguments
+	/// * `query_id` - The query identifier.
+	/// * `count` - The maximum number of values to return.
+	/// # Returns
+	/// The latest values along with their timestamps, oldest first.
+	pub(super) fn latest_values(query_id: QueryId, count: u32) -> CachedValuesOf<T> {
+		let count = count.min(T::MaxCachedValues::get()) as usize;
+		let mut values = Vec::with_capacity(count);
+		let mut current = Self::last_reported(query_id);
+		while let Some((index, timestamp)) = current {
+			if values.len() >= count {
+				break;
+			}
+			let Some(value) = Self::reported_value(query_id, index) else { break };
+			values.push((timestamp, value));
+			current = <Reports<T>>::get(query_id, timestamp)
+				.and_then(|report| report.previous)
+				.and_then(|p| <Reports<T>>::get(query_id, p).map(|r| (r.index, p)));
+		}
+		values.reverse();
+		values.try_into().expect("values bounded by maximum cached values; qed")
+	}
+
+	/// Returns the minimum retention period of values of a query identifier, ensuring values remain
+	/// available throughout the dispute and data feed claim windows of later values.
+	/// # Arguments
+	/// * `query_id` - Identifier of the specific data feed.
+	/// # Returns
+	/// The minimum retention period.
+	pub(super) fn min_retention_period(query_id: QueryId) -> Timestamp {
+		MIN_RETENTION_PERIOD.max(CLAIM_PERIOD.saturating_add(Self::get_reporting_lock(query_id)))
+	}
+
+	/// Notes the interval of a funded feed of a query identifier, retaining only the shortest
+	/// intervals so that staleness can be assessed without reading every feed.
+	/// # Arguments
+	/// * `query_id` - Identifier of reported data type associated with feed.
+	/// * `feed_id` - Unique feed identifier.
+	/// * `interval` - The interval of the feed.
+	pub(super) fn note_funded_feed(query_id: QueryId, feed_id: FeedId, interval: Timestamp) {
+		if interval == 0 {
+			return;
+		}
+		<FundedFeedIntervals<T>>::mutate(query_id, |intervals| {
+			if intervals.iter().any(|(_, id)| id == &feed_id) {
+				return;
+			}
+			let position = intervals.partition_point(|(i, _)| *i <= interval);
+			// Evicts the longest interval when full, unless longer than any noted
+			let _ = intervals.force_insert_keep_left(position, (interval, feed_id));
+		});
+	}
+
+	/// Returns the duration since UNIX_EPOCH, in seconds.
+	/// # Returns
+	/// The duration since UNIX_EPOCH, in seconds.
 	pub(super) fn now() -> u64 {
 		// Use seconds to match EVM smart contracts
 		T::Time::now().as_secs()
 	}
 
+	/// The account identifier of the sub-account used to hold preimage deposits.
+	///
+	/// This actually does computation. If you need to keep using it, then make sure you cache the
+	/// value and only call this once.
+	pub(super) fn preimage_deposits() -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(b"preimages")
+	}
+
+	/// Processes pending data requests, resuming from the last request processed, delivering any
+	/// values which have cleared the dispute buffer and timing out any expired requests, within the
+	/// supplied limits.
+	/// # Arguments
+	/// * `timestamp` - The current time.
+	/// * `remaining_weight` - The weight available for processing.
+	/// * `max_requests` - The maximum number of requests inspected.
+	/// # Returns
+	/// The weight consumed.
+	pub(super) fn process_requests(
+		timestamp: Timestamp,
+		remaining_weight: Weight,
+		max_requests: u32,
+	) -> Weight {
+		if remaining_weight.any_lt(T::WeightInfo::on_idle(0)) {
+			return Weight::zero();
+		}
+		let start = <RequestQueueHead<T>>::get();
+		let tail = <RequestCount<T>>::get();
+		// Resume from the cursor, so that requests pending beyond the head are not rescanned each time
+		let cursor = <RequestScanCursor<T>>::get();
+		let resume = cursor.map_or(start, |cursor| cursor.max(start));
+		let mut head = start;
+		let mut next = resume;
+		let mut processed = 0;
+		// Requests already fulfilled or timed out are skipped, at the cost of a read each
+		let mut skipped = 0;
+		let mut hook_weight = Weight::zero();
+		for request_id in resume..tail {
+			if processed.saturating_add(skipped) >= max_requests {
+				break;
+			}
+			// Ensure sufficient weight remains to process the request, including the worst case response handler
+			let required = T::WeightInfo::on_idle(processed.saturating_add(1))
+				.saturating_add(T::DbWeight::get().reads(skipped.into()))
+				.saturating_add(hook_weight)
+				.saturating_add(T::OnResponse::weight());
+			if remaining_weight.any_lt(required) {
+				break;
+			}
+			next = request_id.saturating_add(1);
+			let Some(request) = <PendingRequests<T>>::get(request_id) else {
+				skipped.saturating_inc();
+				if request_id == head {
+					head.saturating_inc();
+				}
+				continue;
+			};
+			processed.saturating_inc();
+			// Find first non-disputed value at or after the earliest timestamp which has cleared the dispute buffer
+			let completed = match <Self as UsingTellor<AccountIdOf<T>>>::get_data_after(
+				request.query_id,
+				request.earliest_timestamp.saturating_sub(1),
+			) {
+				Some((value, reported))
+					if timestamp.saturating_sub(reported) >= request.dispute_buffer =>
+				{
+					hook_weight.saturating_accrue(T::OnResponse::on_response(
+						request.id,
+						request.callback,
+						request.query_id,
+						value,
+						reported,
+					));
+					Self::deposit_event(Event::RequestFulfilled {
+						request_id: request.id,
+						query_id: request.query_id,
+						timestamp: reported,
+					});
+					true
+				},
+				_ if timestamp >= request.deadline => {
+					hook_weight.saturating_accrue(T::OnResponse::on_timeout(
+						request.id,
+						request.callback,
+						request.query_id,
+					));
+					Self::deposit_event(Event::RequestTimedOut {
+						request_id: request.id,
+						query_id: request.query_id,
+					});
+					true
+				},
+				_ => false,
+			};
+			if completed {
+				<PendingRequests<T>>::remove(request_id);
+				if request_id == head {
+					head.saturating_inc();
+				}
+			}
+		}
+		if head != start {
+			<RequestQueueHead<T>>::set(head);
+		}
+		// Return to the head of the queue once the last request has been processed
+		let next = (next < tail).then_some(next);
+		if next != cursor {
+			<RequestScanCursor<T>>::set(next);
+		}
+		T::WeightInfo::on_idle(processed)
+			.saturating_add(T::DbWeight::get().reads(skipped.into()))
+			.saturating_add(hook_weight)
+	}
+
+	/// Pushes new values to subscribers, resuming from the last subscription processed, within the
+	/// supplied weight limit.
+	/// # Arguments
+	/// * `timestamp` - The current time.
+	/// * `remaining_weight` - The weight available for pushing values.
+	/// # Returns
+	/// The weight consumed.
+	pub(super) fn process_subscriptions(timestamp: Timestamp, remaining_weight: Weight) -> Weight {
+		if remaining_weight.any_lt(T::WeightInfo::push_values(0)) {
+			return Weight::zero();
+		}
+		let subscriptions = match <PushCursor<T>>::get() {
+			Some(subscription_id) => {
+				<Subscriptions<T>>::iter_from(<Subscriptions<T>>::hashed_key_for(subscription_id))
+			},
+			None => <Subscriptions<T>>::iter(),
+		};
+		let mut processed = 0;
+		let mut cursor = None;
+		let mut completed = true;
+		for (subscription_id, mut subscription) in subscriptions {
+			// Ensure sufficient weight remains to push a value to the subscriber
+			if remaining_weight.any_lt(T::WeightInfo::push_values(processed.saturating_add(1))) {
+				completed = false;
+				break;
+			}
+			processed.saturating_inc();
+			cursor = Some(subscription_id);
+			let removed = Self::push_removal(subscription_id, &mut subscription);
+			if Self::push_value(subscription_id, &mut subscription, timestamp) || removed {
+				<Subscriptions<T>>::insert(subscription_id, subscription);
+			}
+		}
+		match cursor {
+			// Resume after the last subscription processed on next pass
+			Some(subscription_id) if !completed => <PushCursor<T>>::set(Some(subscription_id)),
+			// Start from the first subscription on next pass
+			_ if completed => <PushCursor<T>>::kill(),
+			_ => {},
+		}
+		T::WeightInfo::push_values(processed)
+	}
+
+	/// Prunes unrevealed commitments which can no longer be revealed, as either their reveal window
+	/// has expired or commit-reveal has since been disabled for their query identifier, within the
+	/// supplied weight limit. Pruning resumes from the last commitment inspected.
+	/// # Arguments
+	/// * `timestamp` - The current time.
+	/// * `remaining_weight` - The weight available for pruning.
+	/// # Returns
+	/// The weight consumed.
+	pub(super) fn prune_commits(timestamp: Timestamp, remaining_weight: Weight) -> Weight {
+		if remaining_weight.any_lt(T::WeightInfo::prune_commits(0)) {
+			return Weight::zero();
+		}
+		let commits = match <CommitPruningCursor<T>>::get() {
+			Some((query_id, reporter)) => {
+				<Commits<T>>::iter_from(<Commits<T>>::hashed_key_for(query_id, reporter))
+			},
+			None => <Commits<T>>::iter(),
+		};
+		let mut inspected = 0;
+		let mut cursor = None;
+		let mut completed = true;
+		for (query_id, reporter, commit) in commits {
+			// Ensure sufficient weight remains to inspect the commitment
+			if remaining_weight.any_lt(T::WeightInfo::prune_commits(inspected.saturating_add(1))) {
+				completed = false;
+				break;
+			}
+			inspected.saturating_inc();
+			if timestamp > commit.timestamp.saturating_add(T::RevealWindow::get())
+				|| !<CommitRevealQueries<T>>::contains_key(query_id)
+			{
+				<Commits<T>>::remove(query_id, &reporter);
+			}
+			cursor = Some((query_id, reporter));
+		}
+		match cursor {
+			// Resume after the last commitment inspected on next pass
+			Some(cursor) if !completed => <CommitPruningCursor<T>>::set(Some(cursor)),
+			// Start from the first commitment on next pass
+			_ if completed => <CommitPruningCursor<T>>::kill(),
+			_ => {},
+		}
+		T::WeightInfo::prune_commits(inspected)
+	}
+
+	/// Prunes values which are outside of the retention period of their query identifier, within the
+	/// supplied weight limit. Pruning resumes from the last query identifier processed, with the latest
+	/// value of each query identifier always retained. Pruning of a query identifier stops at the first
+	/// disputed value whose dispute has not been executed, as the dispute may still be escalated.
+	/// Pruned values are also evicted from the value cache of their query identifier, with their
+	/// commitment leaves no longer tracked.
+	/// # Arguments
+	/// * `timestamp` - The current time.
+	/// * `remaining_weight` - The weight available for pruning.
+	/// # Returns
+	/// The weight consumed.
+	pub(super) fn prune_values(timestamp: Timestamp, remaining_weight: Weight) -> Weight {
+		// Disputes of disputed values, later values and tips are inspected before pruning, with
+		// deposits returned for any noted values released
+		let weight = |queries, pruned, disputes: u64, inspected: u64, released: u64| {
+			T::WeightInfo::prune_values(queries, pruned)
+				.saturating_add(
+					T::DbWeight::get().reads(disputes.saturating_mul(2).saturating_add(inspected)),
+				)
+				.saturating_add(
+					T::DbWeight::get()
+						.reads_writes(released.saturating_mul(2), released.saturating_mul(2)),
+				)
+		};
+		if remaining_weight.any_lt(weight(0, 0, 0, 0, 0)) {
+			return Weight::zero();
+		}
+		let mut cursor = <PruningCursor<T>>::get();
+		let retention_periods = match cursor {
+			Some(query_id) => {
+				<RetentionPeriods<T>>::iter_from(<RetentionPeriods<T>>::hashed_key_for(query_id))
+			},
+			None => <RetentionPeriods<T>>::iter(),
+		};
+		let mut queries = 0;
+		let mut pruned = 0;
+		let mut disputes = 0;
+		let mut inspected = 0;
+		let mut released = 0;
+		let mut completed = true;
+		for (query_id, retention_period) in retention_periods {
+			// Ensure sufficient weight remains to inspect the query identifier
+			if remaining_weight.any_lt(weight(
+				queries.saturating_add(1),
+				pruned,
+				disputes,
+				inspected,
+				released,
+			)) {
+				completed = false;
+				break;
+			}
+			queries.saturating_inc();
+			let retention_period = retention_period.max(Self::min_retention_period(query_id));
+			let cutoff = timestamp.saturating_sub(retention_period);
+			let count = <ReportedTimestampCount<T>>::get(query_id);
+			let tip_count = <TipCount<T>>::get(query_id);
+			inspected.saturating_accrue(3);
+			let first = <FirstRetainedIndex<T>>::get(query_id);
+			let mut index = first;
+			let mut last_pruned = None;
+			// Always retain the latest value
+			while index.saturating_add(1) < count {
+				let Some(reported) = Self::indexed_value(query_id, index) else {
+					break;
+				};
+				// Retain a value until the next non-disputed value can no longer be claimed, as
+				// claims are assessed against the value before
+				let mut next = None;
+				let mut next_index = index.saturating_add(1);
+				while next_index < count
+					&& next_index.saturating_sub(index) <= T::MaxDisputedTimeSeries::get()
+				{
+					inspected.saturating_inc();
+					match Self::indexed_value(query_id, next_index) {
+						Some(value) if !value.is_disputed => {
+							next = Some(value);
+							break;
+						},
+						Some(_) => next_index.saturating_inc(),
+						None => break,
+					}
+				}
+				let Some(next) = next.filter(|next| next.timestamp < cutoff) else {
+					break;
+				};
+				// Retain values while a one-time tip remains unclaimed, by the value itself or by
+				// the next value
+				let (unclaimed, reads) =
+					Self::has_unclaimed_tip(query_id, reported.timestamp, tip_count);
+				inspected.saturating_accrue(reads);
+				if unclaimed {
+					break;
+				}
+				let (unclaimed, reads) =
+					Self::has_unclaimed_tip(query_id, next.timestamp, tip_count);
+				inspected.saturating_accrue(reads);
+				if unclaimed {
+					break;
+				}
+				// Ensure sufficient weight remains to prune the value
+				let dispute = u64::from(reported.is_disputed);
+				if remaining_weight.any_lt(weight(
+					queries,
+					pruned.saturating_add(1),
+					disputes.saturating_add(dispute),
+					inspected,
+					released.saturating_add(1),
+				)) {
+					completed = false;
+					break;
+				}
+				// Retain disputed values (and later values) until the dispute can no longer be
+				// escalated, as a new vote round requires the value to exist
+				if reported.is_disputed {
+					disputes.saturating_inc();
+					let dispute_id = Self::dispute_id(query_id, reported.timestamp);
+					if <VoteInfo<T>>::get(dispute_id, <VoteRounds<T>>::get(dispute_id))
+						.map_or(false, |vote| !vote.executed)
+					{
+						break;
+					}
+				}
+				<Reports<T>>::remove(query_id, reported.timestamp);
+				<CommitmentLeaves<T>>::remove(query_id, reported.timestamp);
+				let value = Self::reported_value(query_id, index);
+				<ReportedValues<T>>::remove(query_id, index);
+				<ReportedValueData<T>>::remove(query_id, index);
+				migrations::v1::remove_reported_value::<T>(query_id, index, reported.timestamp);
+				if value.map_or(false, |value| Self::release_preimage(query_id, &value)) {
+					released.saturating_inc();
+				}
+				last_pruned = Some(reported.timestamp);
+				index.saturating_inc();
+				pruned.saturating_inc();
+			}
+			if let Some(last_pruned) = last_pruned {
+				<FirstRetainedIndex<T>>::insert(query_id, index);
+				// Evict pruned values from the value cache, if enabled
+				if let Some(mut cached) = <ValueCaches<T>>::get(query_id) {
+					cached.retain(|(cached_timestamp, _)| *cached_timestamp > last_pruned);
+					<ValueCaches<T>>::insert(query_id, cached);
+				}
+			}
+			if !completed {
+				break;
+			}
+			cursor = Some(query_id);
+		}
+		if completed {
+			// Start from the first query identifier on next pass
+			<PruningCursor<T>>::kill();
+		} else {
+			// Resume from the query identifier being processed when weight ran out
+			<PruningCursor<T>>::set(cursor);
+		}
+		weight(queries, pruned, disputes, inspected, released)
+	}
+
+	/// Pushes the next removal of a disputed value of the query identifier of a subscription to the
+	/// subscriber, provided the value may have been pushed and the subscription has a removal call.
+	/// The subscriber is charged the subscription fee for each removal pushed, with a failed push
+	/// retried on subsequent passes. Removals no longer retained are skipped.
+	/// # Arguments
+	/// * `subscription_id` - Identifier of the subscription.
+	/// * `subscription` - The subscription, which is updated with the removals processed.
+	/// # Returns
+	/// Whether the subscription was updated.
+	pub(super) fn push_removal(
+		subscription_id: SubscriptionId,
+		subscription: &mut Subscription,
+	) -> bool {
+		let query_id = subscription.query_id;
+		let count = <RemovalCount<T>>::get(query_id);
+		if subscription.removals >= count {
+			return false;
+		}
+		// Skip removals which are no longer retained
+		let first = count.saturating_sub(MAX_PENDING_REMOVALS);
+		let skipped = subscription.removals < first;
+		if skipped {
+			subscription.removals = first;
+		}
+		let timestamp = <Removals<T>>::get(query_id, subscription.removals);
+		// Only push removals of values at or before the last value pushed, as later values are no
+		// longer eligible to be pushed
+		let pushed = timestamp
+			.zip(subscription.last_pushed)
+			.map_or(false, |(timestamp, (last, _))| timestamp <= last);
+		if let (true, Some(timestamp), Some(removal_call_index)) =
+			(pushed, timestamp, subscription.removal_call_index)
+		{
+			// Use storage layer (transaction) to ensure the subscriber is only charged if sent
+			let para_id = subscription.para_id;
+			let result = frame_support::storage::with_storage_layer(|| -> DispatchResult {
+				let subscriber =
+					<T::SovereignAccountOf as xcm_executor::traits::Convert<_, _>>::convert(
+						MultiLocation::new(1, X1(Parachain(para_id))),
+					)
+					.map_err(|_| Error::<T>::InvalidSubscriber)?;
+				T::Asset::transfer(
+					&subscriber,
+					&Self::account(),
+					T::SubscriptionFee::get(),
+					Preservation::Preserve,
+				)?;
+				let mut call = removal_call_index.to_vec();
+				(query_id, timestamp).encode_to(&mut call);
+				Self::send_xcm(
+					para_id,
+					crate::xcm::deliver::<T>(&subscription.fees, subscription.weight, call),
+					Event::ValueRemovalPushed { subscription_id, para_id, query_id, timestamp },
+				)?;
+				Ok(())
+			});
+			if result.is_err() {
+				return skipped;
+			}
+		}
+		subscription.removals.saturating_inc();
+		true
+	}
+
+	/// Pushes the latest value of the query identifier of a subscription to the subscriber, provided
+	/// it has cleared the dispute buffer and satisfies the minimum interval and deviation of the
+	/// subscription. Values longer than the maximum value length are not pushed. The subscriber is
+	/// charged the subscription fee for each value pushed, with failed pushes retried on subsequent
+	/// passes up to a maximum number of attempts.
+	/// # Arguments
+	/// * `subscription_id` - Identifier of the subscription.
+	/// * `subscription` - The subscription, which is updated with the outcome of any push.
+	/// * `timestamp` - The current time.
+	/// # Returns
+	/// Whether a push was attempted, and so the subscription updated.
+	pub(super) fn push_value(
+		subscription_id: SubscriptionId,
+		subscription: &mut Subscription,
+		timestamp: Timestamp,
+	) -> bool {
+		let query_id = subscription.query_id;
+		let Some((value, reported)) = <Self as UsingTellor<AccountIdOf<T>>>::get_data_before(
+			query_id,
+			timestamp.saturating_sub(T::SubscriptionDisputeBuffer::get()).saturating_add(1),
+		) else {
+			return false;
+		};
+		// Values longer than the maximum value length, such as large values, cannot be received by
+		// subscribers
+		if value.len() as u32 > T::MaxValueLength::get() {
+			return false;
+		}
+		let Some(reporter) = Self::get_reporter_by_timestamp(query_id, reported) else {
+			return false;
+		};
+		let uint = <Self as UsingTellor<AccountIdOf<T>>>::bytes_to_uint(value.clone());
+		if let Some((last, last_value)) = subscription.last_pushed {
+			if reported <= last || reported < last.saturating_add(subscription.min_interval) {
+				return false;
+			}
+			// Only push values which deviate sufficiently from the last value pushed
+			if let (Some(last_value), Some(value)) = (last_value, uint) {
+				let change = value.max(last_value).saturating_sub(value.min(last_value));
+				if change.saturating_mul(U256::from(Permill::ACCURACY))
+					< last_value.saturating_mul(subscription.deviation.deconstruct().into())
+				{
+					return false;
+				}
+			}
+		}
+		let attempts = match subscription.failed {
+			// Skip values which have exhausted their attempts, until a newer value is reported
+			Some((failed, attempts)) if failed == reported && attempts >= MAX_PUSH_ATTEMPTS => {
+				return false;
+			},
+			Some((failed, attempts)) if failed == reported => attempts,
+			_ => 0,
+		};
+
+		// Use storage layer (transaction) to ensure the subscriber is only charged if sent
+		let para_id = subscription.para_id;
+		let result = frame_support::storage::with_storage_layer(|| -> DispatchResult {
+			let subscriber =
+				<T::SovereignAccountOf as xcm_executor::traits::Convert<_, _>>::convert(
+					MultiLocation::new(1, X1(Parachain(para_id))),
+				)
+				.map_err(|_| Error::<T>::InvalidSubscriber)?;
+			T::Asset::transfer(
+				&subscriber,
+				&Self::account(),
+				T::SubscriptionFee::get(),
+				Preservation::Preserve,
+			)?;
+			let mut call = subscription.call_index.to_vec();
+			(query_id, reported, &value, &reporter).encode_to(&mut call);
+			Self::send_xcm(
+				para_id,
+				crate::xcm::deliver::<T>(&subscription.fees, subscription.weight, call),
+				Event::ValuePushed { subscription_id, para_id, query_id, timestamp: reported },
+			)?;
+			Ok(())
+		});
+		match result {
+			Ok(()) => {
+				subscription.last_pushed = Some((reported, uint));
+				subscription.failed = None;
+			},
+			Err(_) => {
+				let attempts = attempts.saturating_add(1);
+				subscription.failed = Some((reported, attempts));
+				Self::deposit_event(Event::ValuePushFailed {
+					subscription_id,
+					query_id,
+					timestamp: reported,
+					attempts,
+				});
+			},
+		}
+		true
+	}
+
+	/// Records the health of a query identifier, emitting an event when changed.
+	/// # Arguments
+	/// * `query_id` - Identifier of the specific data feed.
+	/// * `health` - The current health of the query identifier.
+	pub(super) fn record_health(query_id: QueryId, health: QueryHealth) {
+		if <QueryHealthStates<T>>::get(query_id) == health {
+			return;
+		}
+		if health == QueryHealth::default() {
+			<QueryHealthStates<T>>::remove(query_id);
+		} else {
+			<QueryHealthStates<T>>::insert(query_id, health);
+		}
+		Self::deposit_event(Event::QueryHealthChanged { query_id, health });
+	}
+
+	/// Releases the reference of a reported value to a value noted as a preimage, removing the
+	/// noted value once no longer referenced.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported value.
+	/// * `value` - The reported value.
+	/// # Returns
+	/// Whether the noted value was removed.
+	pub(super) fn release_preimage(query_id: QueryId, value: &[u8]) -> bool {
+		if !<LargeValueQueries<T>>::contains_key(query_id) {
+			return false;
+		}
+		let Ok(reference) = ValueReference::decode_all(&mut &value[..]) else { return false };
+		let Some(mut preimage) = <Preimages<T>>::get(reference.hash) else { return false };
+		preimage.references.saturating_dec();
+		if preimage.references > 0 {
+			<Preimages<T>>::insert(reference.hash, preimage);
+			return false;
+		}
+		Self::remove_preimage(reference.hash, preimage);
+		true
+	}
+
+	/// Removes a value noted as a preimage, returning the deposit held for it.
+	/// # Arguments
+	/// * `hash` - The hash of the noted value.
+	/// * `preimage` - The details of the noted value.
+	pub(super) fn remove_preimage(hash: PreimageHash, preimage: PreimageOf<T>) {
+		<Preimages<T>>::remove(hash);
+		<PreimageValues<T>>::remove(hash);
+		// Deposit was transferred when noted, so only fails if the depositor account cannot exist
+		let _ = T::Asset::transfer(
+			&Self::preimage_deposits(),
+			&preimage.depositor,
+			preimage.deposit,
+			Preservation::Expendable,
+		);
+	}
+
 	/// Removes a value from the oracle.
 	/// # Arguments
 	/// * `query_id` - Identifier of the specific data feed.
 	/// * `timestamp` - The timestamp of the value to remove.
+	/// # Returns
+	/// The number of subsequent timestamps updated and the weight consumed by the `OnValueRemoved`
+	/// handler.
 	pub(super) fn remove_value(
 		query_id: QueryId,
 		timestamp: Timestamp,
-	) -> Result<u32, DispatchError> {
-		let iterations =
-			<Reports<T>>::try_mutate(query_id, timestamp, |maybe| -> Result<u32, DispatchError> {
+	) -> Result<(u32, Weight), DispatchError> {
+		let (iterations, index, reporter, previous) = <Reports<T>>::try_mutate(
+			query_id,
+			timestamp,
+			|maybe| -> Result<(u32, u32, AccountIdOf<T>, Option<u32>), DispatchError> {
 				let Some(report) = maybe else { return Err(Error::<T>::InvalidTimestamp.into()) };
 				ensure!(!report.is_disputed, Error::<T>::ValueDisputed);
 				ensure!(
-					Some(timestamp) == <ReportedTimestampsByIndex<T>>::get(query_id, report.index),
+					Some(timestamp)
+						== Self::indexed_value(query_id, report.index).map(|v| v.timestamp),
 					Error::<T>::InvalidTimestamp
 				);
 				report.is_disputed = true;
+				let previous = report
+					.previous
+					.and_then(|p| <Reports<T>>::get(query_id, p).map(|r| (r.index, p)));
 
 				// Update last reported timestamp, if applicable
-				let _ = <LastReportedTimestamp<T>>::try_mutate(query_id, |lrt| match lrt {
-					// Check if last reported timestamp value is being removed
-					Some(last_reported_timestamp) if *last_reported_timestamp == timestamp => {
-						*lrt = report.previous; // set last reported to previous
-						Ok(())
-					},
-					_ => Err(()), // No mutation
-				});
+				// Check if last reported timestamp value is being removed
+				if Self::last_reported(query_id).map(|(_, t)| t) == Some(timestamp) {
+					// set last reported to previous
+					Self::set_last_reported(query_id, previous);
+				}
 
 				// Update next valid timestamp in series to point to previous valid timestamp (before one being removed)
 				let start = report.index.checked_add(1).ok_or(ArithmeticError::Overflow)?;
@@ -1200,23 +2583,147 @@ impl<T: Config> Pallet<T> {
 					if iterations > T::MaxDisputedTimeSeries::get() {
 						return Err(Error::<T>::MaxDisputedTimeSeriesReached.into());
 					}
-					let Some(timestamp) = <ReportedTimestampsByIndex<T>>::get(query_id, index)
-					else {
+					let Some(mut next) = Self::indexed_value(query_id, index) else {
 						break;
 					};
-					let mut next_report = <Reports<T>>::get(query_id, timestamp)
+					let mut next_report = <Reports<T>>::get(query_id, next.timestamp)
 						.ok_or(Error::<T>::InvalidTimestamp)?;
 					next_report.previous = report.previous;
-					<Reports<T>>::insert(query_id, timestamp, &next_report);
+					<Reports<T>>::insert(query_id, next.timestamp, &next_report);
 					if !next_report.is_disputed {
 						break;
 					}
+					next.previous = previous.map(|(index, _)| index);
+					<ReportedValues<T>>::insert(query_id, index, next);
 				}
-				Ok(iterations)
-			})?;
-		<ReportedValuesByTimestamp<T>>::remove(query_id, timestamp);
+				Ok((
+					iterations,
+					report.index,
+					report.reporter.clone(),
+					previous.map(|(index, _)| index),
+				))
+			},
+		)?;
+		// Archive value as disputed before removal
+		let value = Self::reported_value(query_id, index).unwrap_or_default();
+		Self::release_preimage(query_id, &value);
+		<ReportedValues<T>>::insert(
+			query_id,
+			index,
+			IndexedValueOf::<T> {
+				timestamp,
+				reporter: reporter.clone(),
+				is_disputed: true,
+				previous,
+			},
+		);
+		<ReportedValueData<T>>::remove(query_id, index);
+		migrations::v1::remove_reported_value::<T>(query_id, index, timestamp);
+		Self::uncache_value(query_id, timestamp);
+		Self::commit_value(commitments::Leaf {
+			query_id,
+			timestamp,
+			value_hash: Keccak256::hash(&value),
+			disputed: true,
+		});
+		indexing::archive(
+			query_id,
+			index,
+			&ArchivedReportOf::<T> { timestamp, value, reporter, is_disputed: true },
+		);
+		// Record removal for delivery to any subscribers, retaining a bounded number of removals
+		if <Subscriptions<T>>::count() > 0 {
+			let removal = <RemovalCount<T>>::get(query_id);
+			<Removals<T>>::insert(query_id, removal, timestamp);
+			<RemovalCount<T>>::insert(query_id, removal.saturating_add(1));
+			if let Some(expired) = removal.checked_sub(MAX_PENDING_REMOVALS) {
+				<Removals<T>>::remove(query_id, expired);
+			}
+		}
 		Self::deposit_event(Event::ValueRemoved { query_id, timestamp });
-		Ok(iterations)
+		Ok((iterations, T::OnValueRemoved::on_value_removed(query_id, timestamp)))
+	}
+
+	/// Returns the message a reporter signs for a value report, bound to this pallet (by its
+	/// identifier and index within the runtime) and chain so that the signature cannot be replayed
+	/// elsewhere, including against another instance of the pallet on the same chain.
+	/// # Arguments
+	/// * `payload` - The value report signed by the reporter.
+	/// # Returns
+	/// The bytes to be signed.
+	pub fn report_payload_message(payload: &ReportPayloadOf<T>) -> Vec<u8> {
+		let genesis_hash = <frame_system::Pallet<T>>::block_hash(T::BlockNumber::zero());
+		let pallet_index = Self::index() as u8;
+		(REPORT_PAYLOAD_TAG, genesis_hash, T::PalletId::get(), pallet_index, payload).encode()
+	}
+
+	/// Determines the timestamp of a new report, disambiguating reports of a query identifier
+	/// submitted within the same second by assigning the next unreported second, provided it is no
+	/// more than `MaxReportTimestampOffset` ahead of the current time.
+	/// # Arguments
+	/// * `query_id` - Identifier of the specific data feed.
+	/// * `now` - The current time.
+	/// # Returns
+	/// The timestamp of the new report.
+	pub(super) fn report_timestamp(
+		query_id: QueryId,
+		now: Timestamp,
+	) -> Result<Timestamp, DispatchError> {
+		let last = <ReportedTimestampCount<T>>::get(query_id)
+			.checked_sub(1)
+			.and_then(|index| Self::get_timestamp_by_query_id_and_index(query_id, index));
+		match last {
+			Some(last) if last >= now => {
+				let timestamp = last.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				ensure!(
+					timestamp.saturating_sub(now) <= T::MaxReportTimestampOffset::get(),
+					Error::<T>::ReportTimestampOffsetExceeded
+				);
+				Ok(timestamp)
+			},
+			_ => Ok(now),
+		}
+	}
+
+	/// Returns a reported value by index, which is only available until disputed.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported value.
+	/// * `index` - The index of the reported value.
+	/// # Returns
+	/// The reported value, if found.
+	pub(super) fn reported_value(query_id: QueryId, index: u32) -> Option<ValueOf<T>> {
+		<ReportedValueData<T>>::get(query_id, index)
+			.or_else(|| migrations::v1::reported_value::<T>(query_id, index))
+	}
+
+	/// Returns a reported value by index along with its timestamp, provided it has not been disputed.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported value.
+	/// * `index` - The index of the reported value.
+	/// # Returns
+	/// The reported value and its timestamp, if found.
+	pub(super) fn reported_value_and_timestamp(
+		query_id: QueryId,
+		index: u32,
+	) -> Option<(ValueOf<T>, Timestamp)> {
+		let reported = Self::indexed_value(query_id, index).filter(|r| !r.is_disputed)?;
+		Self::reported_value(query_id, index).map(|value| (value, reported.timestamp))
+	}
+
+	/// Resolves a reported value of a large value query to the value noted as a preimage.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported value.
+	/// * `value` - The reported value.
+	/// # Returns
+	/// The noted value for large value queries, otherwise the reported value.
+	pub(super) fn resolve_value(query_id: QueryId, value: Vec<u8>) -> Vec<u8> {
+		if !<LargeValueQueries<T>>::contains_key(query_id) {
+			return value;
+		}
+		ValueReference::decode_all(&mut &value[..])
+			.ok()
+			.and_then(|reference| Self::get_value_preimage(reference.hash))
+			.unwrap_or_default()
 	}
 
 	/// Retrieve value from the oracle based on timestamp.
@@ -1226,7 +2733,30 @@ impl<T: Config> Pallet<T> {
 	/// # Returns
 	/// Value for timestamp submitted, if found.
 	pub fn retrieve_data(query_id: QueryId, timestamp: Timestamp) -> Option<ValueOf<T>> {
-		<ReportedValuesByTimestamp<T>>::get(query_id, timestamp)
+		<Reports<T>>::get(query_id, timestamp)
+			.filter(|report| !report.is_disputed)
+			.and_then(|report| Self::reported_value(query_id, report.index))
+	}
+
+	/// Sets the index and timestamp of the last (non-disputed) reported value.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported value.
+	/// * `last_reported` - The index and timestamp of the last reported value, if any.
+	pub(super) fn set_last_reported(query_id: QueryId, last_reported: Option<(u32, Timestamp)>) {
+		<LastReported<T>>::set(query_id, last_reported);
+		migrations::v1::remove_last_reported::<T>(query_id);
+	}
+
+	/// Returns the parachain identifier of a location on a sibling parachain.
+	/// # Arguments
+	/// * `location` - The location.
+	/// # Returns
+	/// The parachain identifier, if the location is a sibling parachain.
+	pub(super) fn sibling(location: &MultiLocation) -> Option<ParaId> {
+		match location {
+			MultiLocation { parents: 1, interior: X1(Parachain(para_id)) } => Some(*para_id),
+			_ => None,
+		}
 	}
 
 	/// The account identifier of the sub-account used to hold staking rewards.
@@ -1242,11 +2772,159 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::QueryDataStored { query_id });
 	}
 
+	/// Stores a reported value and updates the reporter's statistics.
+	/// # Arguments
+	/// * `query_id` - Identifier of the specific data feed.
+	/// * `value` - Value the reporter submits to the oracle.
+	/// * `nonce` - The current value count for the query identifier.
+	/// * `query_data` - The data used to fulfil the data query.
+	/// * `reporter` - The identifier of the reporter.
+	/// * `staker` - The staking info of the reporter.
+	/// * `now` - The current time, from which the timestamp of the report is determined.
+	/// # Returns
+	/// The weight consumed by the `OnNewReport` handler.
+	pub(super) fn store_report(
+		query_id: QueryId,
+		value: ValueOf<T>,
+		nonce: Nonce,
+		query_data: QueryDataOf<T>,
+		reporter: AccountIdOf<T>,
+		staker: &mut StakeInfoOf<T>,
+		now: Timestamp,
+	) -> Result<Weight, DispatchError> {
+		let timestamp = Self::report_timestamp(query_id, now)?;
+		// Checks for no double reporting of timestamps
+		ensure!(
+			!<Reports<T>>::contains_key(query_id, timestamp),
+			Error::<T>::TimestampAlreadyReported
+		);
+		// Reference noted value of large value queries, so that it is retained
+		if let Some(reference) = Self::value_reference(query_id, &value)? {
+			<Preimages<T>>::mutate(reference.hash, |maybe| {
+				if let Some(preimage) = maybe {
+					preimage.references.saturating_inc();
+				}
+			});
+		}
+
+		// Update number of timestamps, value for given timestamp, and reporter for timestamp
+		let index = <ReportedTimestampCount<T>>::mutate(query_id, |count| {
+			let index = *count;
+			count.saturating_inc();
+			index
+		});
+		<ReportedValues<T>>::insert(
+			query_id,
+			index,
+			IndexedValueOf::<T> {
+				timestamp,
+				reporter: reporter.clone(),
+				is_disputed: false,
+				previous: None,
+			},
+		);
+		<ReportedValueData<T>>::insert(query_id, index, &value);
+		<Reports<T>>::insert(
+			query_id,
+			timestamp,
+			ReportOf::<T> {
+				index,
+				block_number: frame_system::Pallet::<T>::block_number(),
+				reporter: reporter.clone(),
+				is_disputed: false,
+				previous: Self::last_reported(query_id).map(|(_, t)| t),
+			},
+		);
+		Self::set_last_reported(query_id, Some((index, timestamp)));
+		Self::cache_value(query_id, timestamp, &value);
+		// Queue value for finality once the dispute window of its query identifier has elapsed
+		let reporting_lock = Self::get_reporting_lock(query_id);
+		let due = timestamp.saturating_add(reporting_lock);
+		<FinalityQueueRange<T>>::mutate(reporting_lock, |(_, tail)| {
+			<FinalityQueue<T>>::insert(reporting_lock, *tail, (query_id, timestamp, due));
+			tail.saturating_inc();
+		});
+		// Commit to value, so that it can be verified by external chains and light clients
+		Self::commit_value(commitments::Leaf {
+			query_id,
+			timestamp,
+			value_hash: Keccak256::hash(&value),
+			disputed: false,
+		});
+		// Archive report, so that it remains available offchain once pruned
+		indexing::archive(
+			query_id,
+			index,
+			&ArchivedReportOf::<T> {
+				timestamp,
+				value: value.clone(),
+				reporter: reporter.clone(),
+				is_disputed: false,
+			},
+		);
+
+		// backlog: Disperse Time Based Reward
+		// uint256 _reward = ((block.timestamp - timeOfLastNewValue) * timeBasedReward) / 300; //.5 TRB per 5 minutes
+		// uint256 _totalTimeBasedRewardsBalance =
+		// 	token.balanceOf(address(this)) -
+		// 		(totalStakeAmount + stakingRewardsBalance + toWithdraw);
+		// if (_totalTimeBasedRewardsBalance > 0 && _reward > 0) {
+		// 	if (_totalTimeBasedRewardsBalance < _reward) {
+		// 		token.transfer(msg.sender, _totalTimeBasedRewardsBalance);
+		// 	} else {
+		// 		token.transfer(msg.sender, _reward);
+		// 	}
+		// }
+
+		// Update last oracle value and number of values submitted by a reporter
+		<TimeOfLastNewValue<T>>::set(Some(timestamp));
+		staker.reports_submitted.saturating_inc();
+		<StakerReportsSubmittedByQueryId<T>>::mutate(&reporter, query_id, |reports| {
+			reports.saturating_inc();
+		});
+		<StakerDetails<T>>::insert(&reporter, &*staker);
+		<ReporterLastBlock<T>>::insert(&reporter, frame_system::Pallet::<T>::block_number());
+		let hook_weight = T::OnNewReport::on_new_report(query_id, timestamp, &reporter);
+		Self::deposit_event(Event::NewReport {
+			query_id,
+			time: timestamp,
+			value,
+			nonce,
+			query_data,
+			reporter,
+		});
+		Ok(hook_weight)
+	}
+
+	/// Determines the maximum query data and value lengths of a batch of submissions.
+	/// # Arguments
+	/// * `submissions` - The submissions.
+	/// # Returns
+	/// The maximum query data length and the maximum value length.
+	pub(super) fn submission_lengths(submissions: &[SubmissionOf<T>]) -> (u32, u32) {
+		submissions.iter().fold((0, 0), |(q, v), (_, value, _, query_data)| {
+			(q.max(query_data.len() as u32), v.max(value.len() as u32))
+		})
+	}
+
+	/// The account identifier of the sub-account used to hold subscription deposits.
+	///
+	/// This actually does computation. If you need to keep using it, then make sure you cache the
+	/// value and only call this once.
+	pub(super) fn subscription_deposits() -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(b"subscriptions")
+	}
+
 	/// Tallies the votes and begins the challenge period.
 	/// # Arguments
 	/// * `dispute_id` - The dispute identifier.
 	/// * `result` - The outcome of the vote, as determined by governance.
-	pub(super) fn tally_votes(dispute_id: DisputeId, result: VoteResult) -> DispatchResult {
+	/// # Returns
+	/// The weight consumed by the `OnValueRemoved` handler.
+	pub(super) fn tally_votes(
+		dispute_id: DisputeId,
+		result: VoteResult,
+	) -> Result<Weight, DispatchError> {
 		// Get current vote round for dispute
 		let vote_round = <VoteRounds<T>>::get(dispute_id);
 		let initiator = <VoteInfo<T>>::try_mutate(
@@ -1284,15 +2962,14 @@ impl<T: Config> Pallet<T> {
 				}
 			},
 		)?;
+		let dispute = <DisputeInfo<T>>::get(dispute_id).ok_or(Error::<T>::InvalidDispute)?;
 		Self::deposit_event(Event::VoteTallied {
 			dispute_id,
 			result,
 			initiator,
-			reporter: <DisputeInfo<T>>::get(dispute_id)
-				.ok_or(Error::<T>::InvalidDispute)?
-				.disputed_reporter,
+			reporter: dispute.disputed_reporter,
 		});
-		Ok(())
+		Ok(T::OnValueRemoved::on_vote_tallied(dispute.query_id, dispute.timestamp, result))
 	}
 
 	/// The account identifier of the sub-account used to hold tips.
@@ -1303,6 +2980,32 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_sub_account_truncating(b"tips")
 	}
 
+	/// Removes a disputed value from the value cache of a query identifier, if enabled, replacing
+	/// it with the previous non-disputed value so that the cache continues to hold the latest
+	/// values.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the disputed value.
+	/// * `timestamp` - The timestamp of the disputed value.
+	pub(super) fn uncache_value(query_id: QueryId, timestamp: Timestamp) {
+		let Some(mut cached) = <ValueCaches<T>>::get(query_id) else { return };
+		let Some(position) = cached.iter().position(|(t, _)| *t == timestamp) else { return };
+		cached.remove(position);
+		// Previous values have already been updated to exclude the disputed value
+		let previous = match cached.first() {
+			Some((oldest, _)) => <Reports<T>>::get(query_id, oldest)
+				.and_then(|report| report.previous)
+				.and_then(|previous| <Reports<T>>::get(query_id, previous))
+				.map(|report| report.index),
+			None => Self::last_reported(query_id).map(|(index, _)| index),
+		};
+		if let Some((value, timestamp)) =
+			previous.and_then(|index| Self::reported_value_and_timestamp(query_id, index))
+		{
+			let _ = cached.try_insert(0, (timestamp, value));
+		}
+		<ValueCaches<T>>::insert(query_id, cached);
+	}
+
 	/// A unit in which balances are recorded.
 	fn unit() -> Result<u128, DispatchError> {
 		10u128
@@ -1531,6 +3234,109 @@ impl<T: Config> Pallet<T> {
 		})?;
 		Ok(())
 	}
+
+	/// Validates a signed value report before it is included in the transaction pool or a block.
+	/// # Arguments
+	/// * `payload` - The value report signed by the reporter.
+	/// * `query_data` - The data used to fulfil the data query.
+	/// * `signature` - The reporter's signature of the report payload message.
+	/// # Returns
+	/// The validity of the report, with priority and longevity set from the reporter's stake.
+	pub(super) fn validate_report_payload(
+		payload: &ReportPayloadOf<T>,
+		query_data: &QueryDataOf<T>,
+		signature: &T::ReporterSignature,
+	) -> TransactionValidity {
+		let timestamp = Self::now();
+		if timestamp > payload.valid_until {
+			return InvalidTransaction::Stale.into();
+		}
+		// Reject replayed or premature reports
+		match payload.nonce.cmp(&<ReportedTimestampCount<T>>::get(payload.query_id)) {
+			Ordering::Less => return InvalidTransaction::Stale.into(),
+			Ordering::Greater => return InvalidTransaction::Future.into(),
+			Ordering::Equal => {},
+		}
+		if Self::ensure_valid_submission(
+			payload.query_id,
+			&payload.value,
+			payload.nonce,
+			query_data,
+		)
+		.is_err()
+		{
+			return InvalidTransaction::Call.into();
+		}
+		if !signature.verify(&Self::report_payload_message(payload)[..], &payload.reporter) {
+			return InvalidTransaction::BadProof.into();
+		}
+		let staker = Self::ensure_can_report(
+			&payload.reporter,
+			Self::get_risk_class(payload.query_id),
+			timestamp,
+		)
+		.map_err(Self::invalid_transaction)?;
+		// Reporters with a larger stake have a shorter reporting lock, so are prioritised but
+		// their reports expire sooner
+		let stake_multiple = Self::get_stake_requirement(payload.query_id)
+			.ok()
+			.and_then(|requirement| staker.staked_balance.checked_div(requirement))
+			.unwrap_or_default()
+			.min(u64::MAX.into())
+			.low_u64()
+			.max(1);
+		ValidTransaction::with_tag_prefix("Tellor")
+			.priority(T::UnsignedPriority::get().saturating_add(stake_multiple))
+			// A single report per reporter, and per value count, within the pool
+			.and_provides(&payload.reporter)
+			.and_provides((payload.query_id, payload.nonce))
+			.longevity((T::UnsignedLongevity::get() / stake_multiple).max(1))
+			.propagate(true)
+			.build()
+	}
+
+	/// Ensures that a value submission by the reporter would succeed at the current time, without
+	/// modifying storage.
+	/// # Arguments
+	/// * `reporter` - The account submitting the value.
+	/// * `query_id` - Identifier of the specific data feed.
+	/// * `value` - Value the reporter submits to the oracle.
+	/// * `nonce` - The current value count for the query identifier.
+	/// * `query_data` - The data used to fulfil the data query.
+	pub(super) fn validate_submission(
+		reporter: &AccountIdOf<T>,
+		query_id: QueryId,
+		value: &ValueOf<T>,
+		nonce: Nonce,
+		query_data: &QueryDataOf<T>,
+	) -> DispatchResult {
+		Self::ensure_valid_submission(query_id, value, nonce, query_data)?;
+		let timestamp = Self::now();
+		Self::ensure_can_report(reporter, Self::get_risk_class(query_id), timestamp)?;
+		Self::report_timestamp(query_id, timestamp)?;
+		Ok(())
+	}
+
+	/// Ensures that a value reported for a large value query references a value noted as a
+	/// preimage.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported value.
+	/// * `value` - The reported value.
+	/// # Returns
+	/// The reference to the noted value for large value queries, otherwise `None`.
+	pub(super) fn value_reference(
+		query_id: QueryId,
+		value: &[u8],
+	) -> Result<Option<ValueReference>, DispatchError> {
+		if !<LargeValueQueries<T>>::contains_key(query_id) {
+			return Ok(None);
+		}
+		let reference =
+			ValueReference::decode_all(&mut &value[..]).map_err(|_| Error::<T>::InvalidValue)?;
+		let preimage = <Preimages<T>>::get(reference.hash).ok_or(Error::<T>::PreimageNotNoted)?;
+		ensure!(preimage.len == reference.len, Error::<T>::InvalidValue);
+		Ok(Some(reference))
+	}
 }
 
 impl<T: Config> UsingTellor<AccountIdOf<T>> for Pallet<T> {
@@ -1539,16 +3345,44 @@ impl<T: Config> UsingTellor<AccountIdOf<T>> for Pallet<T> {
 	}
 
 	fn get_data_after(query_id: QueryId, timestamp: Timestamp) -> Option<(Vec<u8>, Timestamp)> {
+		// Serve from value cache, if enabled and complete after timestamp
+		if let Some(cached) = <ValueCaches<T>>::get(query_id) {
+			if Self::is_cached_after(&cached, timestamp) {
+				return cached
+					.into_iter()
+					.find(|(t, _)| *t > timestamp)
+					.map(|(t, value)| (Self::resolve_value(query_id, value.into_inner()), t));
+			}
+		}
 		Self::get_index_for_data_after(query_id, timestamp)
-			.and_then(|index| Self::get_timestamp_by_query_id_and_index(query_id, index))
-			.and_then(|timestamp_retrieved| {
-				Self::retrieve_data(query_id, timestamp_retrieved)
-					.map(|value| (value.into_inner(), timestamp_retrieved))
+			.and_then(|index| Self::reported_value_and_timestamp(query_id, index))
+			.map(|(value, timestamp)| {
+				(Self::resolve_value(query_id, value.into_inner()), timestamp)
 			})
 	}
 
 	fn get_data_before(query_id: QueryId, timestamp: Timestamp) -> Option<(Vec<u8>, Timestamp)> {
-		Self::get_data_before(query_id, timestamp).map(|(v, t)| (v.into_inner(), t))
+		Self::get_data_before(query_id, timestamp)
+			.map(|(v, t)| (Self::resolve_value(query_id, v.into_inner()), t))
+	}
+
+	fn get_derived_value(
+		query_id: QueryId,
+		policy: ReadPolicy,
+	) -> Result<(Vec<u8>, Timestamp), ReadRejection> {
+		Self::get_derived_value(query_id, policy)
+	}
+
+	fn get_finalized_value_before(
+		query_id: QueryId,
+		timestamp: Timestamp,
+	) -> Option<(Vec<u8>, Timestamp)> {
+		Self::get_finalized_value_before(query_id, timestamp)
+			.map(|(v, t)| (Self::resolve_value(query_id, v.into_inner()), t))
+	}
+
+	fn get_first_retained_index(query_id: QueryId) -> u32 {
+		Self::get_first_retained_index(query_id)
 	}
 
 	fn get_index_for_data_after(query_id: QueryId, timestamp: Timestamp) -> Option<u32> {
@@ -1558,8 +3392,8 @@ impl<T: Config> UsingTellor<AccountIdOf<T>> for Pallet<T> {
 		}
 		count.saturating_dec();
 		let mut search = true; // perform binary search
-		let mut middle = 0;
-		let mut start = 0;
+		let mut middle = Self::get_first_retained_index(query_id); // skip any pruned values
+		let mut start = middle;
 		let mut end = count;
 		// checking boundaries to short-circuit the algorithm
 		let mut timestamp_retrieved =
@@ -1599,7 +3433,6 @@ impl<T: Config> UsingTellor<AccountIdOf<T>> for Pallet<T> {
 					// candidate found, check for disputes
 					search = false;
 					middle.saturating_inc();
-					timestamp_retrieved = next_time;
 				} else {
 					// look from middle + 1(next value) to end
 					start = middle.saturating_add(1);
@@ -1607,20 +3440,20 @@ impl<T: Config> UsingTellor<AccountIdOf<T>> for Pallet<T> {
 			}
 		}
 		// candidate found, check for disputed values
-		if !Self::is_in_dispute(query_id, timestamp_retrieved) {
-			// timestamp_retrieved is correct
+		let is_disputed =
+			|index| Self::indexed_value(query_id, index).map_or(false, |v| v.is_disputed);
+		if !is_disputed(middle) {
+			// value at middle is correct
 			Some(middle)
 		} else {
 			// iterate forward until we find a non-disputed value
-			while Self::is_in_dispute(query_id, timestamp_retrieved) && middle < count {
+			while is_disputed(middle) && middle < count {
 				middle.saturating_inc();
-				timestamp_retrieved =
-					Self::get_timestamp_by_query_id_and_index(query_id, middle).unwrap_or_default();
 			}
-			if middle == count && Self::is_in_dispute(query_id, timestamp_retrieved) {
+			if middle == count && is_disputed(middle) {
 				return None;
 			}
-			// timestamp_retrieved is correct
+			// value at middle is correct
 			Some(middle)
 		}
 	}
@@ -1635,6 +3468,21 @@ impl<T: Config> UsingTellor<AccountIdOf<T>> for Pallet<T> {
 		max_age: Timestamp,
 		max_count: u32,
 	) -> Vec<(Vec<u8>, Timestamp)> {
+		// Serve from value cache, if enabled and either complete within range or holding enough values
+		if let Some(cached) = <ValueCaches<T>>::get(query_id) {
+			let after = timestamp.saturating_sub(max_age);
+			let mut values: Vec<_> = cached
+				.iter()
+				.rev()
+				.filter(|(t, _)| *t < timestamp && *t > after)
+				.take(max_count as usize)
+				.map(|(t, value)| (Self::resolve_value(query_id, value.to_vec()), *t))
+				.collect();
+			if values.len() == max_count as usize || Self::is_cached_after(&cached, after) {
+				values.reverse();
+				return values;
+			}
+		}
 		// get index of first possible value
 		let Some(start_index) =
 			Self::get_index_for_data_after(query_id, timestamp.saturating_sub(max_age))
@@ -1650,30 +3498,21 @@ impl<T: Config> UsingTellor<AccountIdOf<T>> for Pallet<T> {
 		let mut value_count: usize = 0;
 		let mut index = 0;
 		let max_count = max_count as usize;
-		let mut timestamps = Vec::with_capacity(max_count);
-		// generate array of non-disputed timestamps within range
+		let mut result = Vec::with_capacity(max_count);
+		// generate array of non-disputed values within range
 		while value_count < max_count
 			&& end_index.saturating_add(1).saturating_sub(index) > start_index
 		{
-			if let Some(timestamp_retrieved) =
-				Self::get_timestamp_by_query_id_and_index(query_id, end_index.saturating_sub(index))
+			if let Some((value, timestamp)) =
+				Self::reported_value_and_timestamp(query_id, end_index.saturating_sub(index))
 			{
-				if !Self::is_in_dispute(query_id, timestamp_retrieved) {
-					timestamps.push(timestamp_retrieved);
-					value_count.saturating_inc();
-				}
+				result.push((Self::resolve_value(query_id, value.into_inner()), timestamp));
+				value_count.saturating_inc();
 			}
 			index.saturating_inc();
 		}
-
-		// retrieve values and reverse timestamps order
-		let mut result = Vec::new();
-		for i in 0..value_count {
-			let timestamp = timestamps[value_count - 1 - i];
-			if let Some(data) = Self::retrieve_data(query_id, timestamp) {
-				result.push((data.into_inner(), timestamp));
-			}
-		}
+		// reverse order, oldest first
+		result.reverse();
 		result
 	}
 
@@ -1681,6 +3520,10 @@ impl<T: Config> UsingTellor<AccountIdOf<T>> for Pallet<T> {
 		Self::get_new_value_count_by_query_id(query_id)
 	}
 
+	fn get_query_health(query_id: QueryId) -> QueryHealth {
+		Self::get_query_health(query_id)
+	}
+
 	fn get_reporter_by_timestamp(
 		query_id: QueryId,
 		timestamp: Timestamp,
@@ -1688,6 +3531,29 @@ impl<T: Config> UsingTellor<AccountIdOf<T>> for Pallet<T> {
 		Self::get_reporter_by_timestamp(query_id, timestamp)
 	}
 
+	fn get_safe_value(
+		query_id: QueryId,
+		policy: ReadPolicy,
+	) -> Result<SafeValue<AccountIdOf<T>>, ReadRejection> {
+		Self::get_safe_value(query_id, policy).map(|safe_value| SafeValue {
+			value: Self::resolve_value(query_id, safe_value.value),
+			..safe_value
+		})
+	}
+
+	fn get_safe_value_or_emergency(
+		query_id: QueryId,
+		policy: ReadPolicy,
+	) -> Result<ReadValue<AccountIdOf<T>>, ReadRejection> {
+		Self::get_safe_value_or_emergency(query_id, policy).map(|read_value| match read_value {
+			ReadValue::Reported(safe_value) => ReadValue::Reported(SafeValue {
+				value: Self::resolve_value(query_id, safe_value.value),
+				..safe_value
+			}),
+			emergency => emergency,
+		})
+	}
+
 	fn get_timestamp_by_query_id_and_index(query_id: QueryId, index: u32) -> Option<Timestamp> {
 		Self::get_timestamp_by_query_id_and_index(query_id, index)
 	}
@@ -1701,6 +3567,59 @@ impl<T: Config> UsingTellor<AccountIdOf<T>> for Pallet<T> {
 	}
 
 	fn retrieve_data(query_id: QueryId, timestamp: Timestamp) -> Option<Vec<u8>> {
-		Self::retrieve_data(query_id, timestamp).map(|v| v.into_inner())
+		Self::retrieve_data(query_id, timestamp)
+			.map(|v| Self::resolve_value(query_id, v.into_inner()))
+	}
+}
+
+impl<T: Config> RequestTellor<AccountIdOf<T>, BalanceOf<T>, T::MaxQueryDataLength> for Pallet<T> {
+	fn request_data(
+		requester: AccountIdOf<T>,
+		query_id: QueryId,
+		earliest_timestamp: Timestamp,
+		dispute_buffer: Timestamp,
+		callback: CallbackId,
+	) -> Result<RequestId, DispatchError> {
+		let request_id = <RequestCount<T>>::get();
+		let request_count = request_id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+		ensure!(
+			<PendingRequests<T>>::count() < T::MaxPendingRequests::get(),
+			Error::<T>::MaxPendingRequestsReached
+		);
+		// Bound the deadline, so that a request cannot remain pending indefinitely
+		let now = Self::now();
+		let max_request_delay = T::MaxRequestDelay::get();
+		ensure!(
+			earliest_timestamp.saturating_sub(now) <= max_request_delay
+				&& dispute_buffer <= max_request_delay,
+			Error::<T>::RequestDelayExceeded
+		);
+		<PendingRequests<T>>::insert(
+			request_id,
+			RequestOf::<T> {
+				id: request_id,
+				requester: requester.clone(),
+				query_id,
+				earliest_timestamp,
+				dispute_buffer,
+				callback,
+				deadline: earliest_timestamp
+					.max(now)
+					.saturating_add(dispute_buffer)
+					.saturating_add(T::RequestTimeout::get()),
+			},
+		);
+		<RequestCount<T>>::set(request_count);
+		Self::deposit_event(Event::DataRequested { request_id, query_id, requester, callback });
+		Ok(request_id)
+	}
+
+	fn tip(
+		tipper: AccountIdOf<T>,
+		query_id: QueryId,
+		amount: BalanceOf<T>,
+		query_data: QueryDataOf<T>,
+	) -> DispatchResult {
+		Self::do_tip(tipper, query_id, amount, query_data)
 	}
 }
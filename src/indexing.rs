@@ -0,0 +1,75 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+//! Archival of reported values to offchain storage via offchain indexing, so that the full history
+//! of each query identifier remains available to node-side readers after values are pruned from the
+//! oracle.
+//!
+//! Each report is written to persistent offchain storage under [`report_key`] when it is submitted,
+//! and rewritten when it is removed by a dispute. Values noted as preimages for large value queries
+//! are written under [`preimage_key`] when noted. Offchain indexing must be enabled on the node.
+
+use crate::{
+	types::{PreimageHash, QueryId},
+	ArchivedReport,
+};
+use codec::Encode;
+use sp_std::vec::Vec;
+
+/// The prefix of the offchain storage keys of archived preimages.
+pub const PREIMAGE_KEY_PREFIX: &[u8] = b"tellor::archive::preimage";
+/// The prefix of the offchain storage keys of archived reports.
+pub const REPORT_KEY_PREFIX: &[u8] = b"tellor::archive::report";
+
+/// Returns the offchain storage key of an archived preimage.
+/// # Arguments
+/// * `hash` - The hash of the noted value.
+/// # Returns
+/// The prefix, followed by the hash.
+pub fn preimage_key(hash: PreimageHash) -> Vec<u8> {
+	[PREIMAGE_KEY_PREFIX, hash.as_bytes()].concat()
+}
+
+/// Returns the offchain storage key of an archived report.
+/// # Arguments
+/// * `query_id` - The query identifier of the reported value.
+/// * `index` - The index of the reported value.
+/// # Returns
+/// The prefix, followed by the query identifier and the big-endian index.
+pub fn report_key(query_id: QueryId, index: u32) -> Vec<u8> {
+	[REPORT_KEY_PREFIX, query_id.as_bytes(), &index.to_be_bytes()].concat()
+}
+
+/// Writes an archived report to offchain storage.
+/// # Arguments
+/// * `query_id` - The query identifier of the reported value.
+/// * `index` - The index of the reported value.
+/// * `report` - The report to be archived.
+pub(crate) fn archive<AccountId: Encode, Value: Encode>(
+	query_id: QueryId,
+	index: u32,
+	report: &ArchivedReport<AccountId, Value>,
+) {
+	sp_io::offchain_index::set(&report_key(query_id, index), &report.encode());
+}
+
+/// Writes a value noted as a preimage to offchain storage.
+/// # Arguments
+/// * `hash` - The hash of the noted value.
+/// * `value` - The noted value, which is written as is.
+pub(crate) fn archive_preimage(hash: PreimageHash, value: &[u8]) {
+	sp_io::offchain_index::set(&preimage_key(hash), value);
+}
@@ -19,11 +19,16 @@
 
 pub use crate::xcm::{ContractLocation, LocationToAccount, LocationToOrigin};
 use crate::{
-	constants::{MAX_AGGREGATE_VOTES_SENT_PER_BLOCK, MAX_ITERATIONS, REPORTING_LOCK},
+	constants::{
+		HEALTH_WINDOW, MAX_AGGREGATE_VOTES_SENT_PER_BLOCK, MAX_FINALIZED_VALUES_PER_BLOCK,
+		MAX_HEALTH_CHECKS_PER_BLOCK, MAX_ITERATIONS, MAX_MIGRATED_VALUES_PER_BLOCK,
+		MAX_READ_POLICY_VALUES, MAX_REQUESTS_PROCESSED_PER_BLOCK,
+	},
 	contracts::gas_limits,
 };
 use codec::Encode;
 pub use constants::{DAYS, HOURS, MAX_VOTE_ROUNDS, MINUTES, WEEKS};
+pub use extensions::CheckValueSubmission;
 use frame_support::{
 	dispatch::{DispatchError, DispatchResult},
 	ensure,
@@ -36,13 +41,18 @@ use sp_runtime::{
 	Saturating,
 };
 use sp_std::vec::Vec;
-pub use traits::{SendXcm, UsingTellor};
+pub use traits::{OnNewReport, OnResponse, OnValueRemoved, RequestTellor, SendXcm, UsingTellor};
 use types::*;
 pub use types::{
 	autopay::{Feed, Tip},
 	governance::VoteResult,
-	oracle::StakeInfo,
-	Address, DisputeId, FeedId, QueryId, Timestamp, Tributes, Weights, U256,
+	oracle::{
+		ArchivedReport, DerivedFeed, DerivedOperation, EmergencyValue, HealthLimits,
+		HealthThresholds, QueryHealth, ReadPolicy, ReadRejection, ReadValue, ReportPayload,
+		Request, RiskClass, SafeValue, StakeInfo, Subscription, ValueReference,
+	},
+	Address, BytesToU256, CallbackId, CommitHash, DisputeId, FeedId, PreimageHash, QueryId,
+	RequestId, RiskClassId, SubscriptionId, Timestamp, Tributes, Weights, U256,
 };
 
 #[cfg(test)]
@@ -53,9 +63,16 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod commitments;
 mod constants;
 mod contracts;
+mod extensions;
 mod impls;
+pub mod indexing;
+pub mod migrations;
+#[cfg(feature = "offchain-worker")]
+pub mod offchain;
+pub mod proofs;
 pub mod traits;
 mod types;
 pub mod weights;
@@ -74,8 +91,10 @@ pub mod pallet {
 	use crate::{contracts::Abi, xcm::ContractLocation, Tip};
 	use ::xcm::latest::prelude::*;
 	use codec::Compact;
+	#[cfg(feature = "offchain-worker")]
+	use frame_support::log;
 	use frame_support::{
-		dispatch::WithPostDispatchInfo,
+		dispatch::{Pays, WithPostDispatchInfo},
 		pallet_prelude::*,
 		sp_runtime::traits::{CheckedAdd, CheckedMul, Hash},
 		traits::{
@@ -86,20 +105,27 @@ pub mod pallet {
 		PalletId,
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_core::U256;
-	use sp_runtime::{traits::CheckedSub, ArithmeticError, SaturatedConversion};
+	use sp_core::{H256, U256};
+	use sp_runtime::{
+		traits::{CheckedSub, IdentifyAccount, Verify},
+		ArithmeticError, Permill, SaturatedConversion,
+	};
 	use sp_std::{prelude::*, result};
 
 	#[cfg(feature = "runtime-benchmarks")]
 	use crate::traits::BenchmarkHelper;
-	use crate::traits::Weigher;
+	use crate::traits::{OffchainWorkerConfig, Weigher};
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: frame_system::Config + OffchainWorkerConfig<Call<Self>> {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// The runtime origin type.
@@ -120,6 +146,9 @@ pub mod pallet {
 		type Fee: Get<u16>;
 		/// The (interior) fee location to be used by controller contracts for XCM execution on this parachain.
 		type FeeLocation: Get<InteriorMultiLocation>;
+		/// Whether successful value submissions from staked reporters are exempt from transaction fees.
+		#[pallet::constant]
+		type FeelessSubmissions: Get<bool>;
 		/// The location of the governance controller contract.
 		#[pallet::constant]
 		type Governance: Get<ContractLocation>;
@@ -128,15 +157,41 @@ pub mod pallet {
 		/// Initial dispute fee.
 		#[pallet::constant]
 		type InitialDisputeFee: Get<BalanceOf<Self>>;
+		/// The maximum number of latest values cached for each query identifier with a value cache.
+		#[pallet::constant]
+		type MaxCachedValues: Get<u32>;
 		/// The maximum number of timestamps per claim.
 		#[pallet::constant]
 		type MaxClaimTimestamps: Get<u32>;
 		/// The maximum number of sequential disputed timestamps.
 		#[pallet::constant]
 		type MaxDisputedTimeSeries: Get<u32>;
+		/// The maximum amount of time an emergency value published by governance remains valid.
+		#[pallet::constant]
+		type MaxEmergencyValueDuration: Get<Timestamp>;
+		/// The maximum length of a value noted as a preimage for a large value query.
+		#[pallet::constant]
+		type MaxLargeValueLength: Get<u32>;
+		/// The maximum number of pending data requests.
+		#[pallet::constant]
+		type MaxPendingRequests: Get<u32>;
 		/// The maximum length of query data.
 		#[pallet::constant]
 		type MaxQueryDataLength: Get<u32>;
+		/// The maximum amount of time the timestamp of a report can be ahead of the current time,
+		/// when disambiguating reports of a query identifier submitted within the same second.
+		#[pallet::constant]
+		type MaxReportTimestampOffset: Get<Timestamp>;
+		/// The maximum amount of time the earliest timestamp and dispute buffer of a data request
+		/// can each be ahead of the current time, which bounds the deadline of the request.
+		#[pallet::constant]
+		type MaxRequestDelay: Get<Timestamp>;
+		/// The maximum number of values submitted to the oracle in a single batch.
+		#[pallet::constant]
+		type MaxSubmissionsPerBatch: Get<u32>;
+		/// The maximum number of subscriptions of remote parachains.
+		#[pallet::constant]
+		type MaxSubscriptions: Get<u32>;
 		/// The maximum length of an individual value submitted to the oracle.
 		#[pallet::constant]
 		type MaxValueLength: Get<u32>;
@@ -146,17 +201,69 @@ pub mod pallet {
 		/// The minimum amount of tokens required to stake.
 		#[pallet::constant]
 		type MinimumStakeAmount: Get<u128>;
+		/// Handler for when a new value is reported.
+		type OnNewReport: OnNewReport<Self::AccountId>;
+		/// Handler for responses to data requests.
+		type OnResponse: OnResponse;
+		/// Handler for when a value is removed by a dispute, along with the outcome of the dispute.
+		type OnValueRemoved: OnValueRemoved;
 		/// The identifier of the pallet within the runtime.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 		/// The local parachain's own identifier.
 		#[pallet::constant]
 		type ParachainId: Get<ParaId>;
+		/// Base amount held from a reporter for noting a value as a preimage, which is returned
+		/// when the noted value is removed.
+		#[pallet::constant]
+		type PreimageBaseDeposit: Get<BalanceOf<Self>>;
+		/// Amount held from a reporter for each byte of a value noted as a preimage, in addition to
+		/// the base deposit.
+		#[pallet::constant]
+		type PreimageByteDeposit: Get<BalanceOf<Self>>;
 		/// Origin that manages registration with the controller contracts.
 		type RegisterOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 		/// The location of the registry controller contract.
 		#[pallet::constant]
 		type Registry: Get<ContractLocation>;
+		/// Amount charged to the sovereign account of a remote parachain for each byte of the value
+		/// replied to an oracle query, in addition to the remote query fee.
+		#[pallet::constant]
+		type RemoteQueryByteFee: Get<BalanceOf<Self>>;
+		/// Amount charged to the sovereign account of a remote parachain for each oracle query
+		/// answered, covering the fees paid by this parachain for sending the reply.
+		#[pallet::constant]
+		type RemoteQueryFee: Get<BalanceOf<Self>>;
+		/// Origin of remote parachains permitted to query oracle values, resolving to the parachain
+		/// location.
+		type RemoteQueryOrigin: EnsureOrigin<
+			<Self as frame_system::Config>::RuntimeOrigin,
+			Success = MultiLocation,
+		>;
+		/// The identifier of reporter keys used by the offchain worker.
+		#[cfg(feature = "offchain-worker")]
+		type ReporterAuthorityId: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>;
+		/// The public key used by reporters to sign value reports submitted without fees.
+		type ReporterPublic: IdentifyAccount<AccountId = Self::AccountId>;
+		/// The signature of a value report submitted without fees.
+		type ReporterSignature: Verify<Signer = Self::ReporterPublic> + Parameter;
+		/// Amount of time a data request remains pending after its earliest eligible delivery time,
+		/// before timing out.
+		#[pallet::constant]
+		type RequestTimeout: Get<Timestamp>;
+		/// Amount paid to staking rewards by a reporter when retracting their own value.
+		#[pallet::constant]
+		type RetractionPenalty: Get<BalanceOf<Self>>;
+		/// Amount of time after reporting a value within which the reporter can retract it.
+		#[pallet::constant]
+		type RetractionPeriod: Get<Timestamp>;
+		/// Amount of time a reporter has to reveal a committed value, for query identifiers using commit-reveal.
+		#[pallet::constant]
+		type RevealWindow: Get<Timestamp>;
+		/// Means of converting the location of a remote parachain into its sovereign account on this
+		/// parachain, which pays the subscription fee of each value pushed and the remote query fee
+		/// of each query answered.
+		type SovereignAccountOf: xcm_executor::traits::Convert<MultiLocation, Self::AccountId>;
 		// Amount required to be a staker, in the currency as specified in the staking token price query identifier.
 		#[pallet::constant]
 		type StakeAmountCurrencyTarget: Get<u128>;
@@ -168,8 +275,33 @@ pub mod pallet {
 		/// Staking token to local token 'SpotPrice' query identifier, used for updating dispute fee.
 		#[pallet::constant]
 		type StakingToLocalTokenPriceQueryId: Get<QueryId>;
+		/// Origin of remote parachains managing subscriptions, resolving to the parachain location.
+		type SubscriberOrigin: EnsureOrigin<
+			<Self as frame_system::Config>::RuntimeOrigin,
+			Success = MultiLocation,
+		>;
+		/// Amount held from the sovereign account of a subscribing parachain for each subscription,
+		/// which is returned when the subscription is cancelled.
+		#[pallet::constant]
+		type SubscriptionDeposit: Get<BalanceOf<Self>>;
+		/// Amount of time a value must have been reported for before it is pushed to subscribers,
+		/// to allow time for disputes.
+		#[pallet::constant]
+		type SubscriptionDisputeBuffer: Get<Timestamp>;
+		/// Amount charged to the sovereign account of a subscribing parachain for each value pushed,
+		/// covering the fees paid by this parachain for sending.
+		#[pallet::constant]
+		type SubscriptionFee: Get<BalanceOf<Self>>;
 		/// The on-chain time provider.
 		type Time: UnixTime;
+		/// Number of blocks a value report submitted without fees remains valid in the transaction
+		/// pool, divided by the reporter's stake multiple.
+		#[pallet::constant]
+		type UnsignedLongevity: Get<TransactionLongevity>;
+		/// Base priority of a value report submitted without fees, increased by the reporter's
+		/// stake multiple.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
 		/// Frequency of stake amount updates.
 		#[pallet::constant]
 		type UpdateStakeAmountInterval: Get<Timestamp>;
@@ -190,6 +322,7 @@ pub mod pallet {
 			Self::AccountId,
 			Self::Balance,
 			Self::MaxQueryDataLength,
+			Self::ReporterSignature,
 		>;
 		/// Means of measuring the weight consumed by an XCM message on destination chain(s).
 		type Weigher: Weigher;
@@ -217,6 +350,10 @@ pub mod pallet {
 	/// Feed identifiers that have funding
 	#[pallet::storage]
 	pub(super) type FeedsWithFunding<T> = StorageMap<_, Identity, FeedId, ()>;
+	/// The shortest intervals of the funded feeds of query identifiers, used to assess staleness.
+	#[pallet::storage]
+	pub(super) type FundedFeedIntervals<T> =
+		StorageMap<_, Identity, QueryId, types::FundedFeedIntervals, ValueQuery>;
 	/// Mapping feed identifier to query identifier
 	#[pallet::storage]
 	pub(super) type QueryIdFromDataFeedId<T> = StorageMap<_, Identity, FeedId, QueryId>;
@@ -239,28 +376,131 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn accumulated_reward_per_share)]
 	pub(super) type AccumulatedRewardPerShare<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
-	/// The last (non-disputed) reported timestamp (by query identifier).
+	/// Query identifiers which require values to be committed before being revealed.
+	#[pallet::storage]
+	pub(super) type CommitRevealQueries<T> = StorageMap<_, Identity, QueryId, ()>;
+	/// The number of leaves appended to the commitment range.
+	#[pallet::storage]
+	pub(super) type CommitmentLeafCount<T> = StorageValue<_, u64, ValueQuery>;
+	/// The index and contents of the commitment leaf of each retained reported value (by query
+	/// identifier and timestamp).
+	#[pallet::storage]
+	pub(super) type CommitmentLeaves<T> = StorageDoubleMap<
+		_,
+		Identity,
+		QueryId,
+		Blake2_128Concat,
+		Timestamp,
+		(u64, commitments::Leaf),
+	>;
+	/// The nodes of the commitment range, by height and index.
+	#[pallet::storage]
+	pub(super) type CommitmentNodes<T> =
+		StorageDoubleMap<_, Twox64Concat, u8, Twox64Concat, u64, H256>;
+	/// The last commitment inspected for pruning, from which pruning of commitments resumes.
+	#[pallet::storage]
+	pub(super) type CommitPruningCursor<T> = StorageValue<_, (QueryId, AccountIdOf<T>)>;
+	/// Unrevealed commitments by query identifier and reporter.
 	#[pallet::storage]
-	pub(super) type LastReportedTimestamp<T> = StorageMap<_, Identity, QueryId, Timestamp>;
+	pub(super) type Commits<T> =
+		StorageDoubleMap<_, Identity, QueryId, Blake2_128Concat, AccountIdOf<T>, CommitOf<T>>;
+	/// Derived feeds registered by governance, by synthetic query identifier.
+	#[pallet::storage]
+	pub(super) type DerivedFeeds<T> = StorageMap<_, Identity, QueryId, DerivedFeed>;
+	/// Emergency values published by governance (by query identifier), stored separately from
+	/// reported values.
+	#[pallet::storage]
+	pub(super) type EmergencyValues<T> = StorageMap<_, Identity, QueryId, EmergencyValueOf<T>>;
+	/// Reported values awaiting finality (by query identifier, timestamp and the time at which their
+	/// dispute window elapses), queued by the length of their dispute window in the order reported.
+	#[pallet::storage]
+	pub(super) type FinalityQueue<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		Timestamp,
+		Twox64Concat,
+		u64,
+		(QueryId, Timestamp, Timestamp),
+	>;
+	/// The head and tail positions of each finality queue (by the length of its dispute window).
+	#[pallet::storage]
+	pub(super) type FinalityQueueRange<T> =
+		StorageMap<_, Twox64Concat, Timestamp, (u64, u64), ValueQuery>;
+	/// The index of the earliest reported timestamp (by query identifier) which has not been pruned.
+	#[pallet::storage]
+	pub(super) type FirstRetainedIndex<T> = StorageMap<_, Identity, QueryId, u32, ValueQuery>;
+	/// The last query identifier whose health was checked, from which health checks resume.
+	#[pallet::storage]
+	pub(super) type HealthCheckCursor<T> = StorageValue<_, QueryId>;
+	/// The index and timestamp of the last finalized value (by query identifier).
+	#[pallet::storage]
+	pub(super) type LastFinalized<T> = StorageMap<_, Identity, QueryId, (u32, Timestamp)>;
+	/// The index and timestamp of the last (non-disputed) reported value (by query identifier).
+	#[pallet::storage]
+	pub(super) type LastReported<T> = StorageMap<_, Identity, QueryId, (u32, Timestamp)>;
 	/// A timestamp at which the stake amount was last updated.
 	#[pallet::storage]
 	#[pallet::getter(fn last_stake_amount_update)]
 	pub(super) type LastStakeAmountUpdate<T> = StorageValue<_, Timestamp, ValueQuery>;
+	/// Query identifiers whose reported values reference values noted as preimages.
+	#[pallet::storage]
+	pub(super) type LargeValueQueries<T> = StorageMap<_, Identity, QueryId, ()>;
+	/// Whether reported values are being migrated to the current storage layout over multiple blocks.
+	#[pallet::storage]
+	pub(super) type MigrationInProgress<T> = StorageValue<_, bool, ValueQuery>;
+	/// The commitment to be deposited as a digest item when the current block is finalized.
+	#[pallet::storage]
+	pub(super) type PendingCommitment<T> = StorageValue<_, commitments::Commitment>;
+	/// Values noted as preimages, by hash.
+	#[pallet::storage]
+	pub(super) type PreimageValues<T> = StorageMap<_, Identity, PreimageHash, LargeValueOf<T>>;
+	/// The length and number of references of values noted as preimages, by hash.
+	#[pallet::storage]
+	pub(super) type Preimages<T> = StorageMap<_, Identity, PreimageHash, PreimageOf<T>>;
+	/// The last query identifier whose retention period was fully processed, from which pruning resumes.
+	#[pallet::storage]
+	pub(super) type PruningCursor<T> = StorageValue<_, QueryId>;
 	/// Mapping of reports by query identifier and timestamp.
 	#[pallet::storage]
 	pub(super) type Reports<T> =
 		StorageDoubleMap<_, Identity, QueryId, Blake2_128Concat, Timestamp, ReportOf<T>>;
-	/// Mapping of reported timestamps (by query identifier) to respective indices.
+	/// The health of query identifiers with health thresholds, as at their last health check.
+	#[pallet::storage]
+	pub(super) type QueryHealthStates<T> =
+		StorageMap<_, Identity, QueryId, QueryHealth, ValueQuery>;
+	/// The thresholds at which query identifiers are considered degraded or frozen.
 	#[pallet::storage]
-	pub(super) type ReportedTimestampsByIndex<T> =
-		StorageDoubleMap<_, Identity, QueryId, Blake2_128Concat, u32, Timestamp>;
+	pub(super) type QueryHealthThresholds<T> = StorageMap<_, Identity, QueryId, HealthThresholds>;
+	/// Mapping of query identifiers to their assigned risk class.
+	#[pallet::storage]
+	pub(super) type QueryRiskClass<T> = StorageMap<_, Identity, QueryId, RiskClassId>;
 	/// Mapping of reported timestamp count by query identifier.
 	#[pallet::storage]
 	pub(super) type ReportedTimestampCount<T> = StorageMap<_, Identity, QueryId, u32, ValueQuery>;
-	/// Mapping of reported timestamps (by query identifier) to values.
+	/// Mapping of reported value data by query identifier and index, which is removed when disputed.
+	#[pallet::storage]
+	pub(super) type ReportedValueData<T> =
+		StorageDoubleMap<_, Identity, QueryId, Twox64Concat, u32, ValueOf<T>>;
+	/// Mapping of the timestamps, reporters and dispute status of reported values by query
+	/// identifier and index, which excludes the values so that searches only read what they compare.
 	#[pallet::storage]
-	pub(super) type ReportedValuesByTimestamp<T> =
-		StorageDoubleMap<_, Identity, QueryId, Blake2_128Concat, Timestamp, ValueOf<T>>;
+	pub(super) type ReportedValues<T> =
+		StorageDoubleMap<_, Identity, QueryId, Twox64Concat, u32, IndexedValueOf<T>>;
+	/// The block number in which each reporter last consumed their reporting lock, used to
+	/// determine whether a retracted value is the reporter's last.
+	#[pallet::storage]
+	pub(super) type ReporterLastBlock<T> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, BlockNumberOf<T>>;
+	/// The amount of time reported values are retained (by query identifier) before being pruned.
+	#[pallet::storage]
+	pub(super) type RetentionPeriods<T> = StorageMap<_, Identity, QueryId, Timestamp>;
+	/// Mapping of reporter to number of values retracted.
+	#[pallet::storage]
+	pub(super) type RetractionsByReporter<T> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u32, ValueQuery>;
+	/// Reporter requirements by risk class identifier.
+	#[pallet::storage]
+	pub(super) type RiskClasses<T> = StorageMap<_, Identity, RiskClassId, RiskClass>;
 	/// Total staking rewards released per second.
 	#[pallet::storage]
 	#[pallet::getter(fn reward_rate)]
@@ -297,6 +537,9 @@ pub mod pallet {
 	/// Amount locked for withdrawal.
 	#[pallet::storage]
 	pub(super) type ToWithdraw<T> = StorageValue<_, Tributes, ValueQuery>;
+	/// The latest non-disputed values (oldest first), by query identifier with a value cache.
+	#[pallet::storage]
+	pub(super) type ValueCaches<T> = StorageMap<_, Identity, QueryId, CachedValuesOf<T>>;
 	// Governance
 	/// The latest dispute fee.
 	#[pallet::storage]
@@ -343,6 +586,46 @@ pub mod pallet {
 	// Query Data
 	#[pallet::storage]
 	pub(super) type QueryData<T> = StorageMap<_, Identity, QueryId, QueryDataOf<T>>;
+	// Requests
+	/// Pending data requests, by request identifier.
+	#[pallet::storage]
+	pub(super) type PendingRequests<T: Config> =
+		CountedStorageMap<_, Twox64Concat, RequestId, RequestOf<T>>;
+	/// Total number of data requests registered, which is the identifier of the next request.
+	#[pallet::storage]
+	pub(super) type RequestCount<T> = StorageValue<_, RequestId, ValueQuery>;
+	/// The identifier of the earliest data request which may still be pending, from which pending
+	/// requests are processed.
+	#[pallet::storage]
+	pub(super) type RequestQueueHead<T> = StorageValue<_, RequestId, ValueQuery>;
+	/// The identifier of the next data request to be processed, from which processing resumes until
+	/// the last request is reached and processing returns to the head of the queue.
+	#[pallet::storage]
+	pub(super) type RequestScanCursor<T> = StorageValue<_, RequestId>;
+	// Subscriptions
+	/// The last subscription whose value was processed, from which pushing resumes.
+	#[pallet::storage]
+	pub(super) type PushCursor<T> = StorageValue<_, SubscriptionId>;
+	/// Total number of values removed by dispute while subscriptions exist, by query identifier.
+	#[pallet::storage]
+	pub(super) type RemovalCount<T> = StorageMap<_, Blake2_128Concat, QueryId, u32, ValueQuery>;
+	/// Timestamps of values removed by dispute while subscriptions exist, by query identifier and
+	/// removal index, for delivery to subscribers.
+	#[pallet::storage]
+	pub(super) type Removals<T> =
+		StorageDoubleMap<_, Blake2_128Concat, QueryId, Twox64Concat, u32, Timestamp>;
+	/// Total number of subscriptions registered.
+	#[pallet::storage]
+	pub(super) type SubscriptionCount<T> = StorageValue<_, SubscriptionId, ValueQuery>;
+	/// Amounts held from the sovereign accounts of subscribing parachains, by subscription
+	/// identifier.
+	#[pallet::storage]
+	pub(super) type SubscriptionDeposits<T> =
+		StorageMap<_, Twox64Concat, SubscriptionId, BalanceOf<T>>;
+	/// Subscriptions of remote parachains, by subscription identifier.
+	#[pallet::storage]
+	pub(super) type Subscriptions<T> =
+		CountedStorageMap<_, Twox64Concat, SubscriptionId, Subscription>;
 
 	#[pallet::type_value]
 	pub fn InitialDisputeFee<T: Config>() -> BalanceOf<T> {
@@ -391,6 +674,16 @@ pub mod pallet {
 		},
 
 		// Oracle
+		/// Emitted when the commit-reveal mode of a query identifier is changed.
+		CommitRevealSet { query_id: QueryId, enabled: bool },
+		/// Emitted when a derived feed is registered or deregistered.
+		DerivedFeedSet { query_id: QueryId, feed: DerivedFeed, enabled: bool },
+		/// Emitted when an emergency value published by governance is cleared.
+		EmergencyValueCleared { query_id: QueryId },
+		/// Emitted when an emergency value is published by governance.
+		EmergencyValueSet { query_id: QueryId, value: ValueOf<T>, expires: Timestamp },
+		/// Emitted when large values are enabled or disabled for a query identifier.
+		LargeValuesSet { query_id: QueryId, enabled: bool },
 		/// Emitted when a new value is submitted.
 		NewReport {
 			query_id: QueryId,
@@ -402,6 +695,16 @@ pub mod pallet {
 		},
 		/// Emitted when the stake amount has changed.
 		NewStakeAmount { amount: Tributes },
+		/// Emitted when the health of a query identifier changes.
+		QueryHealthChanged { query_id: QueryId, health: QueryHealth },
+		/// Emitted when the health thresholds of a query identifier are changed.
+		QueryHealthThresholdsSet { query_id: QueryId, thresholds: Option<HealthThresholds> },
+		/// Emitted when the risk class of a query identifier is changed.
+		QueryRiskClassSet { query_id: QueryId, class_id: Option<RiskClassId> },
+		/// Emitted when the retention period of a query identifier is changed.
+		RetentionPeriodSet { query_id: QueryId, retention_period: Option<Timestamp> },
+		/// Emitted when a risk class is changed.
+		RiskClassSet { class_id: RiskClassId, risk_class: Option<RiskClass> },
 		/// Emitted when a new staker is reported.
 		NewStakerReported { staker: AccountIdOf<T>, amount: Tributes, address: Address },
 		/// Emitted when a stake slash is reported.
@@ -416,8 +719,32 @@ pub mod pallet {
 		},
 		/// Emitted when staking rewards are added.
 		StakingRewardsAdded { source: AccountIdOf<T>, amount: BalanceOf<T> },
+		/// Emitted when an entry of a batch submission is rejected.
+		SubmissionFailed {
+			reporter: AccountIdOf<T>,
+			index: u32,
+			query_id: QueryId,
+			error: DispatchError,
+		},
+		/// Emitted when the value cache of a query identifier is enabled or disabled.
+		ValueCacheSet { query_id: QueryId, enabled: bool },
+		/// Emitted when a value is committed, to be revealed later.
+		ValueCommitted { query_id: QueryId, reporter: AccountIdOf<T>, hash: CommitHash },
+		/// Emitted when the dispute window of a value has elapsed without dispute.
+		ValueFinalized { query_id: QueryId, timestamp: Timestamp },
+		/// Emitted when a value is noted as a preimage, to be referenced by reported values.
+		ValueNoted { hash: PreimageHash, reporter: AccountIdOf<T> },
 		/// Emitted when a value is removed (via governance).
 		ValueRemoved { query_id: QueryId, timestamp: Timestamp },
+		/// Emitted when a value is retracted by its reporter.
+		ValueRetracted {
+			query_id: QueryId,
+			timestamp: Timestamp,
+			reporter: AccountIdOf<T>,
+			penalty: BalanceOf<T>,
+		},
+		/// Emitted when a value noted as a preimage is removed by the reporter which noted it.
+		ValueUnnoted { hash: PreimageHash, reporter: AccountIdOf<T> },
 
 		// Governance
 		/// Emitted when a new dispute is opened.
@@ -452,6 +779,52 @@ pub mod pallet {
 		// Registration
 		/// Emitted when registration is sent to the controller contracts.
 		RegistrationSent { para_id: u32, contract_address: Address, weights: Weights },
+
+		// Requests
+		/// Emitted when data is requested.
+		DataRequested {
+			request_id: RequestId,
+			query_id: QueryId,
+			requester: AccountIdOf<T>,
+			callback: CallbackId,
+		},
+		/// Emitted when a value is delivered in response to a data request.
+		RequestFulfilled { request_id: RequestId, query_id: QueryId, timestamp: Timestamp },
+		/// Emitted when a data request times out before an eligible value is found.
+		RequestTimedOut { request_id: RequestId, query_id: QueryId },
+
+		// Subscriptions
+		/// Emitted when a subscription is cancelled.
+		SubscriptionCancelled { subscription_id: SubscriptionId },
+		/// Emitted when a remote parachain subscribes to the values of a query identifier.
+		SubscriptionRegistered { subscription_id: SubscriptionId, para_id: u32, query_id: QueryId },
+		/// Emitted when pushing a value to a subscriber fails, after which it is retried up to a
+		/// maximum number of attempts.
+		ValuePushFailed {
+			subscription_id: SubscriptionId,
+			query_id: QueryId,
+			timestamp: Timestamp,
+			attempts: u8,
+		},
+		/// Emitted when a value is pushed to a subscriber.
+		ValuePushed {
+			subscription_id: SubscriptionId,
+			para_id: u32,
+			query_id: QueryId,
+			timestamp: Timestamp,
+		},
+		/// Emitted when the removal of a disputed value is pushed to a subscriber.
+		ValueRemovalPushed {
+			subscription_id: SubscriptionId,
+			para_id: u32,
+			query_id: QueryId,
+			timestamp: Timestamp,
+		},
+
+		// Remote Queries
+		/// Emitted when an oracle query from a remote parachain is answered, with the timestamp of
+		/// the value replied, if any.
+		RemoteQueryAnswered { para_id: u32, query_id: QueryId, timestamp: Option<Timestamp> },
 	}
 
 	#[pallet::error]
@@ -498,25 +871,68 @@ pub mod pallet {
 		ValueDisputed,
 
 		// Oracle
+		/// Commit-reveal is not enabled for the query identifier.
+		CommitRevealNotEnabled,
+		/// Values for the query identifier must be committed before being revealed.
+		CommitRevealRequired,
+		/// Values cannot be reported for the synthetic query identifier of a derived feed.
+		DerivedFeedQuery,
 		InvalidAddress,
 		/// Balance must be greater than stake amount.
 		InsufficientStake,
+		/// Derived feed operands must be distinct, reported query identifiers.
+		InvalidDerivedFeed,
+		/// Emergency values must expire within the maximum emergency value duration.
+		InvalidEmergencyValueDuration,
+		/// Minimum reporters of health thresholds cannot exceed the number of values inspected.
+		InvalidHealthThresholds,
 		/// Nonce must match the timestamp index.
 		InvalidNonce,
 		/// Invalid token price.
 		InvalidPrice,
+		/// Revealed value and salt do not match the commitment.
+		InvalidReveal,
+		/// Retention period must be at least the minimum retention period.
+		InvalidRetentionPeriod,
+		/// Risk class must exist, with a non-zero reporting lock and stake multiple.
+		InvalidRiskClass,
 		/// Invalid staking token price.
 		InvalidStakingTokenPrice,
 		/// Value must be submitted.
 		InvalidValue,
 		/// The maximum sequential disputed timestamps has been reached.
 		MaxDisputedTimeSeriesReached,
+		/// No commitment exists for the reporter.
+		NoCommit,
 		/// Reporter not locked for withdrawal.
 		NoWithdrawalRequested,
+		/// The value was not noted as a preimage by the caller.
+		NotPreimageDepositor,
+		/// The value was not reported by the caller.
+		NotValueReporter,
+		/// The signed payload is no longer valid.
+		PayloadExpired,
+		/// The value has already been noted as a preimage.
+		PreimageAlreadyNoted,
+		/// The referenced value has not been noted as a preimage.
+		PreimageNotNoted,
+		/// The value noted as a preimage is still referenced by reported values.
+		PreimageReferenced,
 		/// Still in reporter time lock, please wait!
 		ReporterTimeLocked,
+		/// The timestamp of the report would exceed the maximum report timestamp offset, as too
+		/// many values have been reported for the query identifier within the same second.
+		ReportTimestampOffsetExceeded,
+		/// The retraction period of the value has passed.
+		RetractionPeriodElapsed,
+		/// A commitment must be revealed in a later block.
+		RevealTooEarly,
+		/// The reveal window of the commitment has passed.
+		RevealWindowExpired,
 		/// Timestamp already reported.
 		TimestampAlreadyReported,
+		/// Values have already been reported for the query identifier.
+		ValuesAlreadyReported,
 		/// Withdrawal period didn't pass.
 		WithdrawalPeriodPending,
 
@@ -550,6 +966,24 @@ pub mod pallet {
 		/// Time for voting has not elapsed.
 		VotingPeriodActive,
 
+		// Requests
+		/// The maximum number of pending requests has been reached.
+		MaxPendingRequestsReached,
+		/// The earliest timestamp or dispute buffer of the request exceeds the maximum request delay.
+		RequestDelayExceeded,
+
+		// Subscriptions
+		/// The subscriber is not a sibling parachain.
+		InvalidSubscriber,
+		/// The maximum number of subscriptions has been reached.
+		MaxSubscriptionsReached,
+		/// The subscription does not exist or belongs to another subscriber.
+		UnknownSubscription,
+
+		// Remote Queries
+		/// The querier is not a sibling parachain.
+		InvalidQuerier,
+
 		// XCM
 		FeesNotMet,
 		JunctionOverflow,
@@ -574,6 +1008,23 @@ pub mod pallet {
 		fn on_initialize(_n: T::BlockNumber) -> Weight {
 			let timestamp = Self::now();
 
+			// Continue any multi-block migration of reported values
+			let m = if <MigrationInProgress<T>>::get() {
+				migrations::v1::migrate::<T>(MAX_MIGRATED_VALUES_PER_BLOCK)
+			} else {
+				0
+			};
+
+			// Finalize any values whose dispute window has elapsed without dispute
+			let f = Self::finalize_values(timestamp, MAX_FINALIZED_VALUES_PER_BLOCK);
+
+			// Check the health of query identifiers with health thresholds
+			let h = Self::check_health(timestamp, MAX_HEALTH_CHECKS_PER_BLOCK);
+
+			// Deliver any pending data requests, with the remainder processed using idle weight
+			let r =
+				Self::process_requests(timestamp, Weight::MAX, MAX_REQUESTS_PROCESSED_PER_BLOCK);
+
 			// update stake amount/dispute fee
 			let interval = T::UpdateStakeAmountInterval::get();
 			let (s, l) = if interval > Zero::zero()
@@ -596,6 +1047,62 @@ pub mod pallet {
 				.unwrap_or_default();
 
 			<T as Config>::WeightInfo::on_initialize(s, l, v)
+				.saturating_add(<T as Config>::WeightInfo::migrate_values(m))
+				.saturating_add(<T as Config>::WeightInfo::finalize_values(f))
+				.saturating_add(<T as Config>::WeightInfo::check_health(h))
+				.saturating_add(r)
+				// Account for depositing any pending value commitment when finalized
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		}
+
+		fn on_finalize(_n: T::BlockNumber) {
+			// Deposit a commitment to any values reported or removed within the block
+			if let Some(commitment) = <PendingCommitment<T>>::take() {
+				<frame_system::Pallet<T>>::deposit_log(commitment.to_digest_item());
+			}
+		}
+
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			// Deliver any pending data requests using the remaining weight of the block
+			let timestamp = Self::now();
+			let mut consumed = Self::process_requests(timestamp, remaining_weight, u32::MAX);
+			// Push any new values to subscribers using the weight left over
+			consumed.saturating_accrue(Self::process_subscriptions(
+				timestamp,
+				remaining_weight.saturating_sub(consumed),
+			));
+			// Prune any values outside of their retention period using the weight left over
+			consumed.saturating_accrue(Self::prune_values(
+				timestamp,
+				remaining_weight.saturating_sub(consumed),
+			));
+			// Prune any commitments which can no longer be revealed using the weight left over
+			consumed.saturating_add(Self::prune_commits(
+				timestamp,
+				remaining_weight.saturating_sub(consumed),
+			))
+		}
+
+		#[cfg(feature = "offchain-worker")]
+		fn offchain_worker(_n: T::BlockNumber) {
+			// Report a value for any configured query identifiers using the local reporter key
+			if let Err(e) = Self::offchain_report() {
+				log::debug!(target: "runtime::tellor", "offchain worker failed to report: {}", e);
+			}
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			match call {
+				Call::submit_value_unsigned { payload, query_data, signature } => {
+					Self::validate_report_payload(payload, query_data, signature)
+				},
+				_ => InvalidTransaction::Call.into(),
+			}
 		}
 	}
 
@@ -622,8 +1129,11 @@ pub mod pallet {
 				report_staking_withdraw_request: T::WeightInfo::report_staking_withdraw_request()
 					.ref_time(),
 				report_stake_withdrawn: T::WeightInfo::report_stake_withdrawn().ref_time(),
-				report_vote_tallied: T::WeightInfo::report_vote_tallied().ref_time(),
+				report_vote_tallied: T::WeightInfo::report_vote_tallied()
+					.saturating_add(T::OnValueRemoved::weight())
+					.ref_time(),
 				report_vote_executed: T::WeightInfo::report_vote_executed(MAX_VOTE_ROUNDS.into())
+					.saturating_add(T::OnValueRemoved::weight())
 					.ref_time(),
 				report_slash: T::WeightInfo::report_slash().ref_time(),
 			};
@@ -758,6 +1268,9 @@ pub mod pallet {
 					cumulative_reward = balance;
 					// Adjust currently funded feeds
 					<FeedsWithFunding<T>>::remove(feed_id);
+					<FundedFeedIntervals<T>>::mutate(query_id, |intervals| {
+						intervals.retain(|(_, id)| id != &feed_id)
+					});
 				}
 				<DataFeedRewardClaimed<T>>::set((query_id, feed_id, timestamp.0), ());
 			}
@@ -883,72 +1396,7 @@ pub mod pallet {
 			query_data: QueryDataOf<T>,
 		) -> DispatchResult {
 			let tipper = ensure_signed(origin)?;
-			ensure!(query_id == Keccak256::hash(query_data.as_ref()), Error::<T>::InvalidQueryId);
-			ensure!(amount > Zero::zero(), Error::<T>::InvalidAmount);
-
-			let tip_count = <TipCount<T>>::get(query_id);
-			if tip_count == 0 {
-				<Tips<T>>::insert(
-					query_id,
-					tip_count,
-					TipOf::<T> {
-						amount,
-						timestamp: Self::now()
-							.checked_add(1u8.into())
-							.ok_or(ArithmeticError::Overflow)?,
-						cumulative_tips: amount,
-					},
-				);
-				<TipCount<T>>::mutate(query_id, |count| count.saturating_inc());
-				Self::store_data(query_id, &query_data);
-			} else {
-				let last_reported_timestamp =
-					<LastReportedTimestamp<T>>::get(query_id).unwrap_or_default();
-				let last_tip = <Tips<T>>::get(
-					query_id,
-					tip_count.checked_sub(1).expect("tip_count is always greater than zero; qed"),
-				);
-				match last_tip {
-					Some(mut last_tip) if last_reported_timestamp < last_tip.timestamp => {
-						last_tip.timestamp =
-							Self::now().checked_add(1u8.into()).ok_or(ArithmeticError::Overflow)?;
-						last_tip.amount.saturating_accrue(amount);
-						last_tip.cumulative_tips.saturating_accrue(amount);
-						<Tips<T>>::set(
-							query_id,
-							tip_count
-								.checked_sub(1)
-								.expect("tip_count is always greater than zero; qed"),
-							Some(last_tip),
-						);
-					},
-					_ => {
-						let cumulative_tips = last_tip.map_or(Zero::zero(), |t| t.cumulative_tips);
-						<Tips<T>>::insert(
-							query_id,
-							tip_count,
-							Tip {
-								amount,
-								timestamp: Self::now()
-									.checked_add(1u8.into())
-									.ok_or(ArithmeticError::Overflow)?,
-								cumulative_tips: cumulative_tips
-									.checked_add(&amount)
-									.ok_or(ArithmeticError::Overflow)?,
-							},
-						);
-						<TipCount<T>>::mutate(query_id, |count| count.saturating_inc());
-					},
-				}
-			}
-
-			if Self::get_current_tip(query_id) > Zero::zero() {
-				<QueryIdsWithFunding<T>>::insert(query_id, ());
-			}
-			T::Asset::transfer(&tipper, &Self::tips(), amount, Preservation::Expendable)?;
-			<UserTipsTotal<T>>::mutate(&tipper, |total| total.saturating_accrue(amount));
-			Self::deposit_event(Event::TipAdded { query_id, amount, query_data, tipper });
-			Ok(())
+			Self::do_tip(tipper, query_id, amount, query_data)
 		}
 
 		/// Funds the staking account with staking rewards.
@@ -968,113 +1416,42 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Allows a reporter to submit a value to the oracle.
+		/// Allows a reporter to submit a value to the oracle. Successful submissions are exempt from
+		/// fees when `FeelessSubmissions` is enabled.
 		///
 		/// - `query_id`: Identifier of the specific data feed.
 		/// - `value`: Value the user submits to the oracle.
 		/// - `nonce`: The current value count for the query identifier.
 		/// - `query_data`: The data used to fulfil the data query.
 		#[pallet::call_index(7)]
-		#[pallet::weight(<T as Config>::WeightInfo::submit_value(query_data.len() as u32, value.len() as u32))]
+		#[pallet::weight(<T as Config>::WeightInfo::submit_value(query_data.len() as u32, value.len() as u32)
+			.saturating_add(T::OnNewReport::weight()))]
 		pub fn submit_value(
 			origin: OriginFor<T>,
 			query_id: QueryId,
 			value: ValueOf<T>,
 			#[pallet::compact] nonce: Nonce,
 			query_data: QueryDataOf<T>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let reporter = ensure_signed(origin)?;
-			ensure!(!value.is_empty(), Error::<T>::InvalidValue);
-			ensure!(
-				nonce == <ReportedTimestampCount<T>>::get(query_id) || nonce == 0,
-				Error::<T>::InvalidNonce
-			);
-			let mut staker =
-				<StakerDetails<T>>::get(&reporter).ok_or(Error::<T>::InsufficientStake)?;
-			ensure!(
-				staker.staked_balance >= <StakeAmount<T>>::get(),
-				Error::<T>::InsufficientStake
-			);
+			Self::ensure_valid_submission(query_id, &value, nonce, &query_data)?;
 			// Require reporter to abide by given reporting lock
 			let timestamp = Self::now();
-			ensure!(
-				U256::from(
-					timestamp
-						.checked_sub(staker.reporter_last_timestamp)
-						.ok_or(ArithmeticError::Underflow)?
-				)
-				.checked_mul(1_000.into())
-				.ok_or(ArithmeticError::Overflow)?
-					> (U256::from(REPORTING_LOCK)
-						.checked_mul(1_000.into())
-						.ok_or(ArithmeticError::Overflow)?)
-					.checked_div(
-						staker
-							.staked_balance
-							.checked_div(<StakeAmount<T>>::get())
-							.ok_or(ArithmeticError::DivisionByZero)?
-					)
-					.ok_or(ArithmeticError::DivisionByZero)?,
-				Error::<T>::ReporterTimeLocked
-			);
-			ensure!(query_id == Keccak256::hash(query_data.as_ref()), Error::<T>::InvalidQueryId);
+			let mut staker =
+				Self::ensure_can_report(&reporter, Self::get_risk_class(query_id), timestamp)?;
 			staker.reporter_last_timestamp = timestamp;
-			// Checks for no double reporting of timestamps
-			ensure!(
-				!<Reports<T>>::contains_key(query_id, timestamp),
-				Error::<T>::TimestampAlreadyReported
-			);
-
-			// Update number of timestamps, value for given timestamp, and reporter for timestamp
-			let index = <ReportedTimestampCount<T>>::mutate(query_id, |count| {
-				let index = *count;
-				count.saturating_inc();
-				index
-			});
-			<ReportedTimestampsByIndex<T>>::insert(query_id, index, timestamp);
-			<Reports<T>>::insert(
-				query_id,
-				timestamp,
-				ReportOf::<T> {
-					index,
-					block_number: frame_system::Pallet::<T>::block_number(),
-					reporter: reporter.clone(),
-					is_disputed: false,
-					previous: <LastReportedTimestamp<T>>::get(query_id),
-				},
-			);
-			<LastReportedTimestamp<T>>::insert(query_id, timestamp);
-			<ReportedValuesByTimestamp<T>>::insert(query_id, timestamp, &value);
-
-			// backlog: Disperse Time Based Reward
-			// uint256 _reward = ((block.timestamp - timeOfLastNewValue) * timeBasedReward) / 300; //.5 TRB per 5 minutes
-			// uint256 _totalTimeBasedRewardsBalance =
-			// 	token.balanceOf(address(this)) -
-			// 		(totalStakeAmount + stakingRewardsBalance + toWithdraw);
-			// if (_totalTimeBasedRewardsBalance > 0 && _reward > 0) {
-			// 	if (_totalTimeBasedRewardsBalance < _reward) {
-			// 		token.transfer(msg.sender, _totalTimeBasedRewardsBalance);
-			// 	} else {
-			// 		token.transfer(msg.sender, _reward);
-			// 	}
-			// }
-
-			// Update last oracle value and number of values submitted by a reporter
-			<TimeOfLastNewValue<T>>::set(Some(timestamp));
-			staker.reports_submitted.saturating_inc();
-			<StakerReportsSubmittedByQueryId<T>>::mutate(&reporter, query_id, |reports| {
-				reports.saturating_inc();
-			});
-			<StakerDetails<T>>::insert(&reporter, staker);
-			Self::deposit_event(Event::NewReport {
+			let weight = T::WeightInfo::submit_value(query_data.len() as u32, value.len() as u32);
+			let hook_weight = Self::store_report(
 				query_id,
-				time: timestamp,
 				value,
 				nonce,
 				query_data,
 				reporter,
-			});
-			Ok(())
+				&mut staker,
+				timestamp,
+			)?;
+			let pays_fee = if T::FeelessSubmissions::get() { Pays::No } else { Pays::Yes };
+			Ok((Some(weight.saturating_add(hook_weight)), pays_fee).into())
 		}
 
 		/// Updates the stake amount after retrieving the latest token price from oracle.
@@ -1093,7 +1470,8 @@ pub mod pallet {
 		/// - `timestamp`: Timestamp being disputed.
 		/// - 'beneficiary`: address on controller chain to potentially receive the slash amount if dispute successful
 		#[pallet::call_index(9)]
-		#[pallet::weight(<T as Config>::WeightInfo::begin_dispute(T::MaxDisputedTimeSeries::get()))]
+		#[pallet::weight(<T as Config>::WeightInfo::begin_dispute(T::MaxDisputedTimeSeries::get())
+			.saturating_add(T::OnValueRemoved::weight()))]
 		pub fn begin_dispute(
 			origin: OriginFor<T>,
 			query_id: QueryId,
@@ -1108,15 +1486,9 @@ pub mod pallet {
 				.ok_or(Error::<T>::NotReporter.with_weight(T::WeightInfo::begin_dispute(0)))?;
 
 			// Ensure value actually exists
-			ensure!(
-				<Reports<T>>::contains_key(query_id, timestamp),
-				Error::<T>::NoValueExists.with_weight(T::WeightInfo::begin_dispute(0))
-			);
-			let dispute_id: DisputeId = Keccak256::hash(&contracts::encode(&[
-				Abi::Uint(T::ParachainId::get().into()),
-				Abi::FixedBytes(query_id.0.into()),
-				Abi::Uint(timestamp.into()),
-			]));
+			let report = <Reports<T>>::get(query_id, timestamp)
+				.ok_or(Error::<T>::NoValueExists.with_weight(T::WeightInfo::begin_dispute(0)))?;
+			let dispute_id = Self::dispute_id(query_id, timestamp);
 			// Push new vote round
 			let vote_round = <VoteRounds<T>>::try_mutate(
 				dispute_id,
@@ -1146,10 +1518,17 @@ pub mod pallet {
 				result: None,
 				initiator: dispute_initiator.clone(),
 			};
-			let (dispute, disputed_timestamps) = if vote_round == 1 {
+			let (dispute, (disputed_timestamps, hook_weight)) = if vote_round == 1 {
+				// Reports may be slightly ahead of the current time, when submitted within the same second
 				ensure!(
-					Self::now().checked_sub(timestamp).ok_or(ArithmeticError::Underflow)?
-						< REPORTING_LOCK,
+					Self::now().saturating_sub(timestamp) < Self::get_reporting_lock(query_id),
+					Error::<T>::DisputeReportingPeriodExpired
+						.with_weight(T::WeightInfo::begin_dispute(0))
+				);
+				// Finalized values remain final, should the reporting lock have since been extended
+				ensure!(
+					<LastFinalized<T>>::get(query_id)
+						.map_or(true, |(_, finalized)| timestamp > finalized),
 					Error::<T>::DisputeReportingPeriodExpired
 						.with_weight(T::WeightInfo::begin_dispute(0))
 				);
@@ -1177,13 +1556,15 @@ pub mod pallet {
 				let dispute = DisputeOf::<T> {
 					query_id,
 					timestamp,
-					value: Self::retrieve_data(query_id, timestamp).ok_or(
-						Error::<T>::InvalidTimestamp.with_weight(T::WeightInfo::begin_dispute(0)),
-					)?,
-					disputed_reporter: Self::get_reporter_by_timestamp(query_id, timestamp).ok_or(
-						Error::<T>::NoValueExists.with_weight(T::WeightInfo::begin_dispute(0)),
-					)?,
-					slashed_amount: <StakeAmount<T>>::get(),
+					value: Self::reported_value(query_id, report.index)
+						.filter(|_| !report.is_disputed)
+						.ok_or(
+							Error::<T>::InvalidTimestamp
+								.with_weight(T::WeightInfo::begin_dispute(0)),
+						)?,
+					disputed_reporter: report.reporter,
+					slashed_amount: Self::get_stake_requirement(query_id)
+						.map_err(|e| e.with_weight(T::WeightInfo::begin_dispute(0)))?,
 				};
 				<DisputeIdsByReporter<T>>::insert(&dispute.disputed_reporter, dispute_id, ());
 				<DisputeInfo<T>>::insert(dispute_id, &dispute);
@@ -1208,7 +1589,10 @@ pub mod pallet {
 				vote.fee = vote.fee.saturating_mul(<BalanceOf<T>>::from(2u8).saturating_pow(
 					vote_round.checked_sub(1).expect("vote round checked above; qed").into(),
 				));
-				(<DisputeInfo<T>>::get(dispute_id).ok_or(Error::<T>::InvalidDispute)?, 0)
+				(
+					<DisputeInfo<T>>::get(dispute_id).ok_or(Error::<T>::InvalidDispute)?,
+					(0, Weight::zero()),
+				)
 			};
 			let stake_amount = <DisputeFee<T>>::get().saturating_mul(10u8.into());
 			if vote.fee > stake_amount {
@@ -1266,7 +1650,8 @@ pub mod pallet {
 					contract_address: governance_contract.address.into(),
 				},
 			)?;
-			Ok(Some(T::WeightInfo::begin_dispute(disputed_timestamps)).into())
+			Ok(Some(T::WeightInfo::begin_dispute(disputed_timestamps).saturating_add(hook_weight))
+				.into())
 		}
 
 		/// Enables the caller to cast a vote.
@@ -1554,23 +1939,26 @@ pub mod pallet {
 		/// - `dispute_id`: The identifier of the dispute.
 		/// - `result`: The outcome of the vote, as determined by governance.
 		#[pallet::call_index(17)]
-		#[pallet::weight(<T as Config>::WeightInfo::report_vote_tallied())]
+		#[pallet::weight(<T as Config>::WeightInfo::report_vote_tallied()
+			.saturating_add(T::OnValueRemoved::weight()))]
 		pub fn report_vote_tallied(
 			origin: OriginFor<T>,
 			dispute_id: DisputeId,
 			result: VoteResult,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			// ensure origin is governance controller contract
 			T::GovernanceOrigin::ensure_origin(origin)?;
-			// tally votes
-			Self::tally_votes(dispute_id, result)
+			// tally votes & return consumed weight info
+			let hook_weight = Self::tally_votes(dispute_id, result)?;
+			Ok(Some(T::WeightInfo::report_vote_tallied().saturating_add(hook_weight)).into())
 		}
 
 		/// Reports the execution of a vote.
 		///
 		/// - `dispute_id`: The identifier of the dispute.
 		#[pallet::call_index(18)]
-		#[pallet::weight(<T as Config>::WeightInfo::report_vote_executed(MAX_VOTE_ROUNDS.into()))]
+		#[pallet::weight(<T as Config>::WeightInfo::report_vote_executed(MAX_VOTE_ROUNDS.into())
+			.saturating_add(T::OnValueRemoved::weight()))]
 		pub fn report_vote_executed(
 			origin: OriginFor<T>,
 			dispute_id: DisputeId,
@@ -1578,8 +1966,752 @@ pub mod pallet {
 			// ensure origin is governance controller contract
 			T::GovernanceOrigin::ensure_origin(origin)?;
 			// execute vote & return consumed weight info
-			Ok(Some(T::WeightInfo::report_vote_executed(Self::execute_vote(dispute_id)? as u32))
-				.into())
+			let (vote_rounds, hook_weight) = Self::execute_vote(dispute_id)?;
+			Ok(Some(
+				T::WeightInfo::report_vote_executed(vote_rounds as u32).saturating_add(hook_weight),
+			)
+			.into())
+		}
+
+		/// Enables or disables commit-reveal submission for a query identifier. Unrevealed commitments
+		/// of a query identifier for which commit-reveal is disabled can no longer be revealed, and
+		/// are pruned when the block has weight remaining.
+		///
+		/// - `query_id`: Identifier of the specific data feed.
+		/// - `enabled`: Whether values must be committed before being revealed.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_commit_reveal())]
+		pub fn set_commit_reveal(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			enabled: bool,
+		) -> DispatchResult {
+			// ensure origin is governance controller contract
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			if enabled {
+				<CommitRevealQueries<T>>::insert(query_id, ());
+			} else {
+				<CommitRevealQueries<T>>::remove(query_id);
+			}
+			Self::deposit_event(Event::CommitRevealSet { query_id, enabled });
+			Ok(())
+		}
+
+		/// Allows a reporter to commit to a value for a query identifier using commit-reveal, to
+		/// be revealed in a later block within the reveal window. The reporting lock applies at
+		/// the time of the commitment.
+		///
+		/// - `query_id`: Identifier of the specific data feed.
+		/// - `hash`: The keccak256 hash of the SCALE-encoded reporter, query identifier, value and
+		///   secret salt, binding the commitment to the reporter so that it cannot be copied.
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as Config>::WeightInfo::commit_value())]
+		pub fn commit_value(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			hash: CommitHash,
+		) -> DispatchResult {
+			let reporter = ensure_signed(origin)?;
+			ensure!(
+				<CommitRevealQueries<T>>::contains_key(query_id),
+				Error::<T>::CommitRevealNotEnabled
+			);
+			// Require reporter to abide by given reporting lock
+			let timestamp = Self::now();
+			let mut staker =
+				Self::ensure_can_report(&reporter, Self::get_risk_class(query_id), timestamp)?;
+			staker.reporter_last_timestamp = timestamp;
+			<StakerDetails<T>>::insert(&reporter, staker);
+			<ReporterLastBlock<T>>::insert(&reporter, frame_system::Pallet::<T>::block_number());
+			<Commits<T>>::insert(
+				query_id,
+				&reporter,
+				CommitOf::<T> {
+					hash,
+					timestamp,
+					block_number: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+			Self::deposit_event(Event::ValueCommitted { query_id, reporter, hash });
+			Ok(())
+		}
+
+		/// Allows a reporter to reveal a previously committed value, submitting it to the oracle.
+		///
+		/// - `query_id`: Identifier of the specific data feed.
+		/// - `value`: Value the user submits to the oracle.
+		/// - `salt`: The secret salt used when committing to the value.
+		/// - `nonce`: The current value count for the query identifier.
+		/// - `query_data`: The data used to fulfil the data query.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::reveal_value(query_data.len() as u32, value.len() as u32)
+			.saturating_add(T::OnNewReport::weight()))]
+		pub fn reveal_value(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			value: ValueOf<T>,
+			salt: H256,
+			#[pallet::compact] nonce: Nonce,
+			query_data: QueryDataOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let reporter = ensure_signed(origin)?;
+			ensure!(!value.is_empty(), Error::<T>::InvalidValue);
+			ensure!(
+				nonce == <ReportedTimestampCount<T>>::get(query_id) || nonce == 0,
+				Error::<T>::InvalidNonce
+			);
+			ensure!(
+				<CommitRevealQueries<T>>::contains_key(query_id),
+				Error::<T>::CommitRevealNotEnabled
+			);
+			let commit = <Commits<T>>::get(query_id, &reporter).ok_or(Error::<T>::NoCommit)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > commit.block_number,
+				Error::<T>::RevealTooEarly
+			);
+			let timestamp = Self::now();
+			ensure!(
+				timestamp <= commit.timestamp.saturating_add(T::RevealWindow::get()),
+				Error::<T>::RevealWindowExpired
+			);
+			ensure!(
+				commit.hash == Self::commitment(&reporter, query_id, &value, salt),
+				Error::<T>::InvalidReveal
+			);
+			ensure!(query_id == Keccak256::hash(query_data.as_ref()), Error::<T>::InvalidQueryId);
+			// Reporting lock already applied at commitment, so only require reporter to still be staked
+			let mut staker =
+				<StakerDetails<T>>::get(&reporter).ok_or(Error::<T>::InsufficientStake)?;
+			ensure!(
+				staker.staked_balance >= Self::get_stake_requirement(query_id)?,
+				Error::<T>::InsufficientStake
+			);
+			<Commits<T>>::remove(query_id, &reporter);
+			let weight = T::WeightInfo::reveal_value(query_data.len() as u32, value.len() as u32);
+			let hook_weight = Self::store_report(
+				query_id,
+				value,
+				nonce,
+				query_data,
+				reporter,
+				&mut staker,
+				timestamp,
+			)?;
+			Ok(Some(weight.saturating_add(hook_weight)).into())
+		}
+
+		/// Allows a reporter to submit values for multiple query identifiers at once.
+		///
+		/// The reporting lock is applied once for the whole batch, according to the reporter's
+		/// stake multiple and the strictest risk class of the submitted query identifiers, and all
+		/// accepted values share the same timestamp. Each entry is then
+		/// validated independently: an invalid entry is skipped with a `SubmissionFailed` event,
+		/// whilst each accepted value emits a `NewReport` event. The reporting lock is only
+		/// consumed if at least one value is accepted.
+		///
+		/// - `submissions`: The query identifier, value, nonce and query data of each submission.
+		#[pallet::call_index(22)]
+		#[pallet::weight({
+			let (q, v) = Pallet::<T>::submission_lengths(submissions);
+			<T as Config>::WeightInfo::submit_values(submissions.len() as u32, q, v)
+				.saturating_add(T::OnNewReport::weight().saturating_mul(submissions.len() as u64))
+		})]
+		pub fn submit_values(
+			origin: OriginFor<T>,
+			submissions: BoundedVec<SubmissionOf<T>, T::MaxSubmissionsPerBatch>,
+		) -> DispatchResultWithPostInfo {
+			let reporter = ensure_signed(origin)?;
+			ensure!(!submissions.is_empty(), Error::<T>::InvalidValue);
+			// Require reporter to abide by given reporting lock, once for the batch
+			let timestamp = Self::now();
+			let risk_class =
+				submissions.iter().map(|(query_id, ..)| Self::get_risk_class(*query_id)).fold(
+					RiskClass { reporting_lock: 0, stake_multiple: 0 },
+					|strictest, risk_class| RiskClass {
+						reporting_lock: strictest.reporting_lock.max(risk_class.reporting_lock),
+						stake_multiple: strictest.stake_multiple.max(risk_class.stake_multiple),
+					},
+				);
+			let mut staker = Self::ensure_can_report(&reporter, risk_class, timestamp)?;
+			staker.reporter_last_timestamp = timestamp;
+			let (q, v) = Self::submission_lengths(&submissions);
+			let mut weight = T::WeightInfo::submit_values(submissions.len() as u32, q, v);
+			for (index, (query_id, value, nonce, query_data)) in submissions.into_iter().enumerate()
+			{
+				match Self::ensure_valid_submission(query_id, &value, nonce, &query_data).and_then(
+					|_| {
+						Self::store_report(
+							query_id,
+							value,
+							nonce,
+							query_data,
+							reporter.clone(),
+							&mut staker,
+							timestamp,
+						)
+					},
+				) {
+					Ok(hook_weight) => weight.saturating_accrue(hook_weight),
+					Err(error) => Self::deposit_event(Event::SubmissionFailed {
+						reporter: reporter.clone(),
+						index: index as u32,
+						query_id,
+						error,
+					}),
+				}
+			}
+			Ok(Some(weight).into())
+		}
+
+		/// Sets the reporter requirements of a risk class. Query identifiers assigned to a removed
+		/// class revert to the default requirements.
+		///
+		/// - `class_id`: The identifier of the risk class.
+		/// - `risk_class`: The reporter requirements of the class, or `None` to remove the class.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_risk_class())]
+		pub fn set_risk_class(
+			origin: OriginFor<T>,
+			class_id: RiskClassId,
+			risk_class: Option<RiskClass>,
+		) -> DispatchResult {
+			// ensure origin is governance controller contract
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			match risk_class {
+				Some(risk_class) => {
+					ensure!(
+						risk_class.reporting_lock > 0 && risk_class.stake_multiple > 0,
+						Error::<T>::InvalidRiskClass
+					);
+					<RiskClasses<T>>::insert(class_id, risk_class);
+				},
+				None => <RiskClasses<T>>::remove(class_id),
+			}
+			Self::deposit_event(Event::RiskClassSet { class_id, risk_class });
+			Ok(())
+		}
+
+		/// Assigns a query identifier to a risk class.
+		///
+		/// - `query_id`: Identifier of the specific data feed.
+		/// - `class_id`: The identifier of the risk class, or `None` to use the default requirements.
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_query_risk_class())]
+		pub fn set_query_risk_class(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			class_id: Option<RiskClassId>,
+		) -> DispatchResult {
+			// ensure origin is governance controller contract
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			match class_id {
+				Some(class_id) => {
+					ensure!(<RiskClasses<T>>::contains_key(class_id), Error::<T>::InvalidRiskClass);
+					<QueryRiskClass<T>>::insert(query_id, class_id);
+				},
+				None => <QueryRiskClass<T>>::remove(query_id),
+			}
+			Self::deposit_event(Event::QueryRiskClassSet { query_id, class_id });
+			Ok(())
+		}
+
+		/// Submits a value report signed by a staked reporter, without fees. Reports are validated
+		/// before entering the transaction pool, so that stale, replayed or time-locked reports are
+		/// rejected.
+		///
+		/// - `payload`: The value report, which must use the current value count as its nonce.
+		/// - `query_data`: The data used to fulfil the data query.
+		/// - `signature`: The reporter's signature of the report payload message.
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as Config>::WeightInfo::submit_value_unsigned(query_data.len() as u32, payload.value.len() as u32)
+			.saturating_add(T::OnNewReport::weight()))]
+		pub fn submit_value_unsigned(
+			origin: OriginFor<T>,
+			payload: ReportPayloadOf<T>,
+			query_data: QueryDataOf<T>,
+			// signature verified by validate_unsigned
+			_signature: T::ReporterSignature,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			let timestamp = Self::now();
+			ensure!(timestamp <= payload.valid_until, Error::<T>::PayloadExpired);
+			// Require exact nonce so that the payload cannot be replayed
+			ensure!(
+				payload.nonce == <ReportedTimestampCount<T>>::get(payload.query_id),
+				Error::<T>::InvalidNonce
+			);
+			let ReportPayload { reporter, query_id, value, nonce, .. } = payload;
+			Self::ensure_valid_submission(query_id, &value, nonce, &query_data)?;
+			// Require reporter to abide by given reporting lock
+			let mut staker =
+				Self::ensure_can_report(&reporter, Self::get_risk_class(query_id), timestamp)?;
+			staker.reporter_last_timestamp = timestamp;
+			let weight =
+				T::WeightInfo::submit_value_unsigned(query_data.len() as u32, value.len() as u32);
+			let hook_weight = Self::store_report(
+				query_id,
+				value,
+				nonce,
+				query_data,
+				reporter,
+				&mut staker,
+				timestamp,
+			)?;
+			Ok(Some(weight.saturating_add(hook_weight)).into())
+		}
+
+		/// Sets the amount of time values reported for a query identifier are retained before
+		/// being pruned. The latest value is always retained.
+		///
+		/// - `query_id`: Identifier of the specific data feed.
+		/// - `retention_period`: The retention period, or `None` to retain values indefinitely.
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_retention_period())]
+		pub fn set_retention_period(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			retention_period: Option<Timestamp>,
+		) -> DispatchResult {
+			// ensure origin is governance controller contract
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			match retention_period {
+				Some(retention_period) => {
+					// Values must remain available throughout dispute and claim windows of later
+					// values, including any longer reporting lock of the risk class
+					ensure!(
+						retention_period >= Self::min_retention_period(query_id),
+						Error::<T>::InvalidRetentionPeriod
+					);
+					<RetentionPeriods<T>>::insert(query_id, retention_period);
+				},
+				None => <RetentionPeriods<T>>::remove(query_id),
+			}
+			Self::deposit_event(Event::RetentionPeriodSet { query_id, retention_period });
+			Ok(())
+		}
+
+		/// Enables or disables a cache of the latest non-disputed values of a query identifier, so
+		/// that they can be read in constant time. The cache is seeded with existing values when
+		/// enabled.
+		///
+		/// - `query_id`: Identifier of the specific data feed.
+		/// - `enabled`: Whether the value cache is enabled.
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_value_cache(T::MaxCachedValues::get()))]
+		pub fn set_value_cache(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			enabled: bool,
+		) -> DispatchResultWithPostInfo {
+			// ensure origin is governance controller contract
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let cached = if enabled {
+				let values = Self::latest_values(query_id, T::MaxCachedValues::get());
+				let cached = values.len() as u32;
+				<ValueCaches<T>>::insert(query_id, values);
+				cached
+			} else {
+				<ValueCaches<T>>::remove(query_id);
+				0
+			};
+			Self::deposit_event(Event::ValueCacheSet { query_id, enabled });
+			Ok(Some(<T as Config>::WeightInfo::set_value_cache(cached)).into())
+		}
+
+		/// Enables or disables large values for a query identifier, whereby each reported value is
+		/// a `ValueReference` to a value noted as a preimage via `note_value`. Can only be changed
+		/// before any values are reported.
+		///
+		/// - `query_id`: Identifier of the specific data feed.
+		/// - `enabled`: Whether reported values reference values noted as preimages.
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_large_values())]
+		pub fn set_large_values(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			enabled: bool,
+		) -> DispatchResult {
+			// ensure origin is governance controller contract
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				<ReportedTimestampCount<T>>::get(query_id) == 0,
+				Error::<T>::ValuesAlreadyReported
+			);
+			if enabled {
+				<LargeValueQueries<T>>::insert(query_id, ());
+			} else {
+				<LargeValueQueries<T>>::remove(query_id);
+			}
+			Self::deposit_event(Event::LargeValuesSet { query_id, enabled });
+			Ok(())
+		}
+
+		/// Allows a staked reporter to note a value as a preimage, so that it can be referenced by
+		/// values reported for large value queries. A deposit proportional to the length of the
+		/// value is held until the value is removed, once no longer referenced or when unnoted.
+		///
+		/// - `value`: The value to be noted.
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as Config>::WeightInfo::note_value(value.len() as u32))]
+		pub fn note_value(origin: OriginFor<T>, value: LargeValueOf<T>) -> DispatchResult {
+			let reporter = ensure_signed(origin)?;
+			ensure!(!value.is_empty(), Error::<T>::InvalidValue);
+			// Limit noting to staked reporters, who have stake at risk
+			let staker = <StakerDetails<T>>::get(&reporter).ok_or(Error::<T>::InsufficientStake)?;
+			ensure!(
+				staker.staked_balance >= <StakeAmount<T>>::get(),
+				Error::<T>::InsufficientStake
+			);
+			let hash = Keccak256::hash(value.as_ref());
+			ensure!(!<Preimages<T>>::contains_key(hash), Error::<T>::PreimageAlreadyNoted);
+			let len = value.len() as u32;
+			let deposit = T::PreimageByteDeposit::get()
+				.saturating_mul(len.into())
+				.saturating_add(T::PreimageBaseDeposit::get());
+			T::Asset::transfer(
+				&reporter,
+				&Self::preimage_deposits(),
+				deposit,
+				Preservation::Preserve,
+			)?;
+			<Preimages<T>>::insert(
+				hash,
+				PreimageOf::<T> { len, references: 0, depositor: reporter.clone(), deposit },
+			);
+			// Archive value, so that it remains available offchain once no longer referenced
+			indexing::archive_preimage(hash, &value);
+			<PreimageValues<T>>::insert(hash, value);
+			Self::deposit_event(Event::ValueNoted { hash, reporter });
+			Ok(())
+		}
+
+		/// Allows a reporter to retract their own value within the retraction period, provided it
+		/// has not been disputed. The retraction penalty is paid to staking rewards and the
+		/// reporter's reporting lock is reset, so that they can resubmit.
+		///
+		/// - `query_id`: Identifier of the specific data feed.
+		/// - `timestamp`: Timestamp of the value to be retracted.
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as Config>::WeightInfo::retract_value(T::MaxDisputedTimeSeries::get())
+			.saturating_add(T::OnValueRemoved::weight()))]
+		pub fn retract_value(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			#[pallet::compact] timestamp: Timestamp,
+		) -> DispatchResultWithPostInfo {
+			let reporter = ensure_signed(origin)?;
+			let report = <Reports<T>>::get(query_id, timestamp).ok_or(Error::<T>::NoValueExists)?;
+			ensure!(report.reporter == reporter, Error::<T>::NotValueReporter);
+			ensure!(!report.is_disputed, Error::<T>::ValueDisputed);
+			ensure!(
+				Self::now().saturating_sub(timestamp) <= T::RetractionPeriod::get(),
+				Error::<T>::RetractionPeriodElapsed
+			);
+
+			let penalty = T::RetractionPenalty::get();
+			Self::do_add_staking_rewards(&reporter, penalty)?;
+			let (iterations, hook_weight) = Self::remove_value(query_id, timestamp)?;
+			<RetractionsByReporter<T>>::mutate(&reporter, |count| count.saturating_inc());
+			// Reset reporting lock, provided the retracted value is the reporter's last. Blocks are
+			// compared, as the timestamp of a value may be ahead of the time at which it was reported
+			if <ReporterLastBlock<T>>::get(&reporter)
+				.map_or(true, |block_number| block_number <= report.block_number)
+			{
+				<StakerDetails<T>>::mutate(&reporter, |maybe| {
+					if let Some(staker) = maybe.as_mut() {
+						staker.reporter_last_timestamp = 0;
+					}
+				});
+			}
+			Self::deposit_event(Event::ValueRetracted { query_id, timestamp, reporter, penalty });
+			Ok(Some(T::WeightInfo::retract_value(iterations).saturating_add(hook_weight)).into())
+		}
+
+		/// Sets the thresholds at which a query identifier is considered degraded or frozen, based on
+		/// open disputes, the proportion of recent values disputed, staleness relative to funded feed
+		/// intervals and reporter diversity.
+		///
+		/// - `query_id`: Identifier of the specific data feed.
+		/// - `thresholds`: The health thresholds, or `None` to no longer track the health of the query
+		///   identifier.
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_health_thresholds())]
+		pub fn set_health_thresholds(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			thresholds: Option<HealthThresholds>,
+		) -> DispatchResult {
+			// ensure origin is governance controller contract
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let health = match thresholds {
+				Some(thresholds) => {
+					ensure!(
+						thresholds.degraded.min_reporters <= HEALTH_WINDOW
+							&& thresholds.frozen.min_reporters <= HEALTH_WINDOW,
+						Error::<T>::InvalidHealthThresholds
+					);
+					<QueryHealthThresholds<T>>::insert(query_id, thresholds);
+					Self::assess_health(query_id, &thresholds, Self::now())
+				},
+				None => {
+					<QueryHealthThresholds<T>>::remove(query_id);
+					QueryHealth::default()
+				},
+			};
+			Self::deposit_event(Event::QueryHealthThresholdsSet { query_id, thresholds });
+			Self::record_health(query_id, health);
+			Ok(())
+		}
+
+		/// Publishes a time-limited emergency value for a query identifier, which consumers may opt
+		/// to fall back on when no healthy reported value exists. Emergency values are stored
+		/// separately from reported values, so can never be disputed or claimed for tips.
+		///
+		/// - `query_id`: Identifier of the specific data feed.
+		/// - `value`: The emergency value, or `None` to clear any existing emergency value.
+		/// - `duration`: The amount of time the emergency value remains valid.
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_emergency_value())]
+		pub fn set_emergency_value(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			value: Option<ValueOf<T>>,
+			#[pallet::compact] duration: Timestamp,
+		) -> DispatchResult {
+			// ensure origin is governance controller contract
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			match value {
+				Some(value) => {
+					ensure!(
+						duration > 0 && duration <= T::MaxEmergencyValueDuration::get(),
+						Error::<T>::InvalidEmergencyValueDuration
+					);
+					ensure!(!value.is_empty(), Error::<T>::InvalidValue);
+					let timestamp = Self::now();
+					let expires = timestamp.saturating_add(duration);
+					<EmergencyValues<T>>::insert(
+						query_id,
+						EmergencyValue { value: value.clone(), timestamp, expires },
+					);
+					Self::deposit_event(Event::EmergencyValueSet { query_id, value, expires });
+				},
+				None => {
+					<EmergencyValues<T>>::remove(query_id);
+					Self::deposit_event(Event::EmergencyValueCleared { query_id });
+				},
+			}
+			Ok(())
+		}
+
+		/// Registers (or deregisters) a derived feed, whose value is derived on-chain from the
+		/// latest values of its operands and exposed under its synthetic query identifier.
+		///
+		/// - `feed`: The query identifiers, decimals and operation of the derived feed.
+		/// - `enabled`: Whether the derived feed is registered.
+		#[pallet::call_index(33)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_derived_feed())]
+		pub fn set_derived_feed(
+			origin: OriginFor<T>,
+			feed: DerivedFeed,
+			enabled: bool,
+		) -> DispatchResult {
+			// ensure origin is governance controller contract
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let query_id = feed.query_id();
+			if enabled {
+				// Derived feeds cannot themselves be operands, to avoid nested derivation
+				ensure!(
+					feed.query_id_a != feed.query_id_b
+						&& !<DerivedFeeds<T>>::contains_key(feed.query_id_a)
+						&& !<DerivedFeeds<T>>::contains_key(feed.query_id_b),
+					Error::<T>::InvalidDerivedFeed
+				);
+				ensure!(
+					<ReportedTimestampCount<T>>::get(query_id) == 0,
+					Error::<T>::ValuesAlreadyReported
+				);
+				<DerivedFeeds<T>>::insert(query_id, feed);
+			} else {
+				<DerivedFeeds<T>>::remove(query_id);
+			}
+			Self::deposit_event(Event::DerivedFeedSet { query_id, feed, enabled });
+			Ok(())
+		}
+
+		/// Subscribes a remote parachain to the values of a query identifier, which are pushed to the
+		/// parachain via XCM as a `Transact` of the receiving call, with the call arguments being the
+		/// SCALE-encoded query identifier, timestamp, value and reporter. Removals of values pushed
+		/// which are subsequently disputed are similarly pushed to any removal call, with the call
+		/// arguments being the SCALE-encoded query identifier and timestamp. Values longer than
+		/// `MaxValueLength`, such as large values, are not pushed. The subscription deposit is held
+		/// from the sovereign account of the parachain until the subscription is cancelled.
+		///
+		/// - `query_id`: Identifier of the subscribed data.
+		/// - `min_interval`: Minimum amount of time between the timestamps of values pushed.
+		/// - `deviation`: Minimum change from the last value pushed for a subsequent value to be
+		///   pushed, with zero pushing every value.
+		/// - `call_index`: The pallet and call indices of the receiving call.
+		/// - `removal_call_index`: The pallet and call indices of the call receiving removals, if any.
+		/// - `weight`: The maximum weight of the receiving call.
+		/// - `fees`: The fees withdrawn on the subscribing parachain to pay for each push.
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as Config>::WeightInfo::subscribe())]
+		pub fn subscribe(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			#[pallet::compact] min_interval: Timestamp,
+			deviation: Permill,
+			call_index: [u8; 2],
+			removal_call_index: Option<[u8; 2]>,
+			weight: Weight,
+			fees: Box<MultiAsset>,
+		) -> DispatchResult {
+			let location = T::SubscriberOrigin::ensure_origin(origin)?;
+			let para_id = Self::sibling(&location).ok_or(Error::<T>::InvalidSubscriber)?;
+			ensure!(
+				<Subscriptions<T>>::count() < T::MaxSubscriptions::get(),
+				Error::<T>::MaxSubscriptionsReached
+			);
+			let subscriber =
+				<T::SovereignAccountOf as xcm_executor::traits::Convert<_, _>>::convert(location)
+					.map_err(|_| Error::<T>::InvalidSubscriber)?;
+			let subscription_id = <SubscriptionCount<T>>::get();
+			<SubscriptionCount<T>>::set(
+				subscription_id.checked_add(1).ok_or(ArithmeticError::Overflow)?,
+			);
+			let deposit = T::SubscriptionDeposit::get();
+			T::Asset::transfer(
+				&subscriber,
+				&Self::subscription_deposits(),
+				deposit,
+				Preservation::Preserve,
+			)?;
+			<SubscriptionDeposits<T>>::insert(subscription_id, deposit);
+			<Subscriptions<T>>::insert(
+				subscription_id,
+				Subscription {
+					para_id,
+					query_id,
+					min_interval,
+					deviation,
+					call_index,
+					removal_call_index,
+					weight,
+					fees: *fees,
+					last_pushed: None,
+					failed: None,
+					removals: <RemovalCount<T>>::get(query_id),
+				},
+			);
+			Self::deposit_event(Event::SubscriptionRegistered {
+				subscription_id,
+				para_id,
+				query_id,
+			});
+			Ok(())
+		}
+
+		/// Cancels a subscription of the calling parachain, returning the deposit held for it.
+		///
+		/// - `subscription_id`: Identifier of the subscription.
+		#[pallet::call_index(35)]
+		#[pallet::weight(<T as Config>::WeightInfo::unsubscribe())]
+		pub fn unsubscribe(
+			origin: OriginFor<T>,
+			subscription_id: SubscriptionId,
+		) -> DispatchResult {
+			let location = T::SubscriberOrigin::ensure_origin(origin)?;
+			let para_id = Self::sibling(&location).ok_or(Error::<T>::InvalidSubscriber)?;
+			ensure!(
+				<Subscriptions<T>>::get(subscription_id)
+					.map_or(false, |subscription| subscription.para_id == para_id),
+				Error::<T>::UnknownSubscription
+			);
+			<Subscriptions<T>>::remove(subscription_id);
+			// Return the deposit held for the subscription
+			if let Some(deposit) = <SubscriptionDeposits<T>>::take(subscription_id) {
+				let subscriber =
+					<T::SovereignAccountOf as xcm_executor::traits::Convert<_, _>>::convert(
+						location,
+					)
+					.map_err(|_| Error::<T>::InvalidSubscriber)?;
+				T::Asset::transfer(
+					&Self::subscription_deposits(),
+					&subscriber,
+					deposit,
+					Preservation::Expendable,
+				)?;
+			}
+			Self::deposit_event(Event::SubscriptionCancelled { subscription_id });
+			Ok(())
+		}
+
+		/// Answers an oracle query from a sibling parachain, replying via XCM with a `Transact` of the
+		/// receiving call, with the call arguments being the SCALE-encoded reference, query
+		/// identifier and latest value (with its timestamp) within the window, if any. The sovereign
+		/// account of the parachain is charged the remote query fee, along with the remote query
+		/// byte fee for each byte of the value replied.
+		///
+		/// - `query_id`: Identifier of the queried data.
+		/// - `before`: The timestamp before which to search for the latest value.
+		/// - `max_age`: The maximum amount of time before `before` to search for a value.
+		/// - `reference`: A reference supplied by the querier to correlate the reply.
+		/// - `call_index`: The pallet and call indices of the receiving call.
+		/// - `weight`: The maximum weight of the receiving call.
+		/// - `fees`: The fees withdrawn on the querying parachain to pay for the reply.
+		#[pallet::call_index(36)]
+		#[pallet::weight(<T as Config>::WeightInfo::query_value(
+			T::MaxLargeValueLength::get().max(T::MaxValueLength::get())
+		))]
+		pub fn query_value(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			#[pallet::compact] before: Timestamp,
+			#[pallet::compact] max_age: Timestamp,
+			reference: u64,
+			call_index: [u8; 2],
+			weight: Weight,
+			fees: Box<MultiAsset>,
+		) -> DispatchResultWithPostInfo {
+			let location = T::RemoteQueryOrigin::ensure_origin(origin)?;
+			let para_id = Self::sibling(&location).ok_or(Error::<T>::InvalidQuerier)?;
+			let querier =
+				<T::SovereignAccountOf as xcm_executor::traits::Convert<_, _>>::convert(location)
+					.map_err(|_| Error::<T>::InvalidQuerier)?;
+
+			let value = <Self as UsingTellor<AccountIdOf<T>>>::get_data_before(query_id, before)
+				.filter(|(_, timestamp)| *timestamp >= before.saturating_sub(max_age));
+			// Charge for the length of the value replied, which may be a large value
+			let len = value.as_ref().map_or(0, |(value, _)| value.len() as u32);
+			let fee = T::RemoteQueryByteFee::get()
+				.saturating_mul(len.into())
+				.saturating_add(T::RemoteQueryFee::get());
+			T::Asset::transfer(&querier, &Self::account(), fee, Preservation::Preserve)?;
+			let timestamp = value.as_ref().map(|(_, timestamp)| *timestamp);
+			let mut call = call_index.to_vec();
+			(reference, query_id, &value).encode_to(&mut call);
+			Self::send_xcm(
+				para_id,
+				xcm::deliver::<T>(&fees, weight, call),
+				Event::RemoteQueryAnswered { para_id, query_id, timestamp },
+			)?;
+			Ok(Some(T::WeightInfo::query_value(len)).into())
+		}
+
+		/// Allows the reporter which noted a value as a preimage to remove it whilst not referenced
+		/// by any reported values, returning the deposit held for it.
+		///
+		/// - `hash`: The hash of the noted value.
+		#[pallet::call_index(37)]
+		#[pallet::weight(<T as Config>::WeightInfo::unnote_value())]
+		pub fn unnote_value(origin: OriginFor<T>, hash: PreimageHash) -> DispatchResult {
+			let reporter = ensure_signed(origin)?;
+			let preimage = <Preimages<T>>::get(hash).ok_or(Error::<T>::PreimageNotNoted)?;
+			ensure!(preimage.depositor == reporter, Error::<T>::NotPreimageDepositor);
+			ensure!(preimage.references == 0, Error::<T>::PreimageReferenced);
+			Self::remove_preimage(hash, preimage);
+			Self::deposit_event(Event::ValueUnnoted { hash, reporter });
+			Ok(())
 		}
 	}
 }
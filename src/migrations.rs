@@ -0,0 +1,321 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations of the Tellor pallet.
+
+use super::*;
+
+pub mod v1 {
+	//! Migrates reported values from separate maps of timestamps (by index) and values (by
+	//! timestamp) into maps keyed by index, one holding the timestamp, reporter and dispute status of
+	//! each value and the other the value itself, and the last reported timestamp of each query
+	//! identifier to a pointer to the index and timestamp of the last reported value.
+	//!
+	//! [`MigrateToV1`] starts the migration on runtime upgrade, which is then completed over
+	//! subsequent blocks by `on_initialize`. Values yet to be migrated continue to be read from the
+	//! previous storage layout in the meantime.
+	//!
+	//! `Reports` is retained rather than folded into the index-keyed maps, as disputes, tips,
+	//! retractions and the `Timestamp`-based `UsingTellor` functions look values up by timestamp, and
+	//! it holds the block number and previous value of each report, which searches never read.
+	//! Values are likewise kept apart from their timestamps, so that searches only read the
+	//! fixed-size entries they probe.
+
+	use super::*;
+	use crate::types::{oracle::IndexedValue, IndexedValueOf, ReportOf, ValueOf};
+	#[cfg(feature = "try-runtime")]
+	use frame_support::storage::{with_transaction, TransactionOutcome};
+	use frame_support::{
+		log,
+		pallet_prelude::*,
+		storage_alias,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	};
+	use sp_std::marker::PhantomData;
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	/// The maximum number of reported values and last reported value pointers sampled before the
+	/// upgrade, to be checked once migrated.
+	#[cfg(feature = "try-runtime")]
+	const MAX_SAMPLES: usize = 10;
+
+	/// The storage layout prior to the migration.
+	pub(crate) mod v0 {
+		use super::*;
+
+		#[storage_alias]
+		pub(crate) type LastReportedTimestamp<T: Config> =
+			StorageMap<Pallet<T>, Identity, QueryId, Timestamp>;
+
+		#[storage_alias]
+		pub(crate) type ReportedTimestampsByIndex<T: Config> =
+			StorageDoubleMap<Pallet<T>, Identity, QueryId, Blake2_128Concat, u32, Timestamp>;
+
+		#[storage_alias]
+		pub(crate) type ReportedValuesByTimestamp<T: Config> =
+			StorageDoubleMap<Pallet<T>, Identity, QueryId, Blake2_128Concat, Timestamp, ValueOf<T>>;
+	}
+
+	/// Starts the multi-block migration of reported values to the storage layout of version 1.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				log::info!(target: "runtime::tellor", "skipping migration to v1");
+				return T::DbWeight::get().reads(1);
+			}
+			<MigrationInProgress<T>>::set(true);
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(target: "runtime::tellor", "started migration to v1");
+			T::DbWeight::get().reads_writes(1, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return Ok(Vec::new());
+			}
+			// Only entries with a report are migrated
+			let mut values = 0u32;
+			let mut samples = Vec::new();
+			for (query_id, index, timestamp) in v0::ReportedTimestampsByIndex::<T>::iter() {
+				let Some(report) = <Reports<T>>::get(query_id, timestamp) else { continue };
+				values.saturating_inc();
+				if samples.len() < MAX_SAMPLES {
+					let value = v0::ReportedValuesByTimestamp::<T>::get(query_id, timestamp)
+						.filter(|_| !report.is_disputed);
+					samples.push((query_id, index, timestamp, report.is_disputed, value));
+				}
+			}
+			let mut last_reported = 0u32;
+			let mut last_reported_samples = Vec::new();
+			for (query_id, timestamp) in v0::LastReportedTimestamp::<T>::iter() {
+				let Some(report) = <Reports<T>>::get(query_id, timestamp) else { continue };
+				last_reported.saturating_inc();
+				if last_reported_samples.len() < MAX_SAMPLES {
+					last_reported_samples.push((query_id, report.index, timestamp));
+				}
+			}
+			Ok((values, samples, last_reported, last_reported_samples).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not updated");
+			if state.is_empty() {
+				return Ok(());
+			}
+			ensure!(<MigrationInProgress<T>>::get(), "migration not started");
+			let (values, samples, last_reported, last_reported_samples): (
+				u32,
+				Vec<(QueryId, u32, Timestamp, bool, Option<ValueOf<T>>)>,
+				u32,
+				Vec<(QueryId, u32, Timestamp)>,
+			) = Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			// Complete the migration, which otherwise continues over subsequent blocks, before
+			// discarding the changes
+			with_transaction(|| {
+				let result = (|| -> Result<(), &'static str> {
+					while <MigrationInProgress<T>>::get() {
+						migrate::<T>(u32::MAX);
+					}
+					ensure!(
+						v0::ReportedTimestampsByIndex::<T>::iter().next().is_none()
+							&& v0::ReportedValuesByTimestamp::<T>::iter().next().is_none()
+							&& v0::LastReportedTimestamp::<T>::iter().next().is_none(),
+						"entries of the previous storage layout remain"
+					);
+					ensure!(
+						<ReportedValues<T>>::iter().count() as u32 == values,
+						"reported value count mismatch"
+					);
+					ensure!(
+						<LastReported<T>>::iter().count() as u32 == last_reported,
+						"last reported value count mismatch"
+					);
+					for (query_id, index, timestamp, is_disputed, value) in samples {
+						let migrated = <ReportedValues<T>>::get(query_id, index)
+							.ok_or("reported value not migrated")?;
+						ensure!(
+							migrated.timestamp == timestamp && migrated.is_disputed == is_disputed,
+							"reported value mismatch"
+						);
+						ensure!(
+							<ReportedValueData<T>>::get(query_id, index) == value,
+							"reported value data mismatch"
+						);
+					}
+					for (query_id, index, timestamp) in last_reported_samples {
+						ensure!(
+							<LastReported<T>>::get(query_id) == Some((index, timestamp)),
+							"last reported value mismatch"
+						);
+					}
+					Ok(())
+				})();
+				TransactionOutcome::Rollback(Ok::<_, DispatchError>(result))
+			})
+			.map_err(|_| "migration could not be completed")?
+		}
+	}
+
+	/// Migrates reported values, followed by the last reported value pointers, removing entries of
+	/// the previous storage layout as they are migrated. Completes the migration once no entries
+	/// remain.
+	/// # Arguments
+	/// * `limit` - The maximum number of entries to migrate.
+	/// # Returns
+	/// The number of entries migrated.
+	pub(crate) fn migrate<T: Config>(limit: u32) -> u32 {
+		let mut migrated = 0;
+		if limit == 0 {
+			return migrated;
+		}
+		for (query_id, index, timestamp) in v0::ReportedTimestampsByIndex::<T>::drain() {
+			let value = v0::ReportedValuesByTimestamp::<T>::take(query_id, timestamp);
+			// Values disputed or reported since the upgrade have already been migrated
+			if !<ReportedValues<T>>::contains_key(query_id, index) {
+				if let Some(report) = <Reports<T>>::get(query_id, timestamp) {
+					// Values of disputed reports are not retained
+					if let Some(value) = value.filter(|_| !report.is_disputed) {
+						<ReportedValueData<T>>::insert(query_id, index, value);
+					}
+					<ReportedValues<T>>::insert(
+						query_id,
+						index,
+						to_indexed_value::<T>(query_id, timestamp, report),
+					);
+				}
+			}
+			migrated.saturating_inc();
+			if migrated >= limit {
+				return migrated;
+			}
+		}
+		for (query_id, timestamp) in v0::LastReportedTimestamp::<T>::drain() {
+			if let Some(report) = <Reports<T>>::get(query_id, timestamp) {
+				<LastReported<T>>::insert(query_id, (report.index, timestamp));
+			}
+			migrated.saturating_inc();
+			if migrated >= limit {
+				return migrated;
+			}
+		}
+		<MigrationInProgress<T>>::kill();
+		log::info!(target: "runtime::tellor", "completed migration to v1");
+		migrated
+	}
+
+	/// Converts a report to an indexed value of the current storage layout.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported value.
+	/// * `timestamp` - The timestamp of the reported value.
+	/// * `report` - The report of the value.
+	/// # Returns
+	/// The indexed value.
+	fn to_indexed_value<T: Config>(
+		query_id: QueryId,
+		timestamp: Timestamp,
+		report: ReportOf<T>,
+	) -> IndexedValueOf<T> {
+		// Disputed values point to the last non-disputed value reported prior
+		let previous = report
+			.previous
+			.filter(|_| report.is_disputed)
+			.and_then(|previous| <Reports<T>>::get(query_id, previous))
+			.map(|previous| previous.index);
+		IndexedValue {
+			timestamp,
+			reporter: report.reporter,
+			is_disputed: report.is_disputed,
+			previous,
+		}
+	}
+
+	/// Reads the last reported value pointer of a query identifier which has yet to be migrated.
+	/// # Arguments
+	/// * `query_id` - The query identifier.
+	/// # Returns
+	/// The index and timestamp of the last reported value, if yet to be migrated.
+	pub(crate) fn last_reported<T: Config>(query_id: QueryId) -> Option<(u32, Timestamp)> {
+		if !<MigrationInProgress<T>>::get() {
+			return None;
+		}
+		let timestamp = v0::LastReportedTimestamp::<T>::get(query_id)?;
+		<Reports<T>>::get(query_id, timestamp).map(|report| (report.index, timestamp))
+	}
+
+	/// Reads the timestamp, reporter and dispute status of a reported value which has yet to be
+	/// migrated.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported value.
+	/// * `index` - The index of the reported value.
+	/// # Returns
+	/// The indexed value, if yet to be migrated.
+	pub(crate) fn indexed_value<T: Config>(
+		query_id: QueryId,
+		index: u32,
+	) -> Option<IndexedValueOf<T>> {
+		if !<MigrationInProgress<T>>::get() {
+			return None;
+		}
+		let timestamp = v0::ReportedTimestampsByIndex::<T>::get(query_id, index)?;
+		let report = <Reports<T>>::get(query_id, timestamp)?;
+		Some(to_indexed_value::<T>(query_id, timestamp, report))
+	}
+
+	/// Reads a reported value which has yet to be migrated, provided it has not been disputed.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported value.
+	/// * `index` - The index of the reported value.
+	/// # Returns
+	/// The reported value, if yet to be migrated.
+	pub(crate) fn reported_value<T: Config>(query_id: QueryId, index: u32) -> Option<ValueOf<T>> {
+		if !<MigrationInProgress<T>>::get() {
+			return None;
+		}
+		let timestamp = v0::ReportedTimestampsByIndex::<T>::get(query_id, index)?;
+		<Reports<T>>::get(query_id, timestamp).filter(|report| !report.is_disputed)?;
+		v0::ReportedValuesByTimestamp::<T>::get(query_id, timestamp)
+	}
+
+	/// Removes the last reported value pointer of a query identifier which has yet to be migrated,
+	/// so that it cannot replace a more recent pointer.
+	/// # Arguments
+	/// * `query_id` - The query identifier.
+	pub(crate) fn remove_last_reported<T: Config>(query_id: QueryId) {
+		if <MigrationInProgress<T>>::get() {
+			v0::LastReportedTimestamp::<T>::remove(query_id);
+		}
+	}
+
+	/// Removes a reported value which has yet to be migrated.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported value.
+	/// * `index` - The index of the reported value.
+	/// * `timestamp` - The timestamp of the reported value.
+	pub(crate) fn remove_reported_value<T: Config>(
+		query_id: QueryId,
+		index: u32,
+		timestamp: Timestamp,
+	) {
+		if <MigrationInProgress<T>>::get() {
+			v0::ReportedTimestampsByIndex::<T>::remove(query_id, index);
+			v0::ReportedValuesByTimestamp::<T>::remove(query_id, timestamp);
+		}
+	}
+}
@@ -16,15 +16,16 @@
 
 use crate as tellor;
 use crate::{
-	constants::HOURS,
-	traits::{UniversalWeigher, Weigher},
-	types::Address,
+	constants::{DAYS, HOURS, MINUTES},
+	traits::{OnNewReport, OnResponse, OnValueRemoved, UniversalWeigher, Weigher},
+	types::{Address, CallbackId, QueryId, RequestId, Timestamp},
 	xcm::ContractLocation,
-	EnsureGovernance, EnsureStaking,
+	EnsureGovernance, EnsureStaking, VoteResult,
 };
+use codec::{Decode, Encode};
 use frame_support::{
 	assert_ok, log, parameter_types,
-	traits::{ConstU16, ConstU64, OnFinalize, UnixTime},
+	traits::{ConstU16, ConstU64, EnsureOrigin, OnFinalize, UnixTime},
 	weights::{
 		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
 		Weight,
@@ -35,10 +36,11 @@ use frame_support::{
 use frame_support::{traits::tokens::fungible::Mutate, BoundedVec};
 use frame_system as system;
 use once_cell::sync::Lazy;
+use scale_info::TypeInfo;
 use sp_core::{ConstU128, ConstU32, ConstU8, H256};
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
 };
 use sp_std::cell::RefCell;
 use std::{
@@ -55,6 +57,7 @@ type Block = frame_system::mocking::MockBlock<Test>;
 pub(crate) const EVM_PARA_ID: u32 = 2000;
 pub(crate) const PALLET_INDEX: u8 = 3;
 pub(crate) const PARA_ID: u32 = 3000;
+pub(crate) const SIBLING_PARA_ID: u32 = 4000;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -133,6 +136,7 @@ parameter_types! {
 	pub StakingToLocalTokenPriceQueryId: H256 = H256([252, 212, 53, 69, 139, 47, 79, 224, 14, 207, 98, 192, 81, 195, 123, 170, 138, 241, 23, 4, 53, 70, 22, 191, 191, 171, 11, 101, 130, 16, 61, 30]);
 	pub XcmFeesAsset : AssetId = AssetId::Concrete(PalletInstance(3).into()); // Balances pallet on EVM parachain
 	pub FeeLocation : Junctions = Junctions::Here;
+	pub static FeelessSubmissions: bool = false;
 }
 
 impl tellor::Config for Test {
@@ -144,24 +148,56 @@ impl tellor::Config for Test {
 	type EthereumXcmPalletIndex = ConstU8<38>;
 	type Fee = ConstU16<10>; // 1%
 	type FeeLocation = FeeLocation;
+	type FeelessSubmissions = FeelessSubmissions;
 	type Governance = TellorGovernance;
 	type GovernanceOrigin = EnsureGovernance;
 	type InitialDisputeFee = ConstU128<{ 50 * 10u128.pow(12) }>; // (100 TRB / 10) * 5, where TRB 1:5 OCP
+	type MaxCachedValues = ConstU32<5>;
 	type MaxClaimTimestamps = ConstU32<100>; // 100 timestamps per claim
 	type MaxDisputedTimeSeries = ConstU32<100>;
+	type MaxEmergencyValueDuration = ConstU64<{ 24 * HOURS }>;
+	type MaxLargeValueLength = ConstU32<4096>;
+	type MaxPendingRequests = ConstU32<100>;
 	type MaxQueryDataLength = ConstU32<1024>;
+	type MaxReportTimestampOffset = ConstU64<2>;
+	type MaxRequestDelay = ConstU64<{ 7 * DAYS }>;
+	type MaxSubmissionsPerBatch = ConstU32<10>;
+	type MaxSubscriptions = ConstU32<10>;
 	type MaxValueLength = ConstU32<256>;
 	type MaxVotes = ConstU32<10>; // 10 votes max when voting on multiple disputes
 	type MinimumStakeAmount = MinimumStakeAmount;
+	type OnNewReport = TestHooks;
+	type OnResponse = TestHooks;
+	type OnValueRemoved = TestHooks;
 	type PalletId = TellorPalletId;
 	type ParachainId = ParachainId;
+	type PreimageBaseDeposit = ConstU128<{ 10u128.pow(12) }>; // 1 token
+	type PreimageByteDeposit = ConstU128<{ 10u128.pow(9) }>; // 0.001 token
 	type RegisterOrigin = system::EnsureRoot<AccountId>;
 	type Registry = TellorRegistry;
+	type RemoteQueryByteFee = ConstU128<{ 10u128.pow(9) }>; // 0.001 token
+	type RemoteQueryFee = ConstU128<{ 10u128.pow(12) }>; // 1 token
+	type RemoteQueryOrigin = EnsureSibling;
+	#[cfg(feature = "offchain-worker")]
+	type ReporterAuthorityId = tellor::offchain::ReporterAuthorityId;
+	type ReporterPublic = TestPublic;
+	type ReporterSignature = TestSignature;
+	type RequestTimeout = ConstU64<{ 2 * HOURS }>;
+	type RetractionPenalty = ConstU128<{ 10u128.pow(12) }>; // 1 token
+	type RetractionPeriod = ConstU64<{ 5 * MINUTES }>;
+	type RevealWindow = ConstU64<{ 5 * MINUTES }>;
+	type SovereignAccountOf = SiblingSovereignAccount;
 	type StakeAmountCurrencyTarget = ConstU128<{ 500 * 10u128.pow(18) }>;
 	type StakingOrigin = EnsureStaking;
 	type StakingTokenPriceQueryId = StakingTokenPriceQueryId;
 	type StakingToLocalTokenPriceQueryId = StakingToLocalTokenPriceQueryId;
+	type SubscriberOrigin = EnsureSibling;
+	type SubscriptionDeposit = ConstU128<{ 5 * 10u128.pow(12) }>; // 5 tokens
+	type SubscriptionDisputeBuffer = ConstU64<{ 10 * MINUTES }>;
+	type SubscriptionFee = ConstU128<{ 10u128.pow(12) }>; // 1 token
 	type Time = Timestamp;
+	type UnsignedLongevity = ConstU64<64>;
+	type UnsignedPriority = ConstU64<{ 1 << 20 }>;
 	type UpdateStakeAmountInterval = ConstU64<{ 12 * HOURS }>;
 	type WeightToFee = ConstU128<10_000>;
 	type Xcm = TestSendXcm;
@@ -173,6 +209,97 @@ impl tellor::Config for Test {
 	type WeightInfo = ();
 }
 
+/// Public key of a test reporter, which is the account identifier itself.
+#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, TypeInfo)]
+pub struct TestPublic(AccountId);
+impl IdentifyAccount for TestPublic {
+	type AccountId = AccountId;
+	fn into_account(self) -> Self::AccountId {
+		self.0
+	}
+}
+
+/// Test signature, valid when signed by the expected account over the expected message.
+#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, TypeInfo)]
+pub struct TestSignature(pub AccountId, pub Vec<u8>);
+impl Verify for TestSignature {
+	type Signer = TestPublic;
+	fn verify<L: sp_runtime::traits::Lazy<[u8]>>(&self, mut msg: L, signer: &AccountId) -> bool {
+		signer == &self.0 && msg.get() == &self.1[..]
+	}
+}
+
+#[cfg(feature = "offchain-worker")]
+pub(crate) use offchain::*;
+#[cfg(feature = "offchain-worker")]
+mod offchain {
+	use super::*;
+	use frame_system::offchain::{
+		AppCrypto, CreateSignedTransaction, SendTransactionTypes, SigningTypes,
+	};
+	use sp_core::sr25519;
+	use sp_runtime::testing::TestXt;
+
+	pub(crate) type Extrinsic = TestXt<RuntimeCall, ()>;
+
+	/// Public key of an offchain worker reporter, with the account identifier taken from the
+	/// leading bytes of the key.
+	#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, TypeInfo)]
+	pub struct OffchainPublic(pub sr25519::Public);
+	impl IdentifyAccount for OffchainPublic {
+		type AccountId = AccountId;
+		fn into_account(self) -> Self::AccountId {
+			let mut bytes = [0u8; 16];
+			bytes.copy_from_slice(&self.0 .0[..16]);
+			AccountId::from_le_bytes(bytes)
+		}
+	}
+	impl From<sr25519::Public> for OffchainPublic {
+		fn from(public: sr25519::Public) -> Self {
+			Self(public)
+		}
+	}
+	impl From<OffchainPublic> for sr25519::Public {
+		fn from(public: OffchainPublic) -> Self {
+			public.0
+		}
+	}
+
+	impl AppCrypto<OffchainPublic, sr25519::Signature> for tellor::offchain::ReporterAuthorityId {
+		type RuntimeAppPublic = tellor::offchain::crypto::Public;
+		type GenericSignature = sr25519::Signature;
+		type GenericPublic = sr25519::Public;
+	}
+
+	impl SigningTypes for Test {
+		type Public = OffchainPublic;
+		type Signature = sr25519::Signature;
+	}
+
+	impl<C> SendTransactionTypes<C> for Test
+	where
+		RuntimeCall: From<C>,
+	{
+		type OverarchingCall = RuntimeCall;
+		type Extrinsic = Extrinsic;
+	}
+
+	impl<C> CreateSignedTransaction<C> for Test
+	where
+		RuntimeCall: From<C>,
+	{
+		fn create_transaction<A: AppCrypto<Self::Public, Self::Signature>>(
+			call: RuntimeCall,
+			_public: Self::Public,
+			_account: AccountId,
+			nonce: u64,
+		) -> Option<(RuntimeCall, <Extrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload)>
+		{
+			Some((call, (nonce, ())))
+		}
+	}
+}
+
 thread_local! {
 	pub static SENT_XCM: RefCell<Vec<(MultiLocation, Xcm<()>)>> = RefCell::new(Vec::new());
 }
@@ -205,11 +332,117 @@ impl tellor::traits::SendXcm for TestSendXcm {
 	}
 }
 
+/// Converts a sibling parachain location to its sovereign account, which is the parachain identifier.
+pub struct SiblingSovereignAccount;
+impl xcm_executor::traits::Convert<MultiLocation, AccountId> for SiblingSovereignAccount {
+	fn convert(location: MultiLocation) -> Result<AccountId, MultiLocation> {
+		match location {
+			MultiLocation { parents: 1, interior: X1(Parachain(para_id)) } => Ok(para_id.into()),
+			_ => Err(location),
+		}
+	}
+}
+
+/// Ensure the origin is a remote parachain, with a signed origin of an account mapped to the sibling
+/// parachain of the same identifier and the root origin mapped to the relay chain.
+pub struct EnsureSibling;
+impl EnsureOrigin<RuntimeOrigin> for EnsureSibling {
+	type Success = MultiLocation;
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		let origin: Result<system::RawOrigin<AccountId>, RuntimeOrigin> = o.into();
+		origin.and_then(|o| match o {
+			system::RawOrigin::Signed(account) => {
+				Ok(MultiLocation::new(1, X1(Parachain(account as u32))))
+			},
+			system::RawOrigin::Root => Ok(MultiLocation::parent()),
+			r => Err(RuntimeOrigin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::signed(SIBLING_PARA_ID.into()))
+	}
+}
+
+thread_local! {
+	pub static HOOK_CALLS: RefCell<Vec<HookCall>> = RefCell::new(Vec::new());
+}
+pub fn hook_calls() -> Vec<HookCall> {
+	HOOK_CALLS.with(|q| (*q.borrow()).clone())
+}
+#[derive(Clone, Debug, PartialEq)]
+pub enum HookCall {
+	NewReport(QueryId, Timestamp, AccountId),
+	ValueRemoved(QueryId, Timestamp),
+	VoteTallied(QueryId, Timestamp, VoteResult),
+	VoteExecuted(QueryId, Timestamp, VoteResult),
+	Response(RequestId, CallbackId, QueryId, Vec<u8>, Timestamp),
+	Timeout(RequestId, CallbackId, QueryId),
+}
+/// Handler which records each call, consuming a fixed weight
+pub struct TestHooks;
+impl TestHooks {
+	pub(crate) const WEIGHT: Weight = Weight::from_parts(1_000_000, 0);
+
+	fn record(call: HookCall) -> Weight {
+		HOOK_CALLS.with(|q| q.borrow_mut().push(call));
+		Self::WEIGHT
+	}
+}
+impl OnNewReport<AccountId> for TestHooks {
+	fn on_new_report(query_id: QueryId, timestamp: Timestamp, reporter: &AccountId) -> Weight {
+		Self::record(HookCall::NewReport(query_id, timestamp, *reporter))
+	}
+
+	fn weight() -> Weight {
+		Self::WEIGHT
+	}
+}
+impl OnValueRemoved for TestHooks {
+	fn on_value_removed(query_id: QueryId, timestamp: Timestamp) -> Weight {
+		Self::record(HookCall::ValueRemoved(query_id, timestamp))
+	}
+
+	fn on_vote_tallied(query_id: QueryId, timestamp: Timestamp, result: VoteResult) -> Weight {
+		Self::record(HookCall::VoteTallied(query_id, timestamp, result))
+	}
+
+	fn on_vote_executed(query_id: QueryId, timestamp: Timestamp, result: VoteResult) -> Weight {
+		Self::record(HookCall::VoteExecuted(query_id, timestamp, result))
+	}
+
+	fn weight() -> Weight {
+		Self::WEIGHT
+	}
+}
+
+impl OnResponse for TestHooks {
+	fn on_response(
+		request_id: RequestId,
+		callback: CallbackId,
+		query_id: QueryId,
+		value: Vec<u8>,
+		timestamp: Timestamp,
+	) -> Weight {
+		Self::record(HookCall::Response(request_id, callback, query_id, value, timestamp))
+	}
+
+	fn on_timeout(request_id: RequestId, callback: CallbackId, query_id: QueryId) -> Weight {
+		Self::record(HookCall::Timeout(request_id, callback, query_id))
+	}
+
+	fn weight() -> Weight {
+		Self::WEIGHT
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub struct TestBenchmarkHelper;
 #[cfg(feature = "runtime-benchmarks")]
 impl<MaxQueryDataLength: sp_core::Get<u32>>
-	tellor::traits::BenchmarkHelper<AccountId, Balance, MaxQueryDataLength> for TestBenchmarkHelper
+	tellor::traits::BenchmarkHelper<AccountId, Balance, MaxQueryDataLength, TestSignature>
+	for TestBenchmarkHelper
 {
 	fn set_time(time_in_secs: u64) {
 		System::set_block_number(System::block_number() + 1);
@@ -257,6 +490,10 @@ impl<MaxQueryDataLength: sp_core::Get<u32>>
 			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 		])
 	}
+
+	fn sign(account_id: &AccountId, message: &[u8]) -> TestSignature {
+		TestSignature(*account_id, message.to_vec())
+	}
 }
 
 // Build genesis storage according to the mock runtime.
@@ -306,6 +543,7 @@ pub(crate) fn with_block_after<R>(time_in_secs: u64, execute: impl FnOnce() -> R
 	// Reset events after block executed, ensuring we only receive events for current block
 	System::reset_events();
 	SENT_XCM.with(|q| q.borrow_mut().clear());
+	HOOK_CALLS.with(|q| q.borrow_mut().clear());
 	result
 }
 
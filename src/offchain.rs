@@ -0,0 +1,248 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+//! An optional offchain worker which reports values for configured query identifiers, using a
+//! reporter key from the local keystore.
+//!
+//! The offchain worker is configured via the following (SCALE encoded) offchain storage values:
+//! - [`REPORTER_KEY`]: the public key of the reporter, as held in the local keystore under [`KEY_TYPE`].
+//! - [`SOURCES_KEY`]: the query data and HTTP/JSON sources of each query to be reported.
+
+use super::*;
+use crate::{
+	constants::DECIMALS,
+	types::{QueryDataOf, ValueOf},
+};
+use codec::Decode;
+use frame_support::{log, pallet_prelude::TypeInfo, RuntimeDebug};
+use frame_system::offchain::{AppCrypto, SendSignedTransaction, Signer};
+use lite_json::{parse_json, JsonValue, NumberValue};
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::{
+	offchain::{http, storage::StorageValueRef, Duration},
+	traits::{Hash, IdentifyAccount, Keccak256},
+	MultiSignature, MultiSigner,
+};
+use sp_std::{prelude::*, str, string::String};
+
+/// The key type of reporter keys used by the offchain worker.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"tllr");
+/// The offchain storage key of the reporter public key.
+pub const REPORTER_KEY: &[u8] = b"tellor::ocw::reporter";
+/// The offchain storage key of the sources of each query to be reported.
+pub const SOURCES_KEY: &[u8] = b"tellor::ocw::sources";
+/// The offchain storage key of the time of the last submission, to avoid resubmitting whilst a
+/// previous submission is pending.
+const LAST_SUBMISSION_KEY: &[u8] = b"tellor::ocw::last-submission";
+/// Amount of time after which a submission which has not been included is assumed to have been
+/// rejected, allowing the reporter to submit again.
+const PENDING_SUBMISSION_TIMEOUT: Timestamp = 5 * MINUTES;
+/// Amount of time to wait for a response from a source, in milliseconds.
+const FETCH_TIMEOUT: u64 = 2_000;
+
+/// Reporter keys used by the offchain worker.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_runtime::app_crypto::{app_crypto, sr25519};
+	app_crypto!(sr25519, KEY_TYPE);
+}
+
+/// The identifier of reporter keys used by the offchain worker, for use as `ReporterAuthorityId`.
+pub struct ReporterAuthorityId;
+impl AppCrypto<MultiSigner, MultiSignature> for ReporterAuthorityId {
+	type RuntimeAppPublic = crypto::Public;
+	type GenericSignature = sp_core::sr25519::Signature;
+	type GenericPublic = sp_core::sr25519::Public;
+}
+
+/// A source of values, as a JSON document fetched via HTTP.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Source {
+	/// The URL of the JSON document.
+	pub url: Vec<u8>,
+	/// The path of object keys (or array indices) to the value within the JSON document. The value
+	/// may be either a number or a string containing a number.
+	pub path: Vec<Vec<u8>>,
+}
+
+/// The sources of values for a query to be reported.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct QuerySources {
+	/// The query data of the query to be reported.
+	pub query_data: Vec<u8>,
+	/// The sources of values, aggregated by taking the median.
+	pub sources: Vec<Source>,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Reports a value for the configured query identifier which was least recently reported,
+	/// provided the reporter is not time locked.
+	/// # Returns
+	/// The query identifier reported, if any.
+	pub(super) fn offchain_report() -> Result<Option<QueryId>, &'static str> {
+		let Some(reporter) = StorageValueRef::persistent(REPORTER_KEY)
+			.get::<T::Public>()
+			.map_err(|_| "invalid reporter key")?
+		else {
+			return Ok(None);
+		};
+		let queries = StorageValueRef::persistent(SOURCES_KEY)
+			.get::<Vec<QuerySources>>()
+			.map_err(|_| "invalid sources")?
+			.unwrap_or_default();
+
+		// Wait for any submission still pending inclusion, until it is assumed to have been rejected
+		let timestamp = Self::now();
+		let account = reporter.clone().into_account();
+		let last_timestamp =
+			Self::get_reporter_last_timestamp(account.clone()).ok_or("reporter not staked")?;
+		let last_submission = StorageValueRef::persistent(LAST_SUBMISSION_KEY);
+		if let Some(submitted) = last_submission.get::<Timestamp>().ok().flatten() {
+			if last_timestamp < submitted
+				&& timestamp < submitted.saturating_add(PENDING_SUBMISSION_TIMEOUT)
+			{
+				return Ok(None);
+			}
+		}
+
+		// Check the reporter's stake and reporting lock, as checked on submission
+		let Some((query_id, query)) = queries
+			.into_iter()
+			.map(|query| (Keccak256::hash(&query.query_data), query))
+			.filter(|(query_id, _)| {
+				!<CommitRevealQueries<T>>::contains_key(query_id)
+					&& Self::ensure_can_report(&account, Self::get_risk_class(*query_id), timestamp)
+						.is_ok()
+			})
+			.min_by_key(|(query_id, _)| Self::last_reported(*query_id).map(|(_, t)| t))
+		else {
+			return Ok(None);
+		};
+
+		let query_data: QueryDataOf<T> =
+			query.query_data.try_into().map_err(|_| "query data too long")?;
+		let values: Vec<U256> = query
+			.sources
+			.iter()
+			.filter_map(|source| {
+				Self::fetch_value(source)
+					.map_err(|e| log::debug!(target: "runtime::tellor", "source failed: {}", e))
+					.ok()
+			})
+			.collect();
+		let value = Self::median(values).ok_or("no values fetched")?;
+		let mut bytes = [0u8; 32];
+		value.to_big_endian(&mut bytes);
+		let value: ValueOf<T> = bytes.to_vec().try_into().map_err(|_| "value too long")?;
+
+		let (_, result) = Signer::<T, T::ReporterAuthorityId>::any_account()
+			.with_filter(vec![reporter])
+			.send_signed_transaction(|_| Call::submit_value {
+				query_id,
+				value: value.clone(),
+				nonce: Self::get_new_value_count_by_query_id(query_id),
+				query_data: query_data.clone(),
+			})
+			.ok_or("reporter key not found in keystore")?;
+		result.map_err(|_| "unable to submit transaction")?;
+		last_submission.set(&timestamp);
+		Ok(Some(query_id))
+	}
+
+	/// Fetches a value from a source.
+	/// # Arguments
+	/// * `source` - The source of the value.
+	/// # Returns
+	/// The value, with 18 decimals.
+	pub(super) fn fetch_value(source: &Source) -> Result<U256, &'static str> {
+		let url = str::from_utf8(&source.url).map_err(|_| "invalid url")?;
+		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT));
+		let response = http::Request::get(url)
+			.deadline(deadline)
+			.send()
+			.map_err(|_| "unable to send request")?
+			.try_wait(deadline)
+			.map_err(|_| "deadline reached")?
+			.map_err(|_| "request failed")?;
+		ensure!(response.code == 200, "unexpected status code");
+		let body = response.body().collect::<Vec<u8>>();
+		let body = str::from_utf8(&body).map_err(|_| "invalid response")?;
+		Self::parse_value(body, &source.path).ok_or("value not found")
+	}
+
+	/// Calculates the median of values.
+	/// # Arguments
+	/// * `values` - The values.
+	/// # Returns
+	/// The median value, if any.
+	pub(super) fn median(mut values: Vec<U256>) -> Option<U256> {
+		values.sort();
+		let mid = values.len() / 2;
+		match values.len() {
+			0 => None,
+			len if len % 2 == 0 => values[mid - 1].checked_add(values[mid]).map(|sum| sum / 2),
+			_ => Some(values[mid]),
+		}
+	}
+
+	/// Parses a value from a JSON document.
+	/// # Arguments
+	/// * `json` - The JSON document.
+	/// * `path` - The path of object keys (or array indices) to the value.
+	/// # Returns
+	/// The value, with 18 decimals.
+	pub(super) fn parse_value(json: &str, path: &[Vec<u8>]) -> Option<U256> {
+		let mut value = parse_json(json).ok()?;
+		for key in path {
+			value = match value {
+				JsonValue::Object(entries) => entries
+					.into_iter()
+					.find(|(k, _)| k.iter().copied().eq(key.iter().map(|b| *b as char)))
+					.map(|(_, v)| v)?,
+				JsonValue::Array(items) => {
+					items.into_iter().nth(str::from_utf8(key).ok()?.parse().ok()?)?
+				},
+				_ => return None,
+			};
+		}
+		match value {
+			JsonValue::Number(number) => Self::to_fixed_point(number),
+			JsonValue::String(chars) => match parse_json(&chars.into_iter().collect::<String>()) {
+				Ok(JsonValue::Number(number)) => Self::to_fixed_point(number),
+				_ => None,
+			},
+			_ => None,
+		}
+	}
+
+	/// Converts a (non-negative) JSON number to a value with 18 decimals.
+	fn to_fixed_point(number: NumberValue) -> Option<U256> {
+		if number.negative {
+			return None;
+		}
+		let digits = U256::from(number.integer)
+			.checked_mul(U256::from(10).checked_pow(number.fraction_length.into())?)?
+			.checked_add(number.fraction.into())?;
+		let exponent =
+			i64::from(DECIMALS) - i64::from(number.fraction_length) + i64::from(number.exponent);
+		let scale = U256::from(10).checked_pow(exponent.unsigned_abs().into())?;
+		if exponent >= 0 {
+			digits.checked_mul(scale)
+		} else {
+			digits.checked_div(scale)
+		}
+	}
+}
@@ -0,0 +1,268 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+//! Verification of reported values against a state root, allowing other chains to verify a value
+//! without trusting the node serving it.
+//!
+//! The storage keys of a reported value are recomputed from its query identifier and timestamp,
+//! and read from a storage proof of the relevant keys (as returned by the node-side RPC of the
+//! `tellor-rpc` crate). The value is only proven to be the latest non-disputed value before the
+//! requested timestamp when the proof also shows that it is the last reported value, or that every
+//! value reported after it and before the requested timestamp has been disputed. The verifier is
+//! `no_std` and hashes without host functions, so that it can also be used by other parachains or
+//! contracts.
+
+use crate::types::{
+	oracle::{IndexedValue, Report},
+	QueryId, Timestamp,
+};
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::{
+	hashing::{blake2_128, twox_128, twox_64},
+	Hasher,
+};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+use sp_trie::{read_trie_value, LayoutV1, StorageProof};
+
+/// The latest non-disputed value reported before a timestamp, as proven by a storage proof.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ProvenValue<AccountId> {
+	/// The reported value.
+	pub value: Vec<u8>,
+	/// The time the value was reported.
+	pub timestamp: Timestamp,
+	/// The reporter of the value.
+	pub reporter: AccountId,
+}
+
+/// The reasons a storage proof may fail verification.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ProofError {
+	/// The proof is incomplete or does not match the state root.
+	InvalidProof,
+	/// The proven storage is inconsistent or could not be decoded.
+	InvalidValue,
+	/// The value is not the latest non-disputed value reported before the requested timestamp.
+	NotLatest,
+}
+
+/// Returns the storage key of the index and timestamp of the last (non-disputed) reported value.
+/// # Arguments
+/// * `pallet_name` - The name of the pallet within the runtime.
+/// * `query_id` - The query identifier of the reported values.
+/// # Returns
+/// The storage key of the last reported value within `LastReported`.
+pub fn last_reported_key(pallet_name: &[u8], query_id: QueryId) -> Vec<u8> {
+	[&twox_128(pallet_name)[..], &twox_128(b"LastReported"), query_id.as_bytes()].concat()
+}
+
+/// Returns the storage key of the flag indicating whether reported values are being migrated to
+/// the current storage layout.
+/// # Arguments
+/// * `pallet_name` - The name of the pallet within the runtime.
+/// # Returns
+/// The storage key of `MigrationInProgress`.
+pub fn migration_in_progress_key(pallet_name: &[u8]) -> Vec<u8> {
+	[&twox_128(pallet_name)[..], &twox_128(b"MigrationInProgress")].concat()
+}
+
+/// Returns the storage key of a report.
+/// # Arguments
+/// * `pallet_name` - The name of the pallet within the runtime.
+/// * `query_id` - The query identifier of the reported value.
+/// * `timestamp` - The timestamp of the reported value.
+/// # Returns
+/// The storage key of the report within `Reports`.
+pub fn report_key(pallet_name: &[u8], query_id: QueryId, timestamp: Timestamp) -> Vec<u8> {
+	let timestamp = timestamp.encode();
+	[
+		&twox_128(pallet_name)[..],
+		&twox_128(b"Reports"),
+		query_id.as_bytes(),
+		&blake2_128(&timestamp),
+		&timestamp,
+	]
+	.concat()
+}
+
+/// Returns the storage key of the timestamp, reporter and dispute status of a reported value.
+/// # Arguments
+/// * `pallet_name` - The name of the pallet within the runtime.
+/// * `query_id` - The query identifier of the reported value.
+/// * `index` - The index of the reported value, as recorded in its report.
+/// # Returns
+/// The storage key of the indexed value within `ReportedValues`.
+pub fn indexed_value_key(pallet_name: &[u8], query_id: QueryId, index: u32) -> Vec<u8> {
+	index_key(pallet_name, b"ReportedValues", query_id, index)
+}
+
+/// Returns the storage key of a reported value.
+/// # Arguments
+/// * `pallet_name` - The name of the pallet within the runtime.
+/// * `query_id` - The query identifier of the reported value.
+/// * `index` - The index of the reported value, as recorded in its report.
+/// # Returns
+/// The storage key of the reported value within `ReportedValueData`.
+pub fn reported_value_key(pallet_name: &[u8], query_id: QueryId, index: u32) -> Vec<u8> {
+	index_key(pallet_name, b"ReportedValueData", query_id, index)
+}
+
+// Returns the storage key of an entry of a map keyed by query identifier and index.
+fn index_key(pallet_name: &[u8], storage_name: &[u8], query_id: QueryId, index: u32) -> Vec<u8> {
+	let index = index.encode();
+	[
+		&twox_128(pallet_name)[..],
+		&twox_128(storage_name),
+		query_id.as_bytes(),
+		&twox_64(&index),
+		&index,
+	]
+	.concat()
+}
+
+/// Returns the storage key of the timestamp of a reported value which has not yet been migrated to
+/// the current storage layout.
+/// # Arguments
+/// * `pallet_name` - The name of the pallet within the runtime.
+/// * `query_id` - The query identifier of the reported value.
+/// * `index` - The index of the reported value.
+/// # Returns
+/// The storage key of the timestamp within `ReportedTimestampsByIndex`.
+pub fn legacy_timestamp_key(pallet_name: &[u8], query_id: QueryId, index: u32) -> Vec<u8> {
+	let index = index.encode();
+	[
+		&twox_128(pallet_name)[..],
+		&twox_128(b"ReportedTimestampsByIndex"),
+		query_id.as_bytes(),
+		&blake2_128(&index),
+		&index,
+	]
+	.concat()
+}
+
+/// Returns the storage key of a reported value which has not yet been migrated to the current
+/// storage layout.
+/// # Arguments
+/// * `pallet_name` - The name of the pallet within the runtime.
+/// * `query_id` - The query identifier of the reported value.
+/// * `timestamp` - The timestamp of the reported value.
+/// # Returns
+/// The storage key of the reported value within `ReportedValuesByTimestamp`.
+pub fn legacy_reported_value_key(
+	pallet_name: &[u8],
+	query_id: QueryId,
+	timestamp: Timestamp,
+) -> Vec<u8> {
+	let timestamp = timestamp.encode();
+	[
+		&twox_128(pallet_name)[..],
+		&twox_128(b"ReportedValuesByTimestamp"),
+		query_id.as_bytes(),
+		&blake2_128(&timestamp),
+		&timestamp,
+	]
+	.concat()
+}
+
+/// Verifies that a reported value is the latest non-disputed value reported before a timestamp,
+/// against a state root.
+/// # Arguments
+/// * `state_root` - The state root of the block at which the proof was generated.
+/// * `proof` - The trie nodes of the storage proof.
+/// * `pallet_name` - The name of the pallet within the runtime.
+/// * `query_id` - The query identifier of the reported value.
+/// * `before` - The timestamp before which the latest value was requested.
+/// * `timestamp` - The timestamp of the reported value.
+/// # Returns
+/// The proven value.
+pub fn verify<AccountId: Decode, BlockNumber: Decode, H: Hasher>(
+	state_root: &H::Out,
+	proof: Vec<Vec<u8>>,
+	pallet_name: &[u8],
+	query_id: QueryId,
+	before: Timestamp,
+	timestamp: Timestamp,
+) -> Result<ProvenValue<AccountId>, ProofError> {
+	let db = StorageProof::new(proof).into_memory_db::<H>();
+	let read = |key: Vec<u8>| {
+		read_trie_value::<LayoutV1<H>, _>(&db, state_root, &key, None, None)
+			.map_err(|_| ProofError::InvalidProof)
+	};
+	// Values not yet migrated to the current storage layout are only read whilst migrating
+	let migrating =
+		decode::<bool>(read(migration_in_progress_key(pallet_name))?)?.unwrap_or_default();
+	// Reads the timestamp and dispute status of a reported value by index, in either storage layout
+	let indexed = |index: u32| -> Result<Option<(Timestamp, bool)>, ProofError> {
+		let indexed = read(indexed_value_key(pallet_name, query_id, index))?;
+		if let Some(indexed) = decode::<IndexedValue<AccountId>>(indexed)? {
+			return Ok(Some((indexed.timestamp, indexed.is_disputed)));
+		}
+		if !migrating {
+			return Ok(None);
+		}
+		let timestamp = read(legacy_timestamp_key(pallet_name, query_id, index))?;
+		let Some(timestamp) = decode::<Timestamp>(timestamp)? else {
+			return Ok(None);
+		};
+		let report = read(report_key(pallet_name, query_id, timestamp))?;
+		let report =
+			decode::<Report<AccountId, BlockNumber>>(report)?.ok_or(ProofError::InvalidValue)?;
+		Ok(Some((timestamp, report.is_disputed)))
+	};
+
+	let report = read(report_key(pallet_name, query_id, timestamp))?;
+	let report = decode::<Report<AccountId, BlockNumber>>(report)?
+		.filter(|report| !report.is_disputed && timestamp < before)
+		.ok_or(ProofError::NotLatest)?;
+	if indexed(report.index)?.map(|(indexed, _)| indexed) != Some(timestamp) {
+		return Err(ProofError::InvalidValue);
+	}
+	let value = match read(reported_value_key(pallet_name, query_id, report.index))? {
+		Some(value) => Some(value),
+		None if migrating => read(legacy_reported_value_key(pallet_name, query_id, timestamp))?,
+		None => None,
+	};
+	let value = decode::<Vec<u8>>(value)?.ok_or(ProofError::InvalidValue)?;
+
+	// Any values reported after the last reported value have been disputed, otherwise each value
+	// reported after the value and before the requested timestamp must have been disputed
+	let last_reported =
+		decode::<(u32, Timestamp)>(read(last_reported_key(pallet_name, query_id))?)?;
+	if last_reported.map(|(index, _)| index) != Some(report.index) {
+		let mut index = report.index;
+		while let Some(next) = index.checked_add(1) {
+			match indexed(next)? {
+				Some((reported, is_disputed)) if reported < before => {
+					if !is_disputed {
+						return Err(ProofError::NotLatest);
+					}
+				},
+				_ => break,
+			}
+			index = next;
+		}
+	}
+	Ok(ProvenValue { value, timestamp, reporter: report.reporter })
+}
+
+// Decodes a value read from a storage proof.
+fn decode<T: Decode>(value: Option<Vec<u8>>) -> Result<Option<T>, ProofError> {
+	value
+		.map(|value| T::decode(&mut &value[..]).map_err(|_| ProofError::InvalidValue))
+		.transpose()
+}
@@ -1238,6 +1238,88 @@ fn claim_onetime_tip() {
 	});
 }
 
+#[test]
+fn claim_onetime_tip_with_offset_timestamps() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let tipper = 1;
+	let reporter = 2;
+	let another_reporter = 3;
+	let third_reporter = 4;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let (earlier, later) = with_block(|| {
+			for reporter in [reporter, another_reporter, third_reporter] {
+				deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			}
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			// Reported within the same second, so timestamped ahead of the current time
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(another_reporter),
+				query_id,
+				uint_value(11),
+				0,
+				query_data.clone()
+			));
+			let later = now() + 1;
+			assert_eq!(Tellor::retrieve_data(query_id, later), Some(uint_value(11)));
+
+			// Tip is timestamped after the last reported value, which is therefore not eligible
+			Balances::set_balance(&tipper, token(100));
+			assert_ok!(Tellor::tip(
+				RuntimeOrigin::signed(tipper),
+				query_id,
+				token(1),
+				query_data.clone()
+			));
+			assert_eq!(Tips::get(query_id, 0).unwrap().timestamp, later + 1);
+			assert_eq!(Tellor::get_current_tip(query_id), token(1));
+			(now(), later)
+		});
+
+		let timestamp = with_block(|| {
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(third_reporter),
+				query_id,
+				uint_value(12),
+				0,
+				query_data.clone()
+			));
+			// Also timestamped ahead of the current time, after the tip
+			assert_eq!(now(), later);
+			assert_eq!(Tellor::retrieve_data(query_id, later + 1), Some(uint_value(12)));
+			assert_eq!(Tellor::get_current_tip(query_id), 0);
+			later + 1
+		});
+
+		with_block_after(86_400, || {
+			for (reporter, timestamp) in [(reporter, earlier), (another_reporter, later)] {
+				assert_noop!(
+					Tellor::claim_onetime_tip(
+						RuntimeOrigin::signed(reporter),
+						query_id,
+						bounded_vec![timestamp.into()]
+					),
+					Error::TimestampIneligibleForTip.with_weight(Weights::claim_onetime_tip(1))
+				);
+			}
+			assert_ok!(Tellor::claim_onetime_tip(
+				RuntimeOrigin::signed(third_reporter),
+				query_id,
+				bounded_vec![timestamp.into()]
+			));
+			assert_eq!(Tips::get(query_id, 0).unwrap().amount, 0);
+		});
+	});
+}
+
 #[test]
 fn get_data_feed() {
 	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
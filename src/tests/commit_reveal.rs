@@ -0,0 +1,388 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{constants::REPORTING_LOCK, CommitHash, Config, MINUTES};
+use codec::Encode;
+
+type Commits = crate::pallet::Commits<Test>;
+type RevealWindow = <Test as Config>::RevealWindow;
+type Weights = <Test as Config>::WeightInfo;
+
+fn commitment(
+	reporter: AccountIdOf<Test>,
+	query_id: QueryId,
+	value: &ValueOf<Test>,
+	salt: H256,
+) -> CommitHash {
+	keccak_256(&(reporter, query_id, value, salt).encode()).into()
+}
+
+fn enable_commit_reveal(query_id: QueryId) {
+	assert_ok!(Tellor::set_commit_reveal(Origin::Governance.into(), query_id, true));
+}
+
+#[test]
+fn set_commit_reveal() {
+	let query_id = H256::random();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_noop!(
+				Tellor::set_commit_reveal(RuntimeOrigin::signed(1), query_id, true),
+				BadOrigin
+			);
+
+			assert_ok!(Tellor::set_commit_reveal(Origin::Governance.into(), query_id, true));
+			System::assert_last_event(Event::CommitRevealSet { query_id, enabled: true }.into());
+			assert_noop!(
+				Tellor::submit_value(
+					RuntimeOrigin::signed(1),
+					query_id,
+					uint_value(100),
+					0,
+					Default::default()
+				),
+				Error::CommitRevealRequired
+			);
+
+			assert_ok!(Tellor::set_commit_reveal(Origin::Governance.into(), query_id, false));
+			System::assert_last_event(Event::CommitRevealSet { query_id, enabled: false }.into());
+			assert_noop!(
+				Tellor::commit_value(RuntimeOrigin::signed(1), query_id, H256::random()),
+				Error::CommitRevealNotEnabled
+			);
+		})
+	});
+}
+
+#[test]
+fn commit_value() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let hash = commitment(reporter, query_id, &uint_value(100), H256::random());
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			enable_commit_reveal(query_id);
+			assert_noop!(
+				Tellor::commit_value(RuntimeOrigin::signed(reporter), query_id, hash),
+				Error::InsufficientStake
+			);
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			deposit_stake(another_reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+
+			assert_ok!(Tellor::commit_value(RuntimeOrigin::signed(reporter), query_id, hash));
+			System::assert_last_event(Event::ValueCommitted { query_id, reporter, hash }.into());
+			// Reporting lock applies at time of commitment
+			assert_eq!(Tellor::get_reporter_last_timestamp(reporter), Some(now()));
+			assert_noop!(
+				Tellor::commit_value(RuntimeOrigin::signed(reporter), query_id, hash),
+				Error::ReporterTimeLocked
+			);
+			assert_ok!(Tellor::commit_value(
+				RuntimeOrigin::signed(another_reporter),
+				query_id,
+				hash
+			));
+
+			// Commitments are not reported values
+			assert_eq!(Tellor::get_new_value_count_by_query_id(query_id), 0);
+			let mut pending = Tellor::get_pending_commits(query_id);
+			pending.sort();
+			assert_eq!(pending, vec![(reporter, hash, now()), (another_reporter, hash, now())]);
+		})
+	});
+}
+
+#[test]
+fn reveal_value() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let value = uint_value(100);
+	let salt = H256::random();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let commit_timestamp = with_block(|| {
+			enable_commit_reveal(query_id);
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_noop!(
+				Tellor::reveal_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					value.clone(),
+					salt,
+					0,
+					query_data.clone()
+				),
+				Error::NoCommit
+			);
+			assert_ok!(Tellor::commit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				commitment(reporter, query_id, &value, salt)
+			));
+			assert_noop!(
+				Tellor::reveal_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					value.clone(),
+					salt,
+					0,
+					query_data.clone()
+				),
+				Error::RevealTooEarly
+			);
+			now()
+		});
+
+		let timestamp = with_block(|| {
+			assert_noop!(
+				Tellor::reveal_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					uint_value(101),
+					salt,
+					0,
+					query_data.clone()
+				),
+				Error::InvalidReveal
+			);
+			assert_noop!(
+				Tellor::reveal_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					value.clone(),
+					H256::random(),
+					0,
+					query_data.clone()
+				),
+				Error::InvalidReveal
+			);
+
+			assert_ok!(Tellor::reveal_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				value.clone(),
+				salt,
+				0,
+				query_data.clone()
+			));
+			System::assert_last_event(
+				Event::NewReport {
+					query_id,
+					time: now(),
+					value: value.clone(),
+					nonce: 0,
+					query_data: query_data.clone(),
+					reporter,
+				}
+				.into(),
+			);
+			assert_eq!(Tellor::retrieve_data(query_id, now()), Some(value.clone()));
+			assert_eq!(Tellor::get_reports_submitted_by_address(&reporter), 1);
+			assert!(Tellor::get_pending_commits(query_id).is_empty());
+			// Reporting lock remains from time of commitment
+			assert_eq!(Tellor::get_reporter_last_timestamp(reporter), Some(commit_timestamp));
+			now()
+		});
+		assert_ne!(timestamp, commit_timestamp);
+
+		let salt = H256::random();
+		with_block_after(REPORTING_LOCK, || {
+			assert_ok!(Tellor::commit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				commitment(reporter, query_id, &value, salt)
+			));
+		});
+
+		with_block_after(RevealWindow::get(), || {
+			assert_noop!(
+				Tellor::reveal_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					value.clone(),
+					salt,
+					0,
+					query_data.clone()
+				),
+				Error::RevealWindowExpired
+			);
+			// Expired commitment no longer pending
+			assert!(Tellor::get_pending_commits(query_id).is_empty());
+		});
+	});
+}
+
+#[test]
+fn reveal_requires_stake() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let value = uint_value(100);
+	let salt = H256::random();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			enable_commit_reveal(query_id);
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::commit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				commitment(reporter, query_id, &value, salt)
+			));
+			assert_ok!(Tellor::report_slash(
+				Origin::Staking.into(),
+				reporter,
+				MINIMUM_STAKE_AMOUNT.into()
+			));
+		});
+
+		with_block_after(MINUTES, || {
+			assert_noop!(
+				Tellor::reveal_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					value,
+					salt,
+					0,
+					query_data
+				),
+				Error::InsufficientStake
+			);
+		});
+	});
+}
+
+#[test]
+fn reveal_requires_own_commitment() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let copier = 2;
+	let value = uint_value(100);
+	let salt = H256::random();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			enable_commit_reveal(query_id);
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			deposit_stake(copier, MINIMUM_STAKE_AMOUNT, Address::random());
+			let hash = commitment(reporter, query_id, &value, salt);
+			assert_ok!(Tellor::commit_value(RuntimeOrigin::signed(reporter), query_id, hash));
+			// Copy commitment of another reporter
+			assert_ok!(Tellor::commit_value(RuntimeOrigin::signed(copier), query_id, hash));
+		});
+
+		with_block(|| {
+			assert_ok!(Tellor::reveal_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				value.clone(),
+				salt,
+				0,
+				query_data.clone()
+			));
+			// Copied commitment cannot be revealed, as bound to the committing reporter
+			assert_noop!(
+				Tellor::reveal_value(
+					RuntimeOrigin::signed(copier),
+					query_id,
+					value,
+					salt,
+					0,
+					query_data
+				),
+				Error::InvalidReveal
+			);
+		});
+	});
+}
+
+#[test]
+fn prunes_commits_which_can_no_longer_be_revealed() {
+	let query_id = H256::random();
+	let another_query_id = H256::random();
+	let reporter = 1;
+	let another_reporter = 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			enable_commit_reveal(query_id);
+			enable_commit_reveal(another_query_id);
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			deposit_stake(another_reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::commit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				H256::random()
+			));
+			assert_ok!(Tellor::commit_value(
+				RuntimeOrigin::signed(another_reporter),
+				another_query_id,
+				H256::random()
+			));
+		});
+
+		with_block(|| {
+			// Commitments within their reveal window are retained
+			assert_eq!(Tellor::prune_commits(now(), Weight::MAX), Weights::prune_commits(2));
+			assert!(Commits::contains_key(query_id, reporter));
+			assert!(Commits::contains_key(another_query_id, another_reporter));
+
+			// Commitments of query identifiers no longer using commit-reveal cannot be revealed
+			assert_ok!(Tellor::set_commit_reveal(
+				Origin::Governance.into(),
+				another_query_id,
+				false
+			));
+			assert_noop!(
+				Tellor::reveal_value(
+					RuntimeOrigin::signed(another_reporter),
+					another_query_id,
+					uint_value(100),
+					H256::random(),
+					0,
+					Default::default()
+				),
+				Error::CommitRevealNotEnabled
+			);
+			assert_eq!(Tellor::prune_commits(now(), Weight::MAX), Weights::prune_commits(2));
+			assert!(Commits::contains_key(query_id, reporter));
+			assert!(!Commits::contains_key(another_query_id, another_reporter));
+		});
+
+		with_block_after(RevealWindow::get() + 1, || {
+			// Pruning resumes from the last commitment inspected when weight runs out
+			assert_eq!(Tellor::prune_commits(now(), Weight::zero()), Weight::zero());
+			assert!(Commits::contains_key(query_id, reporter));
+
+			assert_eq!(Tellor::prune_commits(now(), Weight::MAX), Weights::prune_commits(1));
+			assert!(!Commits::contains_key(query_id, reporter));
+			assert!(Tellor::get_pending_commits(query_id).is_empty());
+		});
+	});
+}
@@ -0,0 +1,237 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+	commitments::{self, Commitment, Leaf, DIGEST_PREFIX},
+	constants::REPORTING_LOCK,
+	PendingCommitment,
+};
+use codec::Encode;
+use frame_support::traits::Hooks;
+use sp_runtime::DigestItem;
+
+#[test]
+fn commits_reported_values() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_eq!(Tellor::get_value_commitment(), None);
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			let timestamp = now();
+
+			let commitment = Tellor::get_value_commitment().unwrap();
+			assert_eq!(commitment.leaf_count, 1);
+			let (leaf, proof) = Tellor::get_value_proof(query_id, timestamp).unwrap();
+			assert_eq!(
+				leaf,
+				Leaf {
+					query_id,
+					timestamp,
+					value_hash: keccak_256(&uint_value(10)).into(),
+					disputed: false
+				}
+			);
+			assert!(commitments::verify(&commitment, &leaf, &proof));
+			assert_eq!(Tellor::get_value_proof(query_id, timestamp + 1), None);
+
+			// Commitment deposited as digest item when block finalized
+			Tellor::on_finalize(System::block_number());
+			assert_eq!(PendingCommitment::<Test>::get(), None);
+			assert_eq!(
+				System::digest().logs.last(),
+				Some(&DigestItem::Other((DIGEST_PREFIX, commitment).encode()))
+			);
+			assert_eq!(
+				System::digest().logs.last().and_then(Commitment::from_digest_item),
+				Some(commitment)
+			);
+		});
+
+		with_block(|| {
+			// No commitment deposited when no values reported or removed
+			let logs = System::digest().logs.len();
+			Tellor::on_finalize(System::block_number());
+			assert_eq!(System::digest().logs.len(), logs);
+		});
+	});
+}
+
+#[test]
+fn commits_removed_values() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let disputer = 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let (timestamp, commitment, proof) = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			let (_, proof) = Tellor::get_value_proof(query_id, now()).unwrap();
+			(now(), Tellor::get_value_commitment().unwrap(), proof)
+		});
+
+		with_block(|| {
+			deposit_stake(disputer, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&disputer, token(1_000));
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(disputer),
+				query_id,
+				timestamp,
+				None
+			));
+
+			// Removal updates the leaf of the value in place
+			let undisputed = Leaf {
+				query_id,
+				timestamp,
+				value_hash: keccak_256(&uint_value(10)).into(),
+				disputed: false,
+			};
+			let updated = Tellor::get_value_commitment().unwrap();
+			assert_eq!(updated.leaf_count, 1);
+			assert_ne!(updated.root, commitment.root);
+			assert_eq!(PendingCommitment::<Test>::get(), Some(updated));
+			let (leaf, updated_proof) = Tellor::get_value_proof(query_id, timestamp).unwrap();
+			assert_eq!(leaf, Leaf { disputed: true, ..undisputed.clone() });
+			assert_eq!(updated_proof.leaf_index, 0);
+			assert!(commitments::verify(&updated, &leaf, &updated_proof));
+
+			// The undisputed leaf can no longer be proven against the current commitment
+			assert!(!commitments::verify(&updated, &undisputed, &updated_proof));
+			assert!(!commitments::verify(&updated, &undisputed, &proof));
+			assert!(commitments::verify(&commitment, &undisputed, &proof));
+		});
+	});
+}
+
+#[test]
+fn verifies_proofs() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+		});
+		let timestamps: Vec<_> = (0..11)
+			.map(|i| {
+				with_block_after(REPORTING_LOCK, || {
+					assert_ok!(Tellor::submit_value(
+						RuntimeOrigin::signed(reporter),
+						query_id,
+						uint_value(i),
+						0,
+						query_data.clone()
+					));
+					now()
+				})
+			})
+			.collect();
+
+		let commitment = Tellor::get_value_commitment().unwrap();
+		assert_eq!(commitment.leaf_count, 11);
+		assert_eq!(commitments::peaks(11), vec![(3, 0), (1, 4), (0, 10)]);
+		for (i, timestamp) in timestamps.into_iter().enumerate() {
+			let (leaf, proof) = Tellor::get_value_proof(query_id, timestamp).unwrap();
+			assert_eq!(proof.leaf_index, i as u64);
+			assert_eq!(proof.peaks.len(), 3);
+			assert!(commitments::verify(&commitment, &leaf, &proof));
+
+			// Tampered leaves, proofs or commitments are rejected
+			let tampered = Leaf { value_hash: H256::random(), ..leaf.clone() };
+			assert!(!commitments::verify(&commitment, &tampered, &proof));
+			let mut tampered = proof.clone();
+			tampered.leaf_index = (tampered.leaf_index + 1) % 11;
+			assert!(!commitments::verify(&commitment, &leaf, &tampered));
+			let tampered = Commitment { leaf_count: 12, ..commitment };
+			assert!(!commitments::verify(&tampered, &leaf, &proof));
+		}
+	});
+}
+
+#[test]
+fn updates_leaves_within_range() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let disputer = 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			// Sufficient stake to continue reporting once slashed
+			deposit_stake(reporter, 2 * MINIMUM_STAKE_AMOUNT, Address::random());
+			deposit_stake(disputer, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&disputer, token(1_000));
+		});
+		let timestamps: Vec<_> = (0..11)
+			.map(|i| {
+				with_block_after(REPORTING_LOCK, || {
+					assert_ok!(Tellor::submit_value(
+						RuntimeOrigin::signed(reporter),
+						query_id,
+						uint_value(i),
+						0,
+						query_data.clone()
+					));
+					if i == 5 {
+						assert_ok!(Tellor::begin_dispute(
+							RuntimeOrigin::signed(disputer),
+							query_id,
+							now(),
+							None
+						));
+					}
+					now()
+				})
+			})
+			.collect();
+
+		// Leaves appended after an update are committed alongside it
+		let commitment = Tellor::get_value_commitment().unwrap();
+		assert_eq!(commitment.leaf_count, 11);
+		for (i, timestamp) in timestamps.into_iter().enumerate() {
+			let (leaf, proof) = Tellor::get_value_proof(query_id, timestamp).unwrap();
+			assert_eq!(proof.leaf_index, i as u64);
+			assert_eq!(leaf.disputed, i == 5);
+			assert!(commitments::verify(&commitment, &leaf, &proof));
+			let toggled = Leaf { disputed: !leaf.disputed, ..leaf };
+			assert!(!commitments::verify(&commitment, &toggled, &proof));
+		}
+	});
+}
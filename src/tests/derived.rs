@@ -0,0 +1,204 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+	constants::HOURS, DerivedFeed, DerivedOperation, ReadPolicy, ReadRejection, UsingTellor,
+};
+use codec::Encode;
+
+const READ_POLICY: ReadPolicy =
+	ReadPolicy { dispute_buffer: 0, max_age: HOURS, min_reporters: 1, max_open_disputes: 0 };
+
+fn unit() -> u128 {
+	10u128.pow(DECIMALS)
+}
+
+// Returns the derived ETH/DOT cross rate feed.
+fn eth_dot() -> DerivedFeed {
+	DerivedFeed {
+		query_id_a: keccak_256(&spot_price("eth", "usd")).into(),
+		decimals_a: 18,
+		operation: DerivedOperation::Divide,
+		query_id_b: keccak_256(&spot_price("dot", "usd")).into(),
+		decimals_b: 18,
+		decimals: 18,
+	}
+}
+
+fn get_derived_value(query_id: QueryId) -> Result<(Vec<u8>, Timestamp), ReadRejection> {
+	<Tellor as UsingTellor<AccountIdOf<Test>>>::get_derived_value(query_id, READ_POLICY)
+}
+
+fn submit(reporter: AccountIdOf<Test>, query: (&str, &str), value: u128) -> Timestamp {
+	let query_data: QueryDataOf<Test> = spot_price(query.0, query.1).try_into().unwrap();
+	assert_ok!(Tellor::submit_value(
+		RuntimeOrigin::signed(reporter),
+		keccak_256(query_data.as_ref()).into(),
+		uint_value(value),
+		0,
+		query_data
+	));
+	now()
+}
+
+#[test]
+fn set_derived_feed() {
+	let feed = eth_dot();
+	let query_id = feed.query_id();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_noop!(Tellor::set_derived_feed(RuntimeOrigin::signed(1), feed, true), BadOrigin);
+			assert_noop!(
+				Tellor::set_derived_feed(
+					Origin::Governance.into(),
+					DerivedFeed { query_id_b: feed.query_id_a, ..feed },
+					true
+				),
+				Error::InvalidDerivedFeed
+			);
+
+			assert_ok!(Tellor::set_derived_feed(Origin::Governance.into(), feed, true));
+			System::assert_last_event(
+				Event::DerivedFeedSet { query_id, feed, enabled: true }.into(),
+			);
+			assert_eq!(Tellor::get_derived_feed(query_id), Some(feed));
+
+			// Derived feeds cannot be operands
+			assert_noop!(
+				Tellor::set_derived_feed(
+					Origin::Governance.into(),
+					DerivedFeed { query_id_a: query_id, ..feed },
+					true
+				),
+				Error::InvalidDerivedFeed
+			);
+
+			// Values cannot be reported for the synthetic query identifier
+			let query_data: QueryDataOf<Test> =
+				(b"tellor::derived", feed).encode().try_into().unwrap();
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_noop!(
+				Tellor::submit_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					uint_value(10),
+					0,
+					query_data.clone()
+				),
+				Error::DerivedFeedQuery
+			);
+
+			assert_ok!(Tellor::set_derived_feed(Origin::Governance.into(), feed, false));
+			System::assert_last_event(
+				Event::DerivedFeedSet { query_id, feed, enabled: false }.into(),
+			);
+			assert_eq!(Tellor::get_derived_feed(query_id), None);
+
+			// Cannot be registered once values reported
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data
+			));
+			assert_noop!(
+				Tellor::set_derived_feed(Origin::Governance.into(), feed, true),
+				Error::ValuesAlreadyReported
+			);
+		});
+	});
+}
+
+#[test]
+fn derives_cross_rate() {
+	let feed = eth_dot();
+	let query_id = feed.query_id();
+	let reporter = 1;
+	let another_reporter = 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			assert_eq!(get_derived_value(query_id), Err(ReadRejection::NotDerivedFeed));
+			assert_ok!(Tellor::set_derived_feed(Origin::Governance.into(), feed, true));
+			assert_eq!(get_derived_value(query_id), Err(ReadRejection::NoValue));
+
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			submit(reporter, ("eth", "usd"), 2_000 * unit())
+		});
+
+		with_block(|| {
+			// Both operands must satisfy the read policy
+			assert_eq!(get_derived_value(query_id), Err(ReadRejection::NoValue));
+
+			deposit_stake(another_reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			submit(another_reporter, ("dot", "usd"), 5 * unit());
+		});
+
+		with_block(|| {
+			// Timestamp of the oldest operand
+			assert_eq!(
+				get_derived_value(query_id),
+				Ok((uint_value(400 * unit()).to_vec(), timestamp))
+			);
+		});
+
+		with_block_after(HOURS, || {
+			assert_eq!(get_derived_value(query_id), Err(ReadRejection::Stale { timestamp }));
+		});
+	});
+}
+
+#[test]
+fn derives_values() {
+	let a: QueryId = keccak_256(&spot_price("eth", "usd")).into();
+	let b: QueryId = keccak_256(&spot_price("dot", "usd")).into();
+	let feed = |operation| DerivedFeed {
+		query_id_a: a,
+		decimals_a: 18,
+		operation,
+		query_id_b: b,
+		decimals_b: 6,
+		decimals: 8,
+	};
+	let eth = U256::from(2_000) * U256::exp10(18);
+	let dot = U256::from(5) * U256::exp10(6);
+
+	assert_eq!(
+		feed(DerivedOperation::Add).derive(eth, dot),
+		Some(U256::from(2_005) * U256::exp10(8))
+	);
+	assert_eq!(
+		feed(DerivedOperation::Subtract).derive(eth, dot),
+		Some(U256::from(1_995) * U256::exp10(8))
+	);
+	assert_eq!(feed(DerivedOperation::Subtract).derive(dot, eth), None);
+	assert_eq!(
+		feed(DerivedOperation::Multiply).derive(eth, dot),
+		Some(U256::from(10_000) * U256::exp10(8))
+	);
+	assert_eq!(
+		feed(DerivedOperation::Divide).derive(eth, dot),
+		Some(U256::from(400) * U256::exp10(8))
+	);
+	assert_eq!(feed(DerivedOperation::Divide).derive(eth, U256::zero()), None);
+	assert_eq!(feed(DerivedOperation::Multiply).derive(U256::MAX, U256::MAX), None);
+}
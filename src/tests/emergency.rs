@@ -0,0 +1,243 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+	constants::HOURS, Config, EmergencyValue, HealthLimits, HealthThresholds, ReadPolicy,
+	ReadRejection, ReadValue, SafeValue, UsingTellor,
+};
+
+type MaxEmergencyValueDuration = <Test as Config>::MaxEmergencyValueDuration;
+
+const READ_POLICY: ReadPolicy =
+	ReadPolicy { dispute_buffer: 0, max_age: HOURS, min_reporters: 1, max_open_disputes: 0 };
+
+#[test]
+fn set_emergency_value() {
+	let query_id = H256::random();
+	let value = uint_value(10);
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_noop!(
+				Tellor::set_emergency_value(
+					RuntimeOrigin::signed(1),
+					query_id,
+					Some(value.clone()),
+					HOURS
+				),
+				BadOrigin
+			);
+			for duration in [0, MaxEmergencyValueDuration::get() + 1] {
+				assert_noop!(
+					Tellor::set_emergency_value(
+						Origin::Governance.into(),
+						query_id,
+						Some(value.clone()),
+						duration
+					),
+					Error::InvalidEmergencyValueDuration
+				);
+			}
+			assert_noop!(
+				Tellor::set_emergency_value(
+					Origin::Governance.into(),
+					query_id,
+					Some(Default::default()),
+					HOURS
+				),
+				Error::InvalidValue
+			);
+
+			assert_ok!(Tellor::set_emergency_value(
+				Origin::Governance.into(),
+				query_id,
+				Some(value.clone()),
+				HOURS
+			));
+			System::assert_last_event(
+				Event::EmergencyValueSet { query_id, value: value.clone(), expires: now() + HOURS }
+					.into(),
+			);
+			assert_eq!(
+				Tellor::get_emergency_value(query_id),
+				Some(EmergencyValue {
+					value: value.clone(),
+					timestamp: now(),
+					expires: now() + HOURS
+				})
+			);
+			// Emergency values are not reported values
+			assert_eq!(Tellor::get_new_value_count_by_query_id(query_id), 0);
+			assert_eq!(Tellor::get_data_before(query_id, now() + 1), None);
+
+			assert_ok!(Tellor::set_emergency_value(Origin::Governance.into(), query_id, None, 0));
+			System::assert_last_event(Event::EmergencyValueCleared { query_id }.into());
+			assert_eq!(Tellor::get_emergency_value(query_id), None);
+		});
+	});
+}
+
+#[test]
+fn emergency_value_expires() {
+	let query_id = H256::random();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_ok!(Tellor::set_emergency_value(
+				Origin::Governance.into(),
+				query_id,
+				Some(uint_value(10)),
+				HOURS
+			));
+		});
+
+		with_block_after(HOURS, || {
+			assert_eq!(Tellor::get_emergency_value(query_id), None);
+			assert_eq!(
+				Tellor::get_safe_value_or_emergency(query_id, READ_POLICY),
+				Err(ReadRejection::NoValue)
+			);
+		});
+	});
+}
+
+#[test]
+fn falls_back_to_emergency_value() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let published = with_block(|| {
+			assert_ok!(Tellor::set_emergency_value(
+				Origin::Governance.into(),
+				query_id,
+				Some(uint_value(1)),
+				MaxEmergencyValueDuration::get()
+			));
+			// No reported value, so emergency value used
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::get_safe_value_or_emergency(
+					query_id,
+					READ_POLICY
+				),
+				Ok(ReadValue::Emergency {
+					value: uint_value(1).to_vec(),
+					timestamp: now(),
+					expires: now() + MaxEmergencyValueDuration::get()
+				})
+			);
+			// Reads without opting in are unaffected
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::get_safe_value(query_id, READ_POLICY),
+				Err(ReadRejection::NoValue)
+			);
+			now()
+		});
+
+		let timestamp = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+
+		with_block(|| {
+			// Healthy reported value preferred
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::get_safe_value_or_emergency(
+					query_id,
+					READ_POLICY
+				),
+				Ok(ReadValue::Reported(SafeValue {
+					value: uint_value(10).to_vec(),
+					timestamp,
+					reporter
+				}))
+			);
+
+			// Emergency value used whilst query frozen
+			let limits = HealthLimits {
+				open_disputes: 0,
+				disputed_ratio: Default::default(),
+				stale_intervals: 0,
+				min_reporters: 2,
+			};
+			assert_ok!(Tellor::set_health_thresholds(
+				Origin::Governance.into(),
+				query_id,
+				Some(HealthThresholds { degraded: limits, frozen: limits })
+			));
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::get_safe_value_or_emergency(
+					query_id,
+					READ_POLICY
+				),
+				Ok(ReadValue::Emergency {
+					value: uint_value(1).to_vec(),
+					timestamp: published,
+					expires: published + MaxEmergencyValueDuration::get()
+				})
+			);
+
+			assert_ok!(Tellor::set_emergency_value(Origin::Governance.into(), query_id, None, 0));
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::get_safe_value_or_emergency(
+					query_id,
+					READ_POLICY
+				),
+				Err(ReadRejection::QueryFrozen)
+			);
+		});
+	});
+}
+
+#[test]
+fn emergency_values_cannot_be_disputed() {
+	let query_id = H256::random();
+	let disputer = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			assert_ok!(Tellor::set_emergency_value(
+				Origin::Governance.into(),
+				query_id,
+				Some(uint_value(10)),
+				HOURS
+			));
+			now()
+		});
+
+		with_block(|| {
+			deposit_stake(disputer, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&disputer, token(1_000));
+			assert_noop!(
+				Tellor::begin_dispute(RuntimeOrigin::signed(disputer), query_id, timestamp, None),
+				Error::NoValueExists.with_weight(Weights::begin_dispute(0))
+			);
+		});
+	});
+}
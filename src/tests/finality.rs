@@ -0,0 +1,336 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{constants::REPORTING_LOCK, LastFinalized, RiskClass, UsingTellor};
+use frame_support::traits::Hooks;
+
+#[test]
+fn finalizes_values() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+
+		with_block(|| {
+			// Not final whilst within dispute window
+			Tellor::on_initialize(System::block_number());
+			assert_eq!(LastFinalized::<Test>::get(query_id), None);
+			assert_eq!(Tellor::get_finalized_value_before(query_id, now()), None);
+			assert_eq!(Tellor::get_data_before(query_id, now()), Some((uint_value(10), timestamp)));
+		});
+
+		with_block_after(REPORTING_LOCK, || {
+			Tellor::on_initialize(System::block_number());
+			System::assert_last_event(Event::ValueFinalized { query_id, timestamp }.into());
+			assert_eq!(LastFinalized::<Test>::get(query_id), Some((0, timestamp)));
+			assert_eq!(
+				Tellor::get_finalized_value_before(query_id, now()),
+				Some((uint_value(10), timestamp))
+			);
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::get_finalized_value_before(
+					query_id,
+					now()
+				),
+				Some((uint_value(10).to_vec(), timestamp))
+			);
+			assert_eq!(Tellor::get_finalized_value_before(query_id, timestamp), None);
+
+			// Subsequent values are not final until their own dispute window has elapsed
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(11),
+				0,
+				query_data.clone()
+			));
+			assert_eq!(Tellor::get_data_before(query_id, now() + 1), Some((uint_value(11), now())));
+			assert_eq!(
+				Tellor::get_finalized_value_before(query_id, now() + 1),
+				Some((uint_value(10), timestamp))
+			);
+		});
+	});
+}
+
+#[test]
+fn disputed_values_are_not_finalized() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let disputer = 3;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let timestamps: Vec<_> = [reporter, another_reporter]
+			.into_iter()
+			.map(|reporter| {
+				with_block(|| {
+					deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+					assert_ok!(Tellor::submit_value(
+						RuntimeOrigin::signed(reporter),
+						query_id,
+						uint_value(10 + reporter),
+						0,
+						query_data.clone()
+					));
+					now()
+				})
+			})
+			.collect();
+
+		with_block(|| {
+			deposit_stake(disputer, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&disputer, token(1_000));
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(disputer),
+				query_id,
+				timestamps[1],
+				None
+			));
+		});
+
+		with_block_after(REPORTING_LOCK, || {
+			Tellor::on_initialize(System::block_number());
+			System::assert_has_event(
+				Event::ValueFinalized { query_id, timestamp: timestamps[0] }.into(),
+			);
+			assert_eq!(LastFinalized::<Test>::get(query_id), Some((0, timestamps[0])));
+			assert_eq!(
+				Tellor::get_finalized_value_before(query_id, now()),
+				Some((uint_value(11), timestamps[0]))
+			);
+			assert!(!crate::FinalityQueueRange::<Test>::contains_key(REPORTING_LOCK));
+		});
+	});
+}
+
+#[test]
+fn finalizes_values_after_risk_class_dispute_window() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			assert_ok!(Tellor::set_risk_class(
+				Origin::Governance.into(),
+				1,
+				Some(RiskClass { reporting_lock: 2 * REPORTING_LOCK, stake_multiple: 1 })
+			));
+			assert_ok!(Tellor::set_query_risk_class(Origin::Governance.into(), query_id, Some(1)));
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+
+		// Not final whilst within the dispute window of the risk class
+		with_block_after(REPORTING_LOCK, || {
+			Tellor::on_initialize(System::block_number());
+			assert_eq!(LastFinalized::<Test>::get(query_id), None);
+			assert_eq!(crate::FinalityQueueRange::<Test>::get(2 * REPORTING_LOCK), (0, 1));
+		});
+
+		with_block_after(REPORTING_LOCK, || {
+			Tellor::on_initialize(System::block_number());
+			System::assert_last_event(Event::ValueFinalized { query_id, timestamp }.into());
+			assert_eq!(LastFinalized::<Test>::get(query_id), Some((0, timestamp)));
+		});
+	});
+}
+
+#[test]
+fn longer_dispute_windows_do_not_delay_finality() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let another_query_data: QueryDataOf<Test> = spot_price("eth", "usd").try_into().unwrap();
+	let another_query_id = keccak_256(another_query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let (timestamp, another_timestamp) = with_block(|| {
+			assert_ok!(Tellor::set_risk_class(
+				Origin::Governance.into(),
+				1,
+				Some(RiskClass { reporting_lock: 2 * REPORTING_LOCK, stake_multiple: 1 })
+			));
+			assert_ok!(Tellor::set_query_risk_class(Origin::Governance.into(), query_id, Some(1)));
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			deposit_stake(another_reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(another_reporter),
+				another_query_id,
+				uint_value(20),
+				0,
+				another_query_data.clone()
+			));
+			(now(), now())
+		});
+
+		// Value reported after a value with a longer dispute window is finalized first
+		with_block_after(REPORTING_LOCK, || {
+			Tellor::on_initialize(System::block_number());
+			System::assert_last_event(
+				Event::ValueFinalized { query_id: another_query_id, timestamp: another_timestamp }
+					.into(),
+			);
+			assert_eq!(LastFinalized::<Test>::get(another_query_id), Some((0, another_timestamp)));
+			assert_eq!(LastFinalized::<Test>::get(query_id), None);
+			assert!(!crate::FinalityQueueRange::<Test>::contains_key(REPORTING_LOCK));
+		});
+
+		with_block_after(REPORTING_LOCK, || {
+			Tellor::on_initialize(System::block_number());
+			System::assert_last_event(Event::ValueFinalized { query_id, timestamp }.into());
+			assert_eq!(LastFinalized::<Test>::get(query_id), Some((0, timestamp)));
+			assert!(!crate::FinalityQueueRange::<Test>::contains_key(2 * REPORTING_LOCK));
+		});
+	});
+}
+
+#[test]
+fn shortened_reporting_lock_does_not_revert_finality() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_ok!(Tellor::set_risk_class(
+				Origin::Governance.into(),
+				1,
+				Some(RiskClass { reporting_lock: 2 * REPORTING_LOCK, stake_multiple: 1 })
+			));
+			assert_ok!(Tellor::set_query_risk_class(Origin::Governance.into(), query_id, Some(1)));
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			deposit_stake(another_reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+		});
+
+		let timestamp = with_block(|| {
+			assert_ok!(Tellor::set_query_risk_class(Origin::Governance.into(), query_id, None));
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(another_reporter),
+				query_id,
+				uint_value(20),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+
+		// Later value is finalized once the shortened dispute window has elapsed
+		with_block_after(REPORTING_LOCK, || {
+			Tellor::on_initialize(System::block_number());
+			System::assert_last_event(Event::ValueFinalized { query_id, timestamp }.into());
+			assert_eq!(LastFinalized::<Test>::get(query_id), Some((1, timestamp)));
+			assert_eq!(
+				Tellor::get_finalized_value_before(query_id, now()),
+				Some((uint_value(20), timestamp))
+			);
+		});
+
+		// Earlier value with the longer dispute window is already final
+		with_block_after(REPORTING_LOCK, || {
+			Tellor::on_initialize(System::block_number());
+			assert!(!System::events()
+				.iter()
+				.any(|r| matches!(r.event, RuntimeEvent::Tellor(Event::ValueFinalized { .. }))));
+			assert_eq!(LastFinalized::<Test>::get(query_id), Some((1, timestamp)));
+			assert!(crate::FinalityQueueRange::<Test>::iter().next().is_none());
+		});
+	});
+}
+
+#[test]
+fn finalized_values_cannot_be_disputed() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let disputer = 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+
+		with_block_after(REPORTING_LOCK, || {
+			Tellor::on_initialize(System::block_number());
+			assert_eq!(LastFinalized::<Test>::get(query_id), Some((0, timestamp)));
+
+			// Extending the reporting lock does not reopen the dispute window of a finalized value
+			assert_ok!(Tellor::set_risk_class(
+				Origin::Governance.into(),
+				1,
+				Some(RiskClass { reporting_lock: 2 * REPORTING_LOCK, stake_multiple: 1 })
+			));
+			assert_ok!(Tellor::set_query_risk_class(Origin::Governance.into(), query_id, Some(1)));
+			Balances::set_balance(&disputer, token(1_000));
+			assert_noop!(
+				Tellor::begin_dispute(RuntimeOrigin::signed(disputer), query_id, timestamp, None),
+				Error::DisputeReportingPeriodExpired
+			);
+		});
+	});
+}
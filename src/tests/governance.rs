@@ -832,6 +832,65 @@ fn tally_votes() {
 	});
 }
 
+#[test]
+fn dispute_calls_on_value_removed() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let (dispute_id, timestamp) = with_block(|| {
+			assert_ok!(Tellor::report_stake_deposited(
+				Origin::Staking.into(),
+				reporter,
+				MINIMUM_STAKE_AMOUNT.into(),
+				Address::random()
+			));
+			Balances::set_balance(&reporter, token(1_000));
+			let dispute_id = submit_value_and_begin_dispute(reporter, query_id, query_data.clone());
+			assert_eq!(
+				hook_calls(),
+				vec![
+					HookCall::NewReport(query_id, now(), reporter),
+					HookCall::ValueRemoved(query_id, now())
+				]
+			);
+			(dispute_id, now())
+		});
+
+		with_block_after(DAYS, || {
+			let post_info = Tellor::report_vote_tallied(
+				Origin::Governance.into(),
+				dispute_id,
+				VoteResult::Passed,
+			)
+			.unwrap();
+			assert_eq!(
+				post_info.actual_weight,
+				Some(Weights::report_vote_tallied().saturating_add(TestHooks::WEIGHT))
+			);
+			assert_eq!(
+				hook_calls(),
+				vec![HookCall::VoteTallied(query_id, timestamp, VoteResult::Passed)]
+			);
+		});
+
+		with_block_after(DAYS, || {
+			let post_info =
+				Tellor::report_vote_executed(Origin::Governance.into(), dispute_id).unwrap();
+			assert_eq!(
+				post_info.actual_weight,
+				Some(Weights::report_vote_executed(1).saturating_add(TestHooks::WEIGHT))
+			);
+			assert_eq!(
+				hook_calls(),
+				vec![HookCall::VoteExecuted(query_id, timestamp, VoteResult::Passed)]
+			);
+		});
+	});
+}
+
 #[test]
 fn vote() {
 	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
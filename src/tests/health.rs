@@ -0,0 +1,334 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+	constants::{HEALTH_WINDOW, HOURS, MAX_HEALTH_FEEDS},
+	HealthLimits, HealthThresholds, QueryHealth, UsingTellor,
+};
+use frame_support::traits::Hooks;
+use sp_core::bounded_vec;
+use sp_runtime::Percent;
+
+type DataFeeds = crate::pallet::DataFeeds<Test>;
+type FundedFeedIntervals = crate::pallet::FundedFeedIntervals<Test>;
+
+fn thresholds() -> HealthThresholds {
+	HealthThresholds {
+		degraded: HealthLimits {
+			open_disputes: 1,
+			disputed_ratio: Percent::from_percent(25),
+			stale_intervals: 2,
+			min_reporters: 2,
+		},
+		frozen: HealthLimits {
+			open_disputes: 2,
+			disputed_ratio: Percent::from_percent(50),
+			stale_intervals: 4,
+			min_reporters: 1,
+		},
+	}
+}
+
+#[test]
+fn set_health_thresholds() {
+	let query_id = H256::random();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_noop!(
+				Tellor::set_health_thresholds(
+					RuntimeOrigin::signed(1),
+					query_id,
+					Some(thresholds())
+				),
+				BadOrigin
+			);
+			let mut invalid = thresholds();
+			invalid.frozen.min_reporters = HEALTH_WINDOW + 1;
+			assert_noop!(
+				Tellor::set_health_thresholds(Origin::Governance.into(), query_id, Some(invalid)),
+				Error::InvalidHealthThresholds
+			);
+			assert_eq!(Tellor::get_query_health(query_id), QueryHealth::Healthy);
+
+			// Frozen as no values reported
+			assert_ok!(Tellor::set_health_thresholds(
+				Origin::Governance.into(),
+				query_id,
+				Some(thresholds())
+			));
+			System::assert_has_event(
+				Event::QueryHealthThresholdsSet { query_id, thresholds: Some(thresholds()) }.into(),
+			);
+			System::assert_last_event(
+				Event::QueryHealthChanged { query_id, health: QueryHealth::Frozen }.into(),
+			);
+			assert_eq!(Tellor::get_query_health(query_id), QueryHealth::Frozen);
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::get_query_health(query_id),
+				QueryHealth::Frozen
+			);
+
+			assert_ok!(Tellor::set_health_thresholds(Origin::Governance.into(), query_id, None));
+			System::assert_has_event(
+				Event::QueryHealthThresholdsSet { query_id, thresholds: None }.into(),
+			);
+			System::assert_last_event(
+				Event::QueryHealthChanged { query_id, health: QueryHealth::Healthy }.into(),
+			);
+			assert_eq!(Tellor::get_query_health(query_id), QueryHealth::Healthy);
+			assert!(!crate::QueryHealthStates::<Test>::contains_key(query_id));
+		});
+	});
+}
+
+#[test]
+fn health_driven_by_disputes() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporters = [1, 2, 3, 4];
+	let disputer = 5;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_ok!(Tellor::set_health_thresholds(
+				Origin::Governance.into(),
+				query_id,
+				Some(thresholds())
+			));
+		});
+		let timestamps: Vec<_> = reporters
+			.into_iter()
+			.map(|reporter| {
+				with_block(|| {
+					deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+					assert_ok!(Tellor::submit_value(
+						RuntimeOrigin::signed(reporter),
+						query_id,
+						uint_value(10),
+						0,
+						query_data.clone()
+					));
+					now()
+				})
+			})
+			.collect();
+
+		with_block(|| {
+			Tellor::on_initialize(System::block_number());
+			System::assert_has_event(
+				Event::QueryHealthChanged { query_id, health: QueryHealth::Healthy }.into(),
+			);
+
+			deposit_stake(disputer, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&disputer, token(1_000));
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(disputer),
+				query_id,
+				timestamps[3],
+				None
+			));
+			// Health is assessed when read, whereas transitions are recorded when checked
+			assert_eq!(Tellor::get_query_health(query_id), QueryHealth::Degraded);
+		});
+
+		with_block(|| {
+			Tellor::on_initialize(System::block_number());
+			System::assert_has_event(
+				Event::QueryHealthChanged { query_id, health: QueryHealth::Degraded }.into(),
+			);
+
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(disputer),
+				query_id,
+				timestamps[2],
+				None
+			));
+			assert_eq!(Tellor::get_query_health(query_id), QueryHealth::Frozen);
+		});
+
+		with_block(|| {
+			Tellor::on_initialize(System::block_number());
+			System::assert_has_event(
+				Event::QueryHealthChanged { query_id, health: QueryHealth::Frozen }.into(),
+			);
+		});
+	});
+}
+
+#[test]
+fn health_driven_by_staleness() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let feed_creator = 3;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			Balances::set_balance(&feed_creator, token(1_000));
+			// Unfunded feeds are disregarded, however many are inspected before the funded feed
+			for i in 1..=10 * MAX_HEALTH_FEEDS {
+				assert_ok!(Tellor::setup_data_feed(
+					RuntimeOrigin::signed(feed_creator),
+					query_id,
+					token(1),
+					now(),
+					HOURS + u64::from(i),
+					600,
+					0,
+					0,
+					query_data.clone(),
+					0
+				));
+			}
+			assert_ok!(Tellor::setup_data_feed(
+				RuntimeOrigin::signed(feed_creator),
+				query_id,
+				token(1),
+				now(),
+				HOURS,
+				600,
+				0,
+				0,
+				query_data.clone(),
+				token(10)
+			));
+			assert_ok!(Tellor::set_health_thresholds(
+				Origin::Governance.into(),
+				query_id,
+				Some(thresholds())
+			));
+
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			// Degraded whilst too few distinct reporters
+			assert_eq!(Tellor::get_query_health(query_id), QueryHealth::Degraded);
+		});
+
+		with_block(|| {
+			deposit_stake(another_reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(another_reporter),
+				query_id,
+				uint_value(11),
+				0,
+				query_data.clone()
+			));
+			assert_eq!(Tellor::get_query_health(query_id), QueryHealth::Healthy);
+		});
+
+		with_block_after(2 * HOURS, || {
+			Tellor::on_initialize(System::block_number());
+			System::assert_has_event(
+				Event::QueryHealthChanged { query_id, health: QueryHealth::Degraded }.into(),
+			);
+			assert_eq!(Tellor::get_query_health(query_id), QueryHealth::Degraded);
+		});
+
+		with_block_after(2 * HOURS, || {
+			Tellor::on_initialize(System::block_number());
+			System::assert_has_event(
+				Event::QueryHealthChanged { query_id, health: QueryHealth::Frozen }.into(),
+			);
+			assert_eq!(Tellor::get_query_health(query_id), QueryHealth::Frozen);
+		});
+	});
+}
+
+#[test]
+fn tracks_shortest_funded_feed_intervals() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let feed_creator = 2;
+	let mut ext = new_test_ext();
+
+	let setup_data_feed = |interval: Timestamp, amount: BalanceOf<Test>| {
+		assert_ok!(Tellor::setup_data_feed(
+			RuntimeOrigin::signed(feed_creator),
+			query_id,
+			token(1),
+			now(),
+			interval,
+			600,
+			0,
+			0,
+			query_data.clone(),
+			amount
+		));
+	};
+	let intervals = || {
+		FundedFeedIntervals::get(query_id)
+			.into_iter()
+			.map(|(i, _)| i)
+			.collect::<Vec<_>>()
+	};
+
+	ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			Balances::set_balance(&feed_creator, token(1_000));
+			// Unfunded feeds are disregarded
+			setup_data_feed(HOURS + 1, 0);
+			assert!(intervals().is_empty());
+			for i in 2..=MAX_HEALTH_FEEDS + 2 {
+				setup_data_feed(HOURS + u64::from(i), token(1));
+			}
+			// Only the shortest intervals are retained
+			assert_eq!(
+				intervals(),
+				(2..=MAX_HEALTH_FEEDS + 1).map(|i| HOURS + u64::from(i)).collect::<Vec<_>>()
+			);
+			setup_data_feed(HOURS, token(1));
+			assert_eq!(intervals()[0], HOURS);
+			assert_eq!(intervals().len(), MAX_HEALTH_FEEDS as usize);
+
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+
+		with_block_after(12 * HOURS, || {
+			// Feeds drained by claims are no longer funded
+			let (feed_id, _) = DataFeeds::iter_prefix(query_id)
+				.find(|(_, feed)| feed.interval == HOURS)
+				.unwrap();
+			assert_ok!(Tellor::claim_tip(
+				RuntimeOrigin::signed(reporter),
+				feed_id,
+				query_id,
+				bounded_vec![timestamp.into()]
+			));
+			assert_eq!(intervals()[0], HOURS + 2);
+		});
+	});
+}
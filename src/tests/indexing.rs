@@ -0,0 +1,130 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+	constants::{MIN_RETENTION_PERIOD, REPORTING_LOCK},
+	indexing::report_key,
+	ArchivedReport,
+};
+use codec::Decode;
+use frame_support::traits::Hooks;
+use sp_core::offchain::{storage::OffchainDb, DbExternalities, StorageKind};
+
+type ArchivedReportOf = ArchivedReport<AccountIdOf<Test>, ValueOf<Test>>;
+
+// Returns the archived report from the offchain storage of the externalities.
+fn archived_report(
+	ext: &mut sp_io::TestExternalities,
+	query_id: QueryId,
+	index: u32,
+) -> Option<ArchivedReportOf> {
+	ext.persist_offchain_overlay();
+	OffchainDb::new(ext.offchain_db())
+		.local_storage_get(StorageKind::PERSISTENT, &report_key(query_id, index))
+		.map(|encoded| ArchivedReportOf::decode(&mut encoded.as_slice()).unwrap())
+}
+
+#[test]
+fn archives_reports() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	let timestamp = ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&reporter, token(1_000));
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			now()
+		})
+	});
+	assert_eq!(
+		archived_report(&mut ext, query_id, 0),
+		Some(ArchivedReport { timestamp, value: uint_value(10), reporter, is_disputed: false })
+	);
+	assert_eq!(archived_report(&mut ext, query_id, 1), None);
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				timestamp,
+				None
+			));
+			assert_eq!(Tellor::retrieve_data(query_id, timestamp), None);
+		})
+	});
+	// Removed value remains archived, flagged as disputed
+	assert_eq!(
+		archived_report(&mut ext, query_id, 0),
+		Some(ArchivedReport { timestamp, value: uint_value(10), reporter, is_disputed: true })
+	);
+}
+
+#[test]
+fn archived_reports_outlive_pruning() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	let timestamp = ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::set_retention_period(
+				Origin::Governance.into(),
+				query_id,
+				Some(MIN_RETENTION_PERIOD)
+			));
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+		with_block_after(REPORTING_LOCK, || {
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(11),
+				1,
+				query_data.clone()
+			));
+		});
+		with_block_after(MIN_RETENTION_PERIOD, || {
+			Tellor::on_idle(System::block_number(), Weight::MAX);
+			assert_eq!(Tellor::get_first_retained_index(query_id), 1);
+			assert_eq!(Tellor::retrieve_data(query_id, timestamp), None);
+		});
+		timestamp
+	});
+	assert_eq!(
+		archived_report(&mut ext, query_id, 0),
+		Some(ArchivedReport { timestamp, value: uint_value(10), reporter, is_disputed: false })
+	);
+}
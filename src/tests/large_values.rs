@@ -0,0 +1,313 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+	constants::REPORTING_LOCK, indexing::preimage_key, types::LargeValueOf, Config, UsingTellor,
+	ValueReference,
+};
+use codec::Encode;
+use frame_support::traits::fungible::Inspect;
+use sp_core::offchain::{storage::OffchainDb, DbExternalities, StorageKind};
+use sp_runtime::TokenError;
+
+type MaxLargeValueLength = <Test as Config>::MaxLargeValueLength;
+type MaxValueLength = <Test as Config>::MaxValueLength;
+type PreimageBaseDeposit = <Test as Config>::PreimageBaseDeposit;
+type PreimageByteDeposit = <Test as Config>::PreimageByteDeposit;
+
+// Returns the deposit held for noting the supplied value.
+fn deposit(value: &[u8]) -> BalanceOf<Test> {
+	PreimageBaseDeposit::get() + PreimageByteDeposit::get() * value.len() as BalanceOf<Test>
+}
+
+fn large_value(byte: u8) -> LargeValueOf<Test> {
+	vec![byte; MaxLargeValueLength::get() as usize].try_into().unwrap()
+}
+
+// Returns the reported value referencing the supplied value.
+fn reference(value: &[u8]) -> ValueOf<Test> {
+	ValueReference { hash: keccak_256(value).into(), len: value.len() as u32 }
+		.encode()
+		.try_into()
+		.unwrap()
+}
+
+#[test]
+fn set_large_values() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_noop!(
+				Tellor::set_large_values(RuntimeOrigin::signed(1), query_id, true),
+				BadOrigin
+			);
+
+			assert_ok!(Tellor::set_large_values(Origin::Governance.into(), query_id, true));
+			System::assert_last_event(Event::LargeValuesSet { query_id, enabled: true }.into());
+			assert!(crate::LargeValueQueries::<Test>::contains_key(query_id));
+
+			assert_ok!(Tellor::set_large_values(Origin::Governance.into(), query_id, false));
+			System::assert_last_event(Event::LargeValuesSet { query_id, enabled: false }.into());
+			assert!(!crate::LargeValueQueries::<Test>::contains_key(query_id));
+		});
+
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			// Cannot be changed once values reported
+			assert_noop!(
+				Tellor::set_large_values(Origin::Governance.into(), query_id, true),
+				Error::ValuesAlreadyReported
+			);
+		});
+	});
+}
+
+#[test]
+fn note_value() {
+	let reporter = 1;
+	let value = large_value(1);
+	let hash = keccak_256(value.as_ref()).into();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_noop!(
+				Tellor::note_value(RuntimeOrigin::signed(reporter), value.clone()),
+				Error::InsufficientStake
+			);
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_noop!(
+				Tellor::note_value(RuntimeOrigin::signed(reporter), Default::default()),
+				Error::InvalidValue
+			);
+			assert_noop!(
+				Tellor::note_value(RuntimeOrigin::signed(reporter), value.clone()),
+				TokenError::FundsUnavailable
+			);
+
+			Balances::set_balance(&reporter, token(10));
+			assert_ok!(Tellor::note_value(RuntimeOrigin::signed(reporter), value.clone()));
+			System::assert_last_event(Event::ValueNoted { hash, reporter }.into());
+			assert_eq!(Tellor::get_value_preimage(hash), Some(value.to_vec()));
+			// Deposit proportional to the length of the value is held
+			assert_eq!(Balances::balance(&reporter), token(10) - deposit(&value));
+			assert_eq!(Balances::balance(&Tellor::preimage_deposits()), deposit(&value));
+
+			assert_noop!(
+				Tellor::note_value(RuntimeOrigin::signed(reporter), value.clone()),
+				Error::PreimageAlreadyNoted
+			);
+		});
+	});
+
+	// Noted value is archived offchain
+	ext.persist_offchain_overlay();
+	assert_eq!(
+		OffchainDb::new(ext.offchain_db())
+			.local_storage_get(StorageKind::PERSISTENT, &preimage_key(hash)),
+		Some(value.to_vec())
+	);
+}
+
+#[test]
+fn unnote_value() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let value = large_value(1);
+	let hash = keccak_256(value.as_ref()).into();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&reporter, token(10));
+			assert_ok!(Tellor::set_large_values(Origin::Governance.into(), query_id, true));
+			assert_noop!(
+				Tellor::unnote_value(RuntimeOrigin::signed(reporter), hash),
+				Error::PreimageNotNoted
+			);
+			assert_ok!(Tellor::note_value(RuntimeOrigin::signed(reporter), value.clone()));
+			assert_noop!(
+				Tellor::unnote_value(RuntimeOrigin::signed(another_reporter), hash),
+				Error::NotPreimageDepositor
+			);
+
+			// Cannot be removed whilst referenced
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				reference(&value),
+				0,
+				query_data.clone()
+			));
+			assert_noop!(
+				Tellor::unnote_value(RuntimeOrigin::signed(reporter), hash),
+				Error::PreimageReferenced
+			);
+		});
+
+		with_block(|| {
+			// Unreferenced values can be removed by the reporter which noted them, returning the
+			// deposit
+			let value = large_value(2);
+			let hash = keccak_256(value.as_ref()).into();
+			assert_ok!(Tellor::note_value(RuntimeOrigin::signed(reporter), value.clone()));
+			assert_ok!(Tellor::unnote_value(RuntimeOrigin::signed(reporter), hash));
+			System::assert_last_event(Event::ValueUnnoted { hash, reporter }.into());
+			assert_eq!(Tellor::get_value_preimage(hash), None);
+			assert!(!crate::Preimages::<Test>::contains_key(hash));
+			assert_eq!(Balances::balance(&reporter), token(10) - deposit(&large_value(1)));
+		});
+	});
+}
+
+#[test]
+fn submits_value_references() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let value = large_value(1);
+	assert!(value.len() > MaxValueLength::get() as usize);
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&reporter, token(10));
+			assert_ok!(Tellor::set_large_values(Origin::Governance.into(), query_id, true));
+			assert_noop!(
+				Tellor::submit_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					reference(&value),
+					0,
+					query_data.clone()
+				),
+				Error::PreimageNotNoted
+			);
+			assert_ok!(Tellor::note_value(RuntimeOrigin::signed(reporter), value.clone()));
+
+			// Reported values must be a reference of the correct length
+			assert_noop!(
+				Tellor::submit_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					uint_value(10),
+					0,
+					query_data.clone()
+				),
+				Error::InvalidValue
+			);
+			let mut invalid = ValueReference { hash: keccak_256(value.as_ref()).into(), len: 1 };
+			assert_noop!(
+				Tellor::submit_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					invalid.encode().try_into().unwrap(),
+					0,
+					query_data.clone()
+				),
+				Error::InvalidValue
+			);
+			invalid.len = value.len() as u32;
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				invalid.encode().try_into().unwrap(),
+				0,
+				query_data.clone()
+			));
+			let timestamp = now();
+
+			// Reports carry the reference, whereas UsingTellor resolves the noted value
+			assert_eq!(Tellor::retrieve_data(query_id, timestamp), Some(reference(&value)));
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::retrieve_data(query_id, timestamp),
+				Some(value.to_vec())
+			);
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::get_data_before(
+					query_id,
+					timestamp + 1
+				),
+				Some((value.to_vec(), timestamp))
+			);
+		});
+	});
+}
+
+#[test]
+fn releases_unreferenced_values() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let value = large_value(1);
+	let hash = keccak_256(value.as_ref()).into();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&reporter, token(10));
+			assert_ok!(Tellor::set_large_values(Origin::Governance.into(), query_id, true));
+			assert_ok!(Tellor::note_value(RuntimeOrigin::signed(reporter), value.clone()));
+		});
+		let timestamps: Vec<_> = (0..2)
+			.map(|_| {
+				with_block_after(REPORTING_LOCK, || {
+					assert_ok!(Tellor::submit_value(
+						RuntimeOrigin::signed(reporter),
+						query_id,
+						reference(&value),
+						0,
+						query_data.clone()
+					));
+					now()
+				})
+			})
+			.collect();
+
+		with_block(|| {
+			// Value retained whilst referenced by a reported value
+			assert_ok!(Tellor::remove_value(query_id, timestamps[1]));
+			assert_eq!(Tellor::get_value_preimage(hash), Some(value.to_vec()));
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::retrieve_data(query_id, timestamps[0]),
+				Some(value.to_vec())
+			);
+
+			assert_ok!(Tellor::remove_value(query_id, timestamps[0]));
+			assert_eq!(Tellor::get_value_preimage(hash), None);
+			assert!(!crate::Preimages::<Test>::contains_key(hash));
+			// Deposit returned once no longer referenced
+			assert_eq!(Balances::balance(&reporter), token(10));
+		});
+	});
+}
@@ -0,0 +1,199 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+	constants::REPORTING_LOCK,
+	migrations::v1::{self, v0, MigrateToV1},
+	UsingTellor,
+};
+use frame_support::traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion};
+
+type LastReported = crate::LastReported<Test>;
+type MigrationInProgress = crate::MigrationInProgress<Test>;
+type ReportedValueData = crate::ReportedValueData<Test>;
+type ReportedValues = crate::ReportedValues<Test>;
+
+// Reverts the reported values of a query identifier to the storage layout prior to version 1.
+fn revert_to_v0(query_id: QueryId) {
+	for (index, reported) in ReportedValues::drain_prefix(query_id) {
+		v0::ReportedTimestampsByIndex::<Test>::insert(query_id, index, reported.timestamp);
+		if let Some(value) = ReportedValueData::take(query_id, index) {
+			v0::ReportedValuesByTimestamp::<Test>::insert(query_id, reported.timestamp, value);
+		}
+	}
+	if let Some((_, timestamp)) = LastReported::take(query_id) {
+		v0::LastReportedTimestamp::<Test>::insert(query_id, timestamp);
+	}
+	StorageVersion::new(0).put::<Tellor>();
+}
+
+#[test]
+fn migrates_to_v1() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random()));
+
+		// Report values, removing the second
+		let mut timestamps = Vec::new();
+		for value in 1..=3 {
+			timestamps.push(with_block_after(REPORTING_LOCK, || {
+				assert_ok!(Tellor::submit_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					uint_value(value * 100),
+					0,
+					query_data.clone()
+				));
+				now()
+			}));
+		}
+		assert_ok!(Tellor::remove_value(query_id, timestamps[1]));
+		revert_to_v0(query_id);
+
+		with_block(|| {
+			MigrateToV1::<Test>::on_runtime_upgrade();
+			assert_eq!(Tellor::on_chain_storage_version(), 1);
+			assert!(MigrationInProgress::get());
+
+			// Values yet to be migrated are read from the previous storage layout
+			assert_eq!(ReportedValues::iter_prefix(query_id).count(), 0);
+			assert_eq!(ReportedValueData::iter_prefix(query_id).count(), 0);
+			assert_eq!(Tellor::get_current_value(query_id), Some(uint_value(300)));
+			assert_eq!(Tellor::retrieve_data(query_id, timestamps[0]), Some(uint_value(100)));
+			assert_eq!(Tellor::retrieve_data(query_id, timestamps[1]), None);
+			assert_eq!(
+				Tellor::get_timestamp_by_query_id_and_index(query_id, 1),
+				Some(timestamps[1])
+			);
+			assert_eq!(
+				Tellor::get_data_before(query_id, timestamps[2]),
+				Some((uint_value(100), timestamps[0]))
+			);
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::get_data_after(query_id, timestamps[0]),
+				Some((uint_value(300).to_vec(), timestamps[2]))
+			);
+		});
+
+		// Values reported during the migration take precedence
+		let timestamp = with_block_after(REPORTING_LOCK, || {
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(400),
+				0,
+				query_data.clone()
+			));
+			assert_eq!(Tellor::get_current_value(query_id), Some(uint_value(400)));
+			assert_eq!(v0::LastReportedTimestamp::<Test>::get(query_id), None);
+			now()
+		});
+
+		with_block(|| {
+			assert_eq!(v1::migrate::<Test>(2), 2);
+			assert!(MigrationInProgress::get());
+			assert_eq!(v0::ReportedTimestampsByIndex::<Test>::iter_prefix(query_id).count(), 1);
+
+			Tellor::on_initialize(System::block_number());
+			assert!(!MigrationInProgress::get());
+			assert_eq!(v0::ReportedTimestampsByIndex::<Test>::iter().count(), 0);
+			assert_eq!(v0::ReportedValuesByTimestamp::<Test>::iter().count(), 0);
+			assert_eq!(v0::LastReportedTimestamp::<Test>::iter().count(), 0);
+
+			// Values are read from the current storage layout once migrated
+			assert_eq!(ReportedValues::iter_prefix(query_id).count(), 4);
+			assert!(ReportedValues::get(query_id, 1).unwrap().is_disputed);
+			// Values of disputed reports are not retained
+			assert_eq!(ReportedValueData::iter_prefix(query_id).count(), 3);
+			assert_eq!(ReportedValueData::get(query_id, 1), None);
+			assert_eq!(ReportedValues::get(query_id, 1).unwrap().previous, Some(0));
+			assert_eq!(ReportedValues::get(query_id, 2).unwrap().reporter, reporter);
+			assert_eq!(LastReported::get(query_id), Some((3, timestamp)));
+			assert_eq!(Tellor::get_current_value(query_id), Some(uint_value(400)));
+			assert_eq!(Tellor::retrieve_data(query_id, timestamps[0]), Some(uint_value(100)));
+			assert_eq!(Tellor::retrieve_data(query_id, timestamps[1]), None);
+			assert_eq!(
+				Tellor::get_data_before(query_id, timestamps[2]),
+				Some((uint_value(100), timestamps[0]))
+			);
+			assert_eq!(
+				Tellor::get_multiple_values_before(query_id, timestamp, timestamp, 4),
+				vec![
+					(uint_value(100).to_vec(), timestamps[0]),
+					(uint_value(300).to_vec(), timestamps[2]),
+					(uint_value(400).to_vec(), timestamp)
+				]
+			);
+
+			// Migration only runs once
+			MigrateToV1::<Test>::on_runtime_upgrade();
+			assert!(!MigrationInProgress::get());
+		});
+	});
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn checks_migration_to_v1() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random()));
+
+		// Report values, removing the second
+		let mut timestamps = Vec::new();
+		for value in 1..=3 {
+			timestamps.push(with_block_after(REPORTING_LOCK, || {
+				assert_ok!(Tellor::submit_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					uint_value(value * 100),
+					0,
+					query_data.clone()
+				));
+				now()
+			}));
+		}
+		assert_ok!(Tellor::remove_value(query_id, timestamps[1]));
+		revert_to_v0(query_id);
+
+		with_block(|| {
+			let state = MigrateToV1::<Test>::pre_upgrade().unwrap();
+			MigrateToV1::<Test>::on_runtime_upgrade();
+			assert_ok!(MigrateToV1::<Test>::post_upgrade(state.clone()));
+			// Migration completed by the checks is discarded, so that it continues over subsequent
+			// blocks
+			assert!(MigrationInProgress::get());
+			assert_eq!(ReportedValues::iter_prefix(query_id).count(), 0);
+			assert_eq!(v0::ReportedTimestampsByIndex::<Test>::iter_prefix(query_id).count(), 3);
+
+			// Values which would not be migrated as sampled are detected
+			v0::ReportedValuesByTimestamp::<Test>::insert(query_id, timestamps[0], uint_value(1));
+			assert_eq!(
+				MigrateToV1::<Test>::post_upgrade(state),
+				Err("reported value data mismatch")
+			);
+		});
+	});
+}
@@ -44,9 +44,28 @@ use std::convert::Into;
 use xcm::{latest::prelude::*, DoubleEncoded};
 
 mod autopay;
+mod commit_reveal;
+mod commitments;
+mod derived;
+mod emergency;
+mod finality;
 mod governance;
+mod health;
+mod indexing;
+mod large_values;
+mod migrations;
+#[cfg(feature = "offchain-worker")]
+mod offchain;
 mod oracle;
+mod proofs;
+mod queries;
+mod requests;
+mod retention;
+mod retraction;
+mod subscriptions;
+mod unsigned;
 mod using_tellor;
+mod value_cache;
 mod weights;
 
 type Balance = <Test as crate::Config>::Balance;
@@ -245,10 +264,12 @@ fn registers() {
 				report_stake_withdrawn:
 					<Test as crate::Config>::WeightInfo::report_stake_withdrawn().ref_time(),
 				report_vote_tallied: <Test as crate::Config>::WeightInfo::report_vote_tallied()
+					.saturating_add(TestHooks::WEIGHT)
 					.ref_time(),
 				report_vote_executed: <Test as crate::Config>::WeightInfo::report_vote_executed(
 					MAX_VOTE_ROUNDS.into(),
 				)
+				.saturating_add(TestHooks::WEIGHT)
 				.ref_time(),
 				report_slash: <Test as crate::Config>::WeightInfo::report_slash().ref_time(),
 			};
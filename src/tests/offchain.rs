@@ -0,0 +1,191 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+	constants::REPORTING_LOCK,
+	offchain::{QuerySources, Source, KEY_TYPE, REPORTER_KEY, SOURCES_KEY},
+	Pallet, RiskClass, MINUTES,
+};
+use codec::Decode;
+use frame_support::{assert_ok, traits::Hooks};
+use sp_core::offchain::{
+	testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+	OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+use sp_runtime::{offchain::storage::StorageValueRef, traits::IdentifyAccount};
+
+const DOT_USD: &[u8] = b"http://localhost:8000/dot-usd";
+const DOT_USDT: &[u8] = b"http://localhost:8000/dot-usdt";
+
+fn fixed(value: u128, decimals: u32) -> U256 {
+	U256::from(value) * U256::from(10).pow((18 - decimals).into())
+}
+
+fn request(uri: &[u8], response: &[u8]) -> PendingRequest {
+	PendingRequest {
+		method: "GET".into(),
+		uri: String::from_utf8(uri.to_vec()).unwrap(),
+		response: Some(response.to_vec()),
+		sent: true,
+		..Default::default()
+	}
+}
+
+fn sources() -> Vec<Source> {
+	vec![
+		Source { url: DOT_USD.to_vec(), path: vec![b"polkadot".to_vec(), b"usd".to_vec()] },
+		Source {
+			url: DOT_USDT.to_vec(),
+			path: vec![b"data".to_vec(), b"0".to_vec(), b"price".to_vec()],
+		},
+	]
+}
+
+#[test]
+fn parses_values() {
+	let path = |keys: &[&str]| keys.iter().map(|k| k.as_bytes().to_vec()).collect::<Vec<_>>();
+	assert_eq!(
+		Pallet::<Test>::parse_value(r#"{"polkadot":{"usd":6.12}}"#, &path(&["polkadot", "usd"])),
+		Some(fixed(612, 2))
+	);
+	assert_eq!(
+		Pallet::<Test>::parse_value(
+			r#"{"data":[{"price":"6.14"}]}"#,
+			&path(&["data", "0", "price"])
+		),
+		Some(fixed(614, 2))
+	);
+	assert_eq!(Pallet::<Test>::parse_value("1.5e3", &[]), Some(fixed(1_500, 0)));
+	assert_eq!(Pallet::<Test>::parse_value("-1", &[]), None);
+	assert_eq!(Pallet::<Test>::parse_value(r#"{"usd":6.12}"#, &path(&["eur"])), None);
+	assert_eq!(Pallet::<Test>::parse_value(r#"{"usd":"n/a"}"#, &path(&["usd"])), None);
+	assert_eq!(Pallet::<Test>::parse_value("invalid", &[]), None);
+}
+
+#[test]
+fn median() {
+	assert_eq!(Pallet::<Test>::median(vec![]), None);
+	assert_eq!(Pallet::<Test>::median(vec![3.into()]), Some(3.into()));
+	assert_eq!(Pallet::<Test>::median(vec![5.into(), 1.into(), 3.into()]), Some(3.into()));
+	assert_eq!(
+		Pallet::<Test>::median(vec![4.into(), 1.into(), 2.into(), 8.into()]),
+		Some(3.into())
+	);
+}
+
+#[test]
+fn offchain_worker() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id: QueryId = keccak_256(query_data.as_ref()).into();
+	let (offchain, state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let keystore = MemoryKeystore::new();
+	let public = keystore.sr25519_generate_new(KEY_TYPE, None).unwrap();
+	let reporter = OffchainPublic(public).into_account();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(KeystoreExt::new(keystore));
+
+	ext.execute_with(|| {
+		with_block(|| {
+			// Nothing reported until configured
+			Tellor::offchain_worker(System::block_number());
+			assert!(pool_state.read().transactions.is_empty());
+
+			StorageValueRef::persistent(REPORTER_KEY).set(&OffchainPublic(public));
+			StorageValueRef::persistent(SOURCES_KEY)
+				.set(&vec![QuerySources { query_data: query_data.to_vec(), sources: sources() }]);
+			// Reporter must be staked
+			Tellor::offchain_worker(System::block_number());
+			assert!(pool_state.read().transactions.is_empty());
+
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			state.write().expect_request(request(DOT_USD, br#"{"polkadot":{"usd":6.12}}"#));
+			state
+				.write()
+				.expect_request(request(DOT_USDT, br#"{"data":[{"price":"6.14"}]}"#));
+			Tellor::offchain_worker(System::block_number());
+
+			let tx = pool_state.write().transactions.pop().unwrap();
+			assert!(pool_state.read().transactions.is_empty());
+			let tx = Extrinsic::decode(&mut &*tx).unwrap();
+			assert_eq!(tx.signature.unwrap().0, 0);
+			let mut value = [0u8; 32];
+			fixed(613, 2).to_big_endian(&mut value);
+			assert_eq!(
+				tx.call,
+				RuntimeCall::Tellor(crate::Call::submit_value {
+					query_id,
+					value: value.to_vec().try_into().unwrap(),
+					nonce: 0,
+					query_data: query_data.clone()
+				})
+			);
+
+			// Pending submission is not resubmitted
+			Tellor::offchain_worker(System::block_number());
+			assert!(pool_state.read().transactions.is_empty());
+		});
+
+		// Submission which was not included is assumed rejected, so is retried
+		with_block_after(5 * MINUTES, || {
+			state.write().expect_request(request(DOT_USD, br#"{"polkadot":{"usd":6.12}}"#));
+			state
+				.write()
+				.expect_request(request(DOT_USDT, br#"{"data":[{"price":"6.14"}]}"#));
+			Tellor::offchain_worker(System::block_number());
+			let tx = pool_state.write().transactions.pop().unwrap();
+			let tx = Extrinsic::decode(&mut &*tx).unwrap();
+			assert!(matches!(
+				tx.call,
+				RuntimeCall::Tellor(crate::Call::submit_value { query_id: q, .. }) if q == query_id
+			));
+		});
+
+		with_block_after(REPORTING_LOCK, || {
+			// Failed sources are ignored
+			state.write().expect_request(request(DOT_USD, b"{}"));
+			state
+				.write()
+				.expect_request(request(DOT_USDT, br#"{"data":[{"price":"6.14"}]}"#));
+			Tellor::offchain_worker(System::block_number());
+			let tx = pool_state.write().transactions.pop().unwrap();
+			let tx = Extrinsic::decode(&mut &*tx).unwrap();
+			let mut value = [0u8; 32];
+			fixed(614, 2).to_big_endian(&mut value);
+			assert!(matches!(
+				tx.call,
+				RuntimeCall::Tellor(crate::Call::submit_value { value: v, .. }) if v.to_vec() == value.to_vec()
+			));
+		});
+
+		with_block_after(REPORTING_LOCK, || {
+			// Reporting lock and stake requirement of the risk class are respected
+			assert_ok!(Tellor::set_risk_class(
+				Origin::Governance.into(),
+				1,
+				Some(RiskClass { reporting_lock: REPORTING_LOCK, stake_multiple: 2 })
+			));
+			assert_ok!(Tellor::set_query_risk_class(Origin::Governance.into(), query_id, Some(1)));
+			Tellor::offchain_worker(System::block_number());
+			assert!(pool_state.read().transactions.is_empty());
+		});
+	});
+}
@@ -18,23 +18,29 @@ use super::*;
 use crate::{
 	constants::REPORTING_LOCK,
 	types::{Nonce, QueryId, Timestamp},
-	Config, VoteResult,
+	CheckValueSubmission, Config, RiskClass, VoteResult,
+};
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	dispatch::{DispatchResult, Pays},
+	pallet_prelude::InvalidTransaction,
+	traits::Hooks,
 };
-use frame_support::{assert_err, assert_noop, assert_ok, dispatch::DispatchResult, traits::Hooks};
 use sp_core::{bounded_vec, Get, U256};
 use sp_runtime::{
-	traits::{BadOrigin, Convert},
+	traits::{BadOrigin, Convert, SignedExtension},
 	Saturating,
 };
 use sp_std::num::NonZeroU32;
 use std::time::Instant;
 
 type InitialDisputeFee = <Test as Config>::InitialDisputeFee;
-type LastReportedTimestamp = crate::LastReportedTimestamp<Test>;
+type LastReported = crate::LastReported<Test>;
 type MaxDisputedTimeSeries = <Test as Config>::MaxDisputedTimeSeries;
+type MaxReportTimestampOffset = <Test as Config>::MaxReportTimestampOffset;
 type Reports = crate::Reports<Test>;
 type ReportedTimestampCount = crate::ReportedTimestampCount<Test>;
-type ReportedTimestampsByIndex = crate::ReportedTimestampsByIndex<Test>;
+type ReportedValues = crate::ReportedValues<Test>;
 type StakeAmountCurrencyTarget = <Test as Config>::StakeAmountCurrencyTarget;
 type StakerReportsSubmittedByQueryId = crate::StakerReportsSubmittedByQueryId<Test>;
 
@@ -144,7 +150,7 @@ fn remove_value() {
 				MINIMUM_STAKE_AMOUNT.into(),
 				address
 			));
-			assert_eq!(LastReportedTimestamp::get(query_id), None);
+			assert_eq!(LastReported::get(query_id).map(|(_, t)| t), None);
 			assert_ok!(Tellor::submit_value(
 				RuntimeOrigin::signed(reporter),
 				query_id,
@@ -152,7 +158,7 @@ fn remove_value() {
 				0,
 				query_data.clone(),
 			));
-			assert_eq!(LastReportedTimestamp::get(query_id), Some(timestamp));
+			assert_eq!(LastReported::get(query_id).map(|(_, t)| t), Some(timestamp));
 
 			assert_eq!(Tellor::get_new_value_count_by_query_id(query_id), 1);
 			assert_noop!(Tellor::remove_value(query_id, 500), Error::InvalidTimestamp);
@@ -170,7 +176,7 @@ fn remove_value() {
 			assert_eq!(Tellor::get_new_value_count_by_query_id(query_id), 1);
 			assert_eq!(Tellor::retrieve_data(query_id, timestamp), None);
 			assert!(Reports::get(query_id, timestamp).unwrap().is_disputed);
-			assert_eq!(LastReportedTimestamp::get(query_id), None);
+			assert_eq!(LastReported::get(query_id).map(|(_, t)| t), None);
 			assert!(Tellor::is_in_dispute(query_id, timestamp));
 			assert_noop!(Tellor::remove_value(query_id, timestamp), Error::ValueDisputed);
 
@@ -190,7 +196,7 @@ fn remove_value() {
 
 			// Remove index to ensure verified upon value removal
 			let timestamp = now();
-			ReportedTimestampsByIndex::remove(
+			ReportedValues::remove(
 				query_id,
 				Tellor::get_timestamp_index_by_timestamp(query_id, timestamp).unwrap(),
 			);
@@ -236,7 +242,7 @@ fn remove_from_time_series(size: u32, disputes: impl IntoIterator<Item = usize>)
 	new_test_ext().execute_with(|| -> DispatchResult {
 		with_block(|| super::deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random()));
 
-		assert_eq!(LastReportedTimestamp::get(query_id), None);
+		assert_eq!(LastReported::get(query_id).map(|(_, t)| t), None);
 
 		fn print(timestamps: &Vec<Option<Timestamp>>) {
 			println!(
@@ -263,7 +269,10 @@ fn remove_from_time_series(size: u32, disputes: impl IntoIterator<Item = usize>)
 					query_data.clone(),
 				));
 				timestamps.push(Some(now()));
-				assert_eq!(LastReportedTimestamp::get(query_id), *timestamps.last().unwrap());
+				assert_eq!(
+					LastReported::get(query_id).map(|(_, t)| t),
+					*timestamps.last().unwrap()
+				);
 			});
 		}
 		print(&timestamps);
@@ -293,7 +302,7 @@ fn remove_from_time_series(size: u32, disputes: impl IntoIterator<Item = usize>)
 		// Follow linked timestamps using Report.previous, starting from last reported
 		if timestamps.len() > 0 {
 			let mut i = timestamps.len() - 1;
-			let mut current = LastReportedTimestamp::get(query_id);
+			let mut current = LastReported::get(query_id).map(|(_, t)| t);
 			while let Some(timestamp) = current {
 				assert_eq!(timestamp, timestamps[i]);
 				let report = Reports::get(query_id, timestamp).unwrap();
@@ -306,12 +315,13 @@ fn remove_from_time_series(size: u32, disputes: impl IntoIterator<Item = usize>)
 		// Verify every item's previous value remains valid
 		print!("[");
 		for i in 0..size {
-			let timestamp = ReportedTimestampsByIndex::get(query_id, i).unwrap();
+			let timestamp = Tellor::get_timestamp_by_query_id_and_index(query_id, i).unwrap();
 			let report = Reports::get(query_id, timestamp).unwrap();
 			if let Some(previous) = report.previous {
 				assert_eq!(Reports::get(query_id, previous).unwrap().is_disputed, false)
 			} else if i != 0 {
-				let timestamp = ReportedTimestampsByIndex::get(query_id, i - 1).unwrap();
+				let timestamp =
+					Tellor::get_timestamp_by_query_id_and_index(query_id, i - 1).unwrap();
 				assert_eq!(Reports::get(query_id, timestamp).unwrap().is_disputed, true);
 			}
 			print!(
@@ -322,7 +332,7 @@ fn remove_from_time_series(size: u32, disputes: impl IntoIterator<Item = usize>)
 		}
 		println!("]");
 
-		assert_eq!(LastReportedTimestamp::get(query_id), timestamps.last().copied());
+		assert_eq!(LastReported::get(query_id).map(|(_, t)| t), timestamps.last().copied());
 		println!();
 		Ok(())
 	})
@@ -690,7 +700,7 @@ fn submit_value() {
 				),
 				Error::InvalidQueryId
 			);
-			assert_eq!(LastReportedTimestamp::get(query_id), None);
+			assert_eq!(LastReported::get(query_id).map(|(_, t)| t), None);
 			assert_ok!(Tellor::submit_value(
 				RuntimeOrigin::signed(reporter),
 				query_id,
@@ -699,7 +709,7 @@ fn submit_value() {
 				query_data.clone()
 			));
 			let timestamp = now();
-			assert_eq!(LastReportedTimestamp::get(query_id).unwrap(), timestamp);
+			assert_eq!(LastReported::get(query_id).map(|(_, t)| t).unwrap(), timestamp);
 			assert_noop!(
 				Tellor::submit_value(
 					RuntimeOrigin::signed(reporter),
@@ -802,6 +812,485 @@ fn submit_value() {
 	});
 }
 
+#[test]
+fn submit_value_calls_on_new_report() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_ok!(Tellor::report_stake_deposited(
+				Origin::Staking.into(),
+				reporter,
+				trb(100),
+				Address::random()
+			));
+			assert_noop!(
+				Tellor::submit_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					uint_value(4_000),
+					1,
+					query_data.clone()
+				),
+				Error::InvalidNonce
+			);
+			assert!(hook_calls().is_empty());
+
+			let value = uint_value(4_000);
+			let weight = <Test as Config>::WeightInfo::submit_value(
+				query_data.len() as u32,
+				value.len() as u32,
+			);
+			let post_info = Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				value,
+				0,
+				query_data.clone(),
+			)
+			.unwrap();
+			assert_eq!(post_info.actual_weight, Some(weight.saturating_add(TestHooks::WEIGHT)));
+			assert_eq!(hook_calls(), vec![HookCall::NewReport(query_id, now(), reporter)]);
+		})
+	});
+}
+
+#[test]
+fn submit_value_feeless() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			let post_info = Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(100),
+				0,
+				query_data.clone(),
+			)
+			.unwrap();
+			assert_eq!(post_info.pays_fee, Pays::Yes);
+		});
+
+		with_block_after(REPORTING_LOCK, || {
+			FeelessSubmissions::set(&true);
+			let post_info = Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(101),
+				1,
+				query_data.clone(),
+			)
+			.unwrap();
+			assert_eq!(post_info.pays_fee, Pays::No);
+		});
+	});
+}
+
+#[test]
+fn submit_value_within_same_second() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let last_reporter = MaxReportTimestampOffset::get() as AccountId + 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			for reporter in 1..=last_reporter {
+				deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			}
+			let now = now();
+
+			// Reports within the same second are assigned the next unreported second, up to the offset
+			for (offset, reporter) in (1..last_reporter).enumerate() {
+				assert_ok!(Tellor::submit_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					uint_value(reporter),
+					0,
+					query_data.clone()
+				));
+				let timestamp = now + offset as u64;
+				System::assert_last_event(
+					Event::NewReport {
+						query_id,
+						time: timestamp,
+						value: uint_value(reporter),
+						nonce: 0,
+						query_data: query_data.clone(),
+						reporter,
+					}
+					.into(),
+				);
+				assert_eq!(Tellor::retrieve_data(query_id, timestamp), Some(uint_value(reporter)));
+				// Reporting lock based on the current time
+				assert_eq!(Tellor::get_reporter_last_timestamp(reporter), Some(now));
+			}
+			assert_noop!(
+				Tellor::submit_value(
+					RuntimeOrigin::signed(last_reporter),
+					query_id,
+					uint_value(0),
+					0,
+					query_data.clone()
+				),
+				Error::ReportTimestampOffsetExceeded
+			);
+			assert_eq!(
+				Tellor::get_new_value_count_by_query_id(query_id),
+				MaxReportTimestampOffset::get() as u32 + 1
+			);
+
+			// Reports ahead of the current time can be disputed
+			let timestamp = now + MaxReportTimestampOffset::get();
+			Balances::set_balance(&last_reporter, token(1_000));
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(last_reporter),
+				query_id,
+				timestamp,
+				None
+			));
+			assert_eq!(Tellor::retrieve_data(query_id, timestamp), None);
+		});
+
+		// Reports are then timestamped at the current time again
+		with_block_after(MaxReportTimestampOffset::get(), || {
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(last_reporter),
+				query_id,
+				uint_value(0),
+				0,
+				query_data.clone()
+			));
+			assert_eq!(Tellor::retrieve_data(query_id, now()), Some(uint_value(0)));
+		});
+	});
+}
+
+#[test]
+fn check_value_submission() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let submit_value = |value: ValueOf<Test>, nonce: Nonce, query_data: QueryDataOf<Test>| {
+		RuntimeCall::from(crate::Call::submit_value { query_id, value, nonce, query_data })
+	};
+	let validate = |who: AccountIdOf<Test>, call: RuntimeCall| {
+		CheckValueSubmission::<Test>::new().validate(&who, &call, &Default::default(), 0)
+	};
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_eq!(
+				validate(reporter, submit_value(uint_value(100), 0, query_data.clone())),
+				Err(InvalidTransaction::BadSigner.into())
+			);
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			deposit_stake(another_reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+
+			assert_eq!(
+				validate(reporter, submit_value(uint_value(100), 0, query_data.clone())),
+				Ok(Default::default())
+			);
+			assert_eq!(
+				validate(reporter, submit_value(uint_value(100), 1, query_data.clone())),
+				Err(InvalidTransaction::Stale.into())
+			);
+			assert_eq!(
+				validate(reporter, submit_value(uint_value(100), 0, Default::default())),
+				Err(InvalidTransaction::Call.into())
+			);
+			// Other calls are not checked
+			assert_eq!(
+				validate(reporter, crate::Call::update_stake_amount {}.into()),
+				Ok(Default::default())
+			);
+
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(100),
+				0,
+				query_data.clone()
+			));
+			// Reporter time locked
+			assert_eq!(
+				validate(reporter, submit_value(uint_value(101), 1, query_data.clone())),
+				Err(InvalidTransaction::Future.into())
+			);
+			assert_eq!(
+				CheckValueSubmission::<Test>::new().pre_dispatch(
+					&reporter,
+					&submit_value(uint_value(101), 1, query_data.clone()),
+					&Default::default(),
+					0
+				),
+				Err(InvalidTransaction::Future.into())
+			);
+			// Timestamp already reported
+			assert_eq!(
+				validate(another_reporter, submit_value(uint_value(101), 1, query_data.clone())),
+				Err(InvalidTransaction::Call.into())
+			);
+		})
+	});
+}
+
+#[test]
+fn submit_values() {
+	let dot_query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let dot_query_id: QueryId = keccak_256(dot_query_data.as_ref()).into();
+	let ksm_query_data: QueryDataOf<Test> = spot_price("ksm", "usd").try_into().unwrap();
+	let ksm_query_id: QueryId = keccak_256(ksm_query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_noop!(
+				Tellor::submit_values(RuntimeOrigin::signed(reporter), bounded_vec![]),
+				Error::InvalidValue
+			);
+			assert_noop!(
+				Tellor::submit_values(
+					RuntimeOrigin::signed(2),
+					bounded_vec![(dot_query_id, uint_value(5), 0, dot_query_data.clone())]
+				),
+				Error::InsufficientStake
+			);
+
+			let submissions = bounded_vec![
+				(dot_query_id, uint_value(5), 0, dot_query_data.clone()),
+				(ksm_query_id, uint_value(30), 1, ksm_query_data.clone()),
+				(H256::random(), uint_value(1), 0, ksm_query_data.clone()),
+				(ksm_query_id, uint_value(31), 0, ksm_query_data.clone()),
+			];
+			let weight = <Test as Config>::WeightInfo::submit_values(
+				4,
+				ksm_query_data.len() as u32,
+				uint_value(1).len() as u32,
+			);
+			let post_info =
+				Tellor::submit_values(RuntimeOrigin::signed(reporter), submissions).unwrap();
+			// Only accepted values incur hook weight
+			assert_eq!(
+				post_info.actual_weight,
+				Some(weight.saturating_add(TestHooks::WEIGHT.saturating_mul(2)))
+			);
+
+			let timestamp = now();
+			assert_eq!(Tellor::retrieve_data(dot_query_id, timestamp), Some(uint_value(5)));
+			assert_eq!(Tellor::retrieve_data(ksm_query_id, timestamp), Some(uint_value(31)));
+			assert_eq!(Tellor::get_reports_submitted_by_address(&reporter), 2);
+			assert_eq!(Tellor::get_reporter_last_timestamp(reporter), Some(timestamp));
+			assert_eq!(
+				System::events()
+					.into_iter()
+					.filter_map(|e| match e.event {
+						RuntimeEvent::Tellor(Event::NewReport { query_id, .. }) => Some(query_id),
+						RuntimeEvent::Tellor(Event::SubmissionFailed {
+							index,
+							query_id,
+							error,
+							..
+						}) => {
+							assert_eq!(
+								error,
+								match index {
+									1 => Error::InvalidNonce.into(),
+									2 => Error::InvalidQueryId.into(),
+									_ => unreachable!(),
+								}
+							);
+							None
+						},
+						_ => None,
+					})
+					.collect::<Vec<_>>(),
+				vec![dot_query_id, ksm_query_id]
+			);
+
+			// Reporting lock applies once per batch
+			assert_noop!(
+				Tellor::submit_values(
+					RuntimeOrigin::signed(reporter),
+					bounded_vec![(dot_query_id, uint_value(6), 0, dot_query_data.clone())]
+				),
+				Error::ReporterTimeLocked
+			);
+		});
+
+		with_block_after(REPORTING_LOCK, || {
+			// Lock is not consumed when no values are accepted
+			assert_ok!(Tellor::submit_values(
+				RuntimeOrigin::signed(reporter),
+				bounded_vec![(dot_query_id, uint_value(6), 5, dot_query_data.clone())]
+			));
+			assert_ok!(Tellor::submit_values(
+				RuntimeOrigin::signed(reporter),
+				bounded_vec![(dot_query_id, uint_value(6), 1, dot_query_data.clone())]
+			));
+			assert_eq!(Tellor::get_reporter_last_timestamp(reporter), Some(now()));
+		});
+	});
+}
+
+#[test]
+fn risk_classes() {
+	let query_data: QueryDataOf<Test> = spot_price("eth", "usd").try_into().unwrap();
+	let query_id: QueryId = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let risk_class = RiskClass { reporting_lock: 2 * REPORTING_LOCK, stake_multiple: 2 };
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_noop!(
+				Tellor::set_risk_class(RuntimeOrigin::signed(reporter), 1, Some(risk_class)),
+				BadOrigin
+			);
+			assert_noop!(
+				Tellor::set_risk_class(
+					Origin::Governance.into(),
+					1,
+					Some(RiskClass { reporting_lock: REPORTING_LOCK, stake_multiple: 0 })
+				),
+				Error::InvalidRiskClass
+			);
+			assert_noop!(
+				Tellor::set_query_risk_class(Origin::Governance.into(), query_id, Some(1)),
+				Error::InvalidRiskClass
+			);
+			assert_ok!(Tellor::set_risk_class(Origin::Governance.into(), 1, Some(risk_class)));
+			System::assert_last_event(
+				Event::RiskClassSet { class_id: 1, risk_class: Some(risk_class) }.into(),
+			);
+			assert_ok!(Tellor::set_query_risk_class(Origin::Governance.into(), query_id, Some(1)));
+			System::assert_last_event(
+				Event::QueryRiskClassSet { query_id, class_id: Some(1) }.into(),
+			);
+			assert_eq!(Tellor::get_reporting_lock(query_id), 2 * REPORTING_LOCK);
+			assert_eq!(Tellor::get_risk_class(H256::random()), RiskClass::default());
+
+			// Stake requirement is a multiple of the stake amount
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			deposit_stake(another_reporter, 2 * MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_noop!(
+				Tellor::submit_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					uint_value(4_000),
+					0,
+					query_data.clone()
+				),
+				Error::InsufficientStake
+			);
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(another_reporter),
+				query_id,
+				uint_value(4_000),
+				0,
+				query_data.clone()
+			));
+		});
+
+		// Reporting lock is that of the risk class
+		with_block_after(REPORTING_LOCK, || {
+			assert_noop!(
+				Tellor::submit_value(
+					RuntimeOrigin::signed(another_reporter),
+					query_id,
+					uint_value(4_000),
+					0,
+					query_data.clone()
+				),
+				Error::ReporterTimeLocked
+			);
+		});
+
+		with_block_after(REPORTING_LOCK, || {
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(another_reporter),
+				query_id,
+				uint_value(4_000),
+				0,
+				query_data.clone()
+			));
+
+			// Disputes slash the class-specific amount
+			Balances::set_balance(&reporter, token(1_000));
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				now(),
+				None
+			));
+			let dispute_id = dispute_id(PARA_ID, query_id, now());
+			assert_eq!(
+				crate::DisputeInfo::<Test>::get(dispute_id).unwrap().slashed_amount,
+				trb(200)
+			);
+
+			// Removing the class reverts to the default requirements
+			assert_ok!(Tellor::set_risk_class(Origin::Governance.into(), 1, None));
+			assert_eq!(Tellor::get_reporting_lock(query_id), REPORTING_LOCK);
+			assert_ok!(Tellor::set_query_risk_class(Origin::Governance.into(), query_id, None));
+			System::assert_last_event(Event::QueryRiskClassSet { query_id, class_id: None }.into());
+		});
+	});
+}
+
+#[test]
+fn risk_class_dispute_window() {
+	let query_data: QueryDataOf<Test> = spot_price("eth", "usd").try_into().unwrap();
+	let query_id: QueryId = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			assert_ok!(Tellor::set_risk_class(
+				Origin::Governance.into(),
+				1,
+				Some(RiskClass { reporting_lock: 2 * REPORTING_LOCK, stake_multiple: 1 })
+			));
+			assert_ok!(Tellor::set_query_risk_class(Origin::Governance.into(), query_id, Some(1)));
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(4_000),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+
+		// Disputes may be started within the reporting lock of the risk class
+		with_block_after(REPORTING_LOCK, || {
+			Balances::set_balance(&another_reporter, token(1_000));
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(another_reporter),
+				query_id,
+				timestamp,
+				None
+			));
+		});
+	});
+}
+
 #[test]
 fn withdraw_stake() {
 	let reporter = 1;
@@ -1016,7 +1505,8 @@ fn get_report_details() {
 fn get_reporting_lock() {
 	// Based on https://github.com/tellor-io/tellorFlex/blob/3b3820f2111ec2813cb51455ef68cf0955c51674/test/functionTests-TellorFlex.js#L398
 	let reporting_lock: Timestamp = REPORTING_LOCK;
-	assert_eq!(Tellor::get_reporting_lock(), reporting_lock)
+	new_test_ext()
+		.execute_with(|| assert_eq!(Tellor::get_reporting_lock(H256::random()), reporting_lock));
 }
 
 #[test]
@@ -1733,11 +2223,20 @@ fn get_index_for_data_before_with_start() {
 					block_number,
 					reporter: 0,
 					is_disputed: false,
-					previous: LastReportedTimestamp::get(query_id),
+					previous: LastReported::get(query_id).map(|(_, t)| t),
+				},
+			);
+			ReportedValues::insert(
+				query_id,
+				index,
+				crate::types::IndexedValueOf::<Test> {
+					timestamp,
+					reporter: 0,
+					is_disputed: false,
+					previous: None,
 				},
 			);
-			ReportedTimestampsByIndex::insert(query_id, index, timestamp);
-			LastReportedTimestamp::insert(query_id, timestamp);
+			LastReported::insert(query_id, (index, timestamp));
 
 			if index > 0 && i < reports {
 				assert_ok!(Tellor::remove_value(query_id, timestamp));
@@ -1746,7 +2245,7 @@ fn get_index_for_data_before_with_start() {
 		ReportedTimestampCount::insert(query_id, reports);
 		println!("Reports created in {:?}\n", now.elapsed());
 
-		let timestamp = LastReportedTimestamp::get(query_id).unwrap();
+		let timestamp = LastReported::get(query_id).map(|(_, t)| t).unwrap();
 		let expected = Some(0);
 
 		println!("Using `get_index_for_data_before`, based on last timestamp");
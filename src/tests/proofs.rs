@@ -0,0 +1,307 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+	migrations::v1::v0,
+	proofs::{self, ProofError, ProvenValue},
+	LastReported, MigrationInProgress, ReportedValueData, ReportedValues, Reports,
+};
+use sp_core::Blake2Hasher;
+
+const PALLET_NAME: &[u8] = b"Tellor";
+
+// Generates a proof of the storage keys of a reported value, along with those of the values
+// reported after it up to the requested timestamp, and returns it along with the state root.
+fn prove(
+	ext: &mut sp_io::TestExternalities,
+	query_id: QueryId,
+	before: Timestamp,
+	timestamp: Timestamp,
+) -> (H256, Vec<Vec<u8>>) {
+	let keys = ext.execute_with(|| {
+		let index = Reports::<Test>::get(query_id, timestamp).map_or(0, |report| report.index);
+		let mut keys = vec![
+			proofs::report_key(PALLET_NAME, query_id, timestamp),
+			proofs::indexed_value_key(PALLET_NAME, query_id, index),
+			proofs::legacy_timestamp_key(PALLET_NAME, query_id, index),
+			proofs::reported_value_key(PALLET_NAME, query_id, index),
+			proofs::migration_in_progress_key(PALLET_NAME),
+			proofs::legacy_reported_value_key(PALLET_NAME, query_id, timestamp),
+			proofs::last_reported_key(PALLET_NAME, query_id),
+		];
+		for index in index + 1.. {
+			keys.push(proofs::indexed_value_key(PALLET_NAME, query_id, index));
+			keys.push(proofs::legacy_timestamp_key(PALLET_NAME, query_id, index));
+			let Some(timestamp) = Tellor::get_timestamp_by_query_id_and_index(query_id, index)
+			else {
+				break;
+			};
+			keys.push(proofs::report_key(PALLET_NAME, query_id, timestamp));
+			if timestamp >= before {
+				break;
+			}
+		}
+		keys
+	});
+	let backend = ext.as_backend();
+	let root = *backend.root();
+	let proof = sp_state_machine::prove_read(backend, keys).unwrap();
+	(root, proof.into_iter_nodes().collect())
+}
+
+fn verify(
+	root: &H256,
+	proof: Vec<Vec<u8>>,
+	query_id: QueryId,
+	before: Timestamp,
+	timestamp: Timestamp,
+) -> Result<ProvenValue<AccountIdOf<Test>>, ProofError> {
+	proofs::verify::<AccountIdOf<Test>, u64, Blake2Hasher>(
+		root,
+		proof,
+		PALLET_NAME,
+		query_id,
+		before,
+		timestamp,
+	)
+}
+
+#[test]
+fn storage_keys() {
+	let query_id = QueryId::random();
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			proofs::report_key(PALLET_NAME, query_id, 1_234),
+			Reports::<Test>::hashed_key_for(query_id, 1_234)
+		);
+		assert_eq!(
+			proofs::indexed_value_key(PALLET_NAME, query_id, 5),
+			ReportedValues::<Test>::hashed_key_for(query_id, 5)
+		);
+		assert_eq!(
+			proofs::reported_value_key(PALLET_NAME, query_id, 5),
+			ReportedValueData::<Test>::hashed_key_for(query_id, 5)
+		);
+		assert_eq!(
+			proofs::last_reported_key(PALLET_NAME, query_id),
+			LastReported::<Test>::hashed_key_for(query_id)
+		);
+		assert_eq!(
+			proofs::migration_in_progress_key(PALLET_NAME),
+			MigrationInProgress::<Test>::hashed_key().to_vec()
+		);
+		assert_eq!(
+			proofs::legacy_timestamp_key(PALLET_NAME, query_id, 5),
+			v0::ReportedTimestampsByIndex::<Test>::hashed_key_for(query_id, 5)
+		);
+		assert_eq!(
+			proofs::legacy_reported_value_key(PALLET_NAME, query_id, 1_234),
+			v0::ReportedValuesByTimestamp::<Test>::hashed_key_for(query_id, 1_234)
+		);
+	});
+}
+
+#[test]
+fn verifies_reported_values() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let disputer = 3;
+	let mut ext = new_test_ext();
+
+	let timestamps: Vec<_> = ext.execute_with(|| {
+		let timestamps: Vec<_> = [reporter, another_reporter]
+			.into_iter()
+			.map(|reporter| {
+				with_block(|| {
+					deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+					assert_ok!(Tellor::submit_value(
+						RuntimeOrigin::signed(reporter),
+						query_id,
+						uint_value(10 + reporter),
+						Tellor::get_new_value_count_by_query_id(query_id),
+						query_data.clone()
+					));
+					now()
+				})
+			})
+			.collect();
+		with_block(|| {
+			deposit_stake(disputer, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&disputer, token(1_000));
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(disputer),
+				query_id,
+				timestamps[1],
+				None
+			));
+		});
+		timestamps
+	});
+
+	// Value is the last reported
+	let (root, proof) = prove(&mut ext, query_id, timestamps[1] + 1, timestamps[0]);
+	assert_eq!(
+		verify(&root, proof.clone(), query_id, timestamps[1] + 1, timestamps[0]),
+		Ok(ProvenValue { value: uint_value(11).into(), timestamp: timestamps[0], reporter })
+	);
+	// Proof does not cover other query identifiers, nor other state roots
+	assert_eq!(
+		verify(&root, proof.clone(), QueryId::random(), timestamps[1] + 1, timestamps[0]),
+		Err(ProofError::InvalidProof)
+	);
+	assert_eq!(
+		verify(&H256::random(), proof, query_id, timestamps[1] + 1, timestamps[0]),
+		Err(ProofError::InvalidProof)
+	);
+
+	// Disputed values, and values reported at or after the requested timestamp, are not the latest
+	let (root, proof) = prove(&mut ext, query_id, timestamps[1] + 1, timestamps[1]);
+	assert_eq!(
+		verify(&root, proof, query_id, timestamps[1] + 1, timestamps[1]),
+		Err(ProofError::NotLatest)
+	);
+	let (root, proof) = prove(&mut ext, query_id, timestamps[0], timestamps[0]);
+	assert_eq!(
+		verify(&root, proof, query_id, timestamps[0], timestamps[0]),
+		Err(ProofError::NotLatest)
+	);
+	// Values must have been reported
+	let (root, proof) = prove(&mut ext, query_id, timestamps[1] + 2, timestamps[1] + 1);
+	assert_eq!(
+		verify(&root, proof, query_id, timestamps[1] + 2, timestamps[1] + 1),
+		Err(ProofError::NotLatest)
+	);
+}
+
+#[test]
+fn verifies_values_are_latest() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporters = [1, 2, 3];
+	let disputer = 4;
+	let mut ext = new_test_ext();
+
+	// Values reported, of which the second is disputed
+	let timestamps: Vec<_> = ext.execute_with(|| {
+		let timestamps: Vec<_> = reporters
+			.into_iter()
+			.map(|reporter| {
+				with_block(|| {
+					deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+					assert_ok!(Tellor::submit_value(
+						RuntimeOrigin::signed(reporter),
+						query_id,
+						uint_value(10 + reporter),
+						Tellor::get_new_value_count_by_query_id(query_id),
+						query_data.clone()
+					));
+					now()
+				})
+			})
+			.collect();
+		with_block(|| {
+			deposit_stake(disputer, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&disputer, token(1_000));
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(disputer),
+				query_id,
+				timestamps[1],
+				None
+			));
+		});
+		timestamps
+	});
+
+	// Later values are either disputed or reported at or after the requested timestamp
+	for before in [timestamps[1], timestamps[2]] {
+		let (root, proof) = prove(&mut ext, query_id, before, timestamps[0]);
+		assert_eq!(
+			verify(&root, proof, query_id, before, timestamps[0]),
+			Ok(ProvenValue {
+				value: uint_value(11).into(),
+				timestamp: timestamps[0],
+				reporter: reporters[0]
+			})
+		);
+	}
+
+	// A later non-disputed value was reported before the requested timestamp
+	let before = timestamps[2] + 1;
+	let (root, proof) = prove(&mut ext, query_id, before, timestamps[0]);
+	assert_eq!(verify(&root, proof, query_id, before, timestamps[0]), Err(ProofError::NotLatest));
+	let (root, proof) = prove(&mut ext, query_id, before, timestamps[2]);
+	assert_eq!(
+		verify(&root, proof, query_id, before, timestamps[2]),
+		Ok(ProvenValue {
+			value: uint_value(13).into(),
+			timestamp: timestamps[2],
+			reporter: reporters[2]
+		})
+	);
+
+	// Proof must cover the later values
+	let (root, proof) = prove(&mut ext, query_id, timestamps[1], timestamps[0]);
+	assert_eq!(
+		verify(&root, proof, query_id, before, timestamps[0]),
+		Err(ProofError::InvalidProof)
+	);
+}
+
+#[test]
+fn verifies_values_not_yet_migrated() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	let timestamp = ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			// Revert value to the storage layout prior to version 1
+			ReportedValues::<Test>::remove(query_id, 0);
+			let value = ReportedValueData::<Test>::take(query_id, 0).unwrap();
+			v0::ReportedTimestampsByIndex::<Test>::insert(query_id, 0, now());
+			v0::ReportedValuesByTimestamp::<Test>::insert(query_id, now(), value);
+			LastReported::<Test>::remove(query_id);
+			v0::LastReportedTimestamp::<Test>::insert(query_id, now());
+			MigrationInProgress::<Test>::set(true);
+			now()
+		})
+	});
+
+	let (root, proof) = prove(&mut ext, query_id, timestamp + 1, timestamp);
+	assert_eq!(
+		verify(&root, proof, query_id, timestamp + 1, timestamp),
+		Ok(ProvenValue { value: uint_value(10).into(), timestamp, reporter })
+	);
+	// Previous storage layout only read whilst migrating
+	ext.execute_with(|| MigrationInProgress::<Test>::kill());
+	let (root, proof) = prove(&mut ext, query_id, timestamp + 1, timestamp);
+	assert_eq!(
+		verify(&root, proof, query_id, timestamp + 1, timestamp),
+		Err(ProofError::InvalidValue)
+	);
+}
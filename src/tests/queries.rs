@@ -0,0 +1,248 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{types::LargeValueOf, Config, ValueReference, HOURS};
+use codec::Encode;
+use frame_support::{dispatch::DispatchResultWithPostInfo, traits::fungible::Inspect};
+use sp_runtime::TokenError;
+
+type MaxLargeValueLength = <Test as Config>::MaxLargeValueLength;
+type RemoteQueryByteFee = <Test as Config>::RemoteQueryByteFee;
+type RemoteQueryFee = <Test as Config>::RemoteQueryFee;
+type Weights = <Test as Config>::WeightInfo;
+
+const CALL_INDEX: [u8; 2] = [50, 1];
+const WEIGHT: Weight = Weight::from_parts(1_000_000_000, 65_536);
+
+fn fees() -> MultiAsset {
+	(Here, 1_000_000_000u128).into()
+}
+
+fn query_value(
+	origin: RuntimeOrigin,
+	query_id: QueryId,
+	before: Timestamp,
+	max_age: Timestamp,
+	reference: u64,
+) -> DispatchResultWithPostInfo {
+	Tellor::query_value(
+		origin,
+		query_id,
+		before,
+		max_age,
+		reference,
+		CALL_INDEX,
+		WEIGHT,
+		Box::new(fees()),
+	)
+}
+
+// Returns the message expected to be sent to the querier when answering a query.
+fn reply(
+	reference: u64,
+	query_id: QueryId,
+	value: Option<(Vec<u8>, Timestamp)>,
+) -> (MultiLocation, Xcm<()>) {
+	let mut call = CALL_INDEX.to_vec();
+	(reference, query_id, value).encode_to(&mut call);
+	(
+		MultiLocation::new(1, X1(Parachain(SIBLING_PARA_ID))),
+		Xcm(vec![
+			DescendOrigin(X1(PalletInstance(PALLET_INDEX))),
+			WithdrawAsset(fees().into()),
+			BuyExecution { fees: fees(), weight_limit: Unlimited },
+			Transact {
+				origin_kind: OriginKind::Xcm,
+				require_weight_at_most: WEIGHT,
+				call: call.into(),
+			},
+			RefundSurplus,
+			DepositAsset {
+				assets: Wild(AllCounted(1)),
+				beneficiary: MultiLocation::new(
+					1,
+					X2(Parachain(PARA_ID), PalletInstance(PALLET_INDEX)),
+				),
+			},
+		]),
+	)
+}
+
+#[test]
+fn query_value_answers_query() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let querier: AccountIdOf<Test> = SIBLING_PARA_ID.into();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&querier, token(10));
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(100),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+
+		with_block_after(HOURS, || {
+			let balance = Balances::balance(&Tellor::account());
+			let result = query_value(RuntimeOrigin::signed(querier), query_id, now(), 2 * HOURS, 7);
+			assert_eq!(result.unwrap().actual_weight, Some(Weights::query_value(32)));
+			assert_eq!(
+				sent_xcm(),
+				vec![reply(7, query_id, Some((uint_value(100).to_vec(), timestamp)))]
+			);
+			System::assert_last_event(
+				Event::RemoteQueryAnswered {
+					para_id: SIBLING_PARA_ID,
+					query_id,
+					timestamp: Some(timestamp),
+				}
+				.into(),
+			);
+			// Querier charged for reply, including each byte of the value
+			let fee = RemoteQueryFee::get() + RemoteQueryByteFee::get() * 32;
+			assert_eq!(Balances::balance(&querier), token(10) - fee);
+			assert_eq!(Balances::balance(&Tellor::account()), balance + fee);
+		});
+	});
+}
+
+#[test]
+fn query_value_answers_query_with_large_value() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let querier: AccountIdOf<Test> = SIBLING_PARA_ID.into();
+	let value: LargeValueOf<Test> =
+		vec![1; MaxLargeValueLength::get() as usize].try_into().unwrap();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&querier, token(10));
+			Balances::set_balance(&reporter, token(10));
+			assert_ok!(Tellor::set_large_values(Origin::Governance.into(), query_id, true));
+			assert_ok!(Tellor::note_value(RuntimeOrigin::signed(reporter), value.clone()));
+			let reference =
+				ValueReference { hash: keccak_256(value.as_ref()).into(), len: value.len() as u32 };
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				reference.encode().try_into().unwrap(),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+
+		with_block_after(HOURS, || {
+			let result = query_value(RuntimeOrigin::signed(querier), query_id, now(), 2 * HOURS, 7);
+			// Weighed and charged by the length of the value replied
+			assert_eq!(
+				result.unwrap().actual_weight,
+				Some(Weights::query_value(MaxLargeValueLength::get()))
+			);
+			assert_eq!(sent_xcm(), vec![reply(7, query_id, Some((value.to_vec(), timestamp)))]);
+			let byte_fee = RemoteQueryByteFee::get() * MaxLargeValueLength::get() as u128;
+			assert_eq!(Balances::balance(&querier), token(10) - RemoteQueryFee::get() - byte_fee);
+		});
+	});
+}
+
+#[test]
+fn query_value_answers_query_without_value() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let querier: AccountIdOf<Test> = SIBLING_PARA_ID.into();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&querier, token(10));
+			// No value before timestamp
+			assert_ok!(query_value(RuntimeOrigin::signed(querier), query_id, now(), HOURS, 0));
+			assert_eq!(sent_xcm(), vec![reply(0, query_id, None)]);
+			System::assert_last_event(
+				Event::RemoteQueryAnswered { para_id: SIBLING_PARA_ID, query_id, timestamp: None }
+					.into(),
+			);
+
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(100),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+
+		with_block_after(HOURS, || {
+			// Latest value older than maximum age
+			assert_ok!(query_value(RuntimeOrigin::signed(querier), query_id, now(), HOURS, 1));
+			assert_eq!(sent_xcm(), vec![reply(1, query_id, None)]);
+
+			// Value not before timestamp
+			assert_ok!(query_value(RuntimeOrigin::signed(querier), query_id, timestamp, HOURS, 2));
+			assert_eq!(sent_xcm(), vec![reply(1, query_id, None), reply(2, query_id, None)]);
+			assert_eq!(Balances::balance(&querier), token(10) - RemoteQueryFee::get() * 3);
+		});
+	});
+}
+
+#[test]
+fn query_value_requires_sibling_parachain() {
+	let query_id = QueryId::random();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_noop!(query_value(RuntimeOrigin::none(), query_id, now(), HOURS, 0), BadOrigin);
+			assert_noop!(
+				query_value(RuntimeOrigin::root(), query_id, now(), HOURS, 0),
+				Error::InvalidQuerier
+			);
+		});
+	});
+}
+
+#[test]
+fn query_value_charges_querier() {
+	let query_id = QueryId::random();
+	let querier: AccountIdOf<Test> = SIBLING_PARA_ID.into();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_noop!(
+				query_value(RuntimeOrigin::signed(querier), query_id, now(), HOURS, 0),
+				TokenError::FundsUnavailable
+			);
+			assert!(sent_xcm().is_empty());
+		});
+	});
+}
@@ -0,0 +1,440 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+	constants::{MAX_REQUESTS_PROCESSED_PER_BLOCK, REPORTING_LOCK},
+	CallbackId, Config, RequestId, RequestTellor, HOURS, MINUTES,
+};
+use frame_support::traits::Hooks;
+use sp_runtime::DispatchError;
+
+type MaxPendingRequests = <Test as Config>::MaxPendingRequests;
+type MaxRequestDelay = <Test as Config>::MaxRequestDelay;
+type PendingRequests = crate::pallet::PendingRequests<Test>;
+type RequestQueueHead = crate::pallet::RequestQueueHead<Test>;
+type RequestScanCursor = crate::pallet::RequestScanCursor<Test>;
+type RequestTimeout = <Test as Config>::RequestTimeout;
+type Weights = <Test as Config>::WeightInfo;
+
+fn request_data(
+	requester: AccountIdOf<Test>,
+	query_id: QueryId,
+	earliest_timestamp: Timestamp,
+	dispute_buffer: Timestamp,
+	callback: CallbackId,
+) -> Result<RequestId, DispatchError> {
+	<Tellor as RequestTellor<_, _, _>>::request_data(
+		requester,
+		query_id,
+		earliest_timestamp,
+		dispute_buffer,
+		callback,
+	)
+}
+
+fn on_idle() -> Weight {
+	Tellor::on_idle(System::block_number(), Weight::MAX)
+}
+
+// Returns the expected weight of processing requests, including pushing values with no subscriptions
+// and pruning with no retention periods set or commitments made.
+fn on_idle_weight(requests: u32) -> Weight {
+	Weights::on_idle(requests)
+		.saturating_add(Weights::push_values(0))
+		.saturating_add(Weights::prune_values(0, 0))
+		.saturating_add(Weights::prune_commits(0))
+}
+
+// Returns the calls made to the response handler within the current block.
+fn responses() -> Vec<HookCall> {
+	hook_calls()
+		.into_iter()
+		.filter(|c| matches!(c, HookCall::Response(..) | HookCall::Timeout(..)))
+		.collect()
+}
+
+#[test]
+fn request_data_works() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let requester = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_eq!(request_data(requester, query_id, now(), MINUTES, 42), Ok(0));
+			System::assert_last_event(
+				Event::DataRequested { request_id: 0, query_id, requester, callback: 42 }.into(),
+			);
+			assert_eq!(request_data(requester, query_id, now() + HOURS, MINUTES, 43), Ok(1));
+			assert_noop!(
+				request_data(requester, query_id, now() + MaxRequestDelay::get() + 1, MINUTES, 0),
+				Error::RequestDelayExceeded
+			);
+			assert_noop!(
+				request_data(requester, query_id, now(), MaxRequestDelay::get() + 1, 0),
+				Error::RequestDelayExceeded
+			);
+			assert_noop!(
+				request_data(requester, query_id, Timestamp::MAX, 0, 0),
+				Error::RequestDelayExceeded
+			);
+
+			assert_eq!(PendingRequests::count(), 2);
+			assert_eq!(
+				PendingRequests::get(0).unwrap().deadline,
+				now() + MINUTES + RequestTimeout::get()
+			);
+			assert_eq!(
+				PendingRequests::get(1).unwrap().deadline,
+				now() + HOURS + MINUTES + RequestTimeout::get()
+			);
+
+			for callback in 2..MaxPendingRequests::get() {
+				assert_ok!(request_data(requester, query_id, now(), MINUTES, callback));
+			}
+			assert_noop!(
+				request_data(requester, query_id, now(), MINUTES, 0),
+				Error::MaxPendingRequestsReached
+			);
+		})
+	});
+}
+
+#[test]
+fn delivers_value_after_dispute_buffer() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let requester = 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(100),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+
+		let (request_id, timestamp) = with_block_after(REPORTING_LOCK, || {
+			// Values before the earliest timestamp are not eligible
+			let request_id = request_data(requester, query_id, now(), 15 * MINUTES, 7).unwrap();
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(200),
+				0,
+				query_data.clone()
+			));
+			assert_ne!(now(), timestamp);
+			assert_eq!(on_idle(), on_idle_weight(1));
+			assert!(responses().is_empty());
+			(request_id, now())
+		});
+
+		with_block_after(15 * MINUTES, || {
+			assert_eq!(on_idle(), on_idle_weight(1).saturating_add(TestHooks::WEIGHT));
+			assert_eq!(
+				responses(),
+				vec![HookCall::Response(
+					request_id,
+					7,
+					query_id,
+					uint_value(200).to_vec(),
+					timestamp
+				)]
+			);
+			System::assert_last_event(
+				Event::RequestFulfilled { request_id, query_id, timestamp }.into(),
+			);
+			assert_eq!(PendingRequests::count(), 0);
+
+			assert_eq!(on_idle(), on_idle_weight(0));
+		});
+	});
+}
+
+#[test]
+fn skips_disputed_values() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let requester = 3;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let request_id = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			deposit_stake(another_reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&another_reporter, token(1_000));
+			let request_id = request_data(requester, query_id, now(), 15 * MINUTES, 0).unwrap();
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(100),
+				0,
+				query_data.clone()
+			));
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(another_reporter),
+				query_id,
+				now(),
+				None
+			));
+			request_id
+		});
+
+		let timestamp = with_block_after(MINUTES, || {
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(another_reporter),
+				query_id,
+				uint_value(200),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+
+		with_block_after(15 * MINUTES, || {
+			on_idle();
+			assert_eq!(
+				responses(),
+				vec![HookCall::Response(
+					request_id,
+					0,
+					query_id,
+					uint_value(200).to_vec(),
+					timestamp
+				)]
+			);
+		});
+	});
+}
+
+#[test]
+fn times_out() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let requester = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let request_id =
+			with_block(|| request_data(requester, query_id, now(), MINUTES, 3).unwrap());
+
+		with_block_after(MINUTES + RequestTimeout::get() - 2, || {
+			on_idle();
+			assert!(responses().is_empty());
+			assert_eq!(PendingRequests::count(), 1);
+		});
+
+		with_block(|| {
+			assert_eq!(on_idle(), on_idle_weight(1).saturating_add(TestHooks::WEIGHT));
+			assert_eq!(responses(), vec![HookCall::Timeout(request_id, 3, query_id)]);
+			System::assert_last_event(Event::RequestTimedOut { request_id, query_id }.into());
+			assert_eq!(PendingRequests::count(), 0);
+		});
+	});
+}
+
+#[test]
+fn on_idle_respects_remaining_weight() {
+	let query_id = H256::random();
+	let requester = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			for callback in 0..3 {
+				assert_ok!(request_data(requester, query_id, now(), 0, callback));
+			}
+		});
+
+		with_block_after(RequestTimeout::get(), || {
+			assert_eq!(
+				Tellor::on_idle(System::block_number(), Weights::on_idle(0)),
+				Weight::zero()
+			);
+			assert_eq!(PendingRequests::count(), 3);
+
+			// Sufficient weight for two requests
+			let remaining_weight =
+				Weights::on_idle(2).saturating_add(TestHooks::WEIGHT.saturating_mul(2));
+			assert_eq!(Tellor::on_idle(System::block_number(), remaining_weight), remaining_weight);
+			assert_eq!(
+				responses(),
+				vec![HookCall::Timeout(0, 0, query_id), HookCall::Timeout(1, 1, query_id)]
+			);
+			assert_eq!(PendingRequests::count(), 1);
+		});
+	});
+}
+
+#[test]
+fn processes_requests_out_of_order() {
+	let query_id = H256::random();
+	let requester = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			// First request times out after the second
+			assert_ok!(request_data(requester, query_id, now() + HOURS, 0, 0));
+			assert_ok!(request_data(requester, query_id, now(), 0, 1));
+		});
+
+		with_block_after(RequestTimeout::get(), || {
+			on_idle();
+			assert_eq!(responses(), vec![HookCall::Timeout(1, 1, query_id)]);
+			assert_eq!(PendingRequests::count(), 1);
+			assert_eq!(RequestQueueHead::get(), 0);
+		});
+
+		with_block_after(HOURS, || {
+			on_idle();
+			assert_eq!(responses(), vec![HookCall::Timeout(0, 0, query_id)]);
+			assert_eq!(PendingRequests::count(), 0);
+			assert_eq!(RequestQueueHead::get(), 2);
+		});
+	});
+}
+
+#[test]
+fn resumes_processing_from_scan_cursor() {
+	let query_id = H256::random();
+	let requester = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			// First request remains pending at the head of the queue
+			assert_ok!(request_data(requester, query_id, now() + HOURS, 0, 0));
+			for callback in 1..4 {
+				assert_ok!(request_data(requester, query_id, now(), 0, callback));
+			}
+		});
+
+		with_block_after(RequestTimeout::get(), || {
+			// Sufficient weight for two requests
+			let remaining_weight = Weights::on_idle(2).saturating_add(TestHooks::WEIGHT);
+			assert_eq!(Tellor::on_idle(System::block_number(), remaining_weight), remaining_weight);
+			assert_eq!(responses(), vec![HookCall::Timeout(1, 1, query_id)]);
+			assert_eq!(RequestQueueHead::get(), 0);
+			assert_eq!(RequestScanCursor::get(), Some(2));
+
+			// Processing resumes after the pending request, rather than from the head of the queue
+			let remaining_weight =
+				Weights::on_idle(2).saturating_add(TestHooks::WEIGHT.saturating_mul(2));
+			assert_eq!(Tellor::on_idle(System::block_number(), remaining_weight), remaining_weight);
+			assert_eq!(
+				responses(),
+				vec![
+					HookCall::Timeout(1, 1, query_id),
+					HookCall::Timeout(2, 2, query_id),
+					HookCall::Timeout(3, 3, query_id)
+				]
+			);
+			assert_eq!(PendingRequests::count(), 1);
+			assert_eq!(RequestQueueHead::get(), 0);
+			assert_eq!(RequestScanCursor::get(), None);
+		});
+
+		with_block_after(HOURS, || {
+			on_idle();
+			assert_eq!(responses(), vec![HookCall::Timeout(0, 0, query_id)]);
+			assert_eq!(PendingRequests::count(), 0);
+			assert_eq!(RequestQueueHead::get(), 4);
+			assert_eq!(RequestScanCursor::get(), None);
+		});
+	});
+}
+
+#[test]
+fn on_initialize_processes_requests() {
+	let query_id = H256::random();
+	let requester = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			for callback in 0..MAX_REQUESTS_PROCESSED_PER_BLOCK + 1 {
+				assert_ok!(request_data(requester, query_id, now(), 0, callback));
+			}
+		});
+
+		with_block_after(RequestTimeout::get(), || {
+			Tellor::on_initialize(System::block_number());
+			assert_eq!(responses().len(), MAX_REQUESTS_PROCESSED_PER_BLOCK as usize);
+			assert_eq!(PendingRequests::count(), 1);
+			assert_eq!(RequestQueueHead::get(), MAX_REQUESTS_PROCESSED_PER_BLOCK.into());
+
+			// Remaining requests are processed using idle weight
+			on_idle();
+			assert_eq!(
+				responses().last(),
+				Some(&HookCall::Timeout(
+					MAX_REQUESTS_PROCESSED_PER_BLOCK.into(),
+					MAX_REQUESTS_PROCESSED_PER_BLOCK,
+					query_id
+				))
+			);
+			assert_eq!(PendingRequests::count(), 0);
+		});
+	});
+}
+
+#[test]
+fn tip() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let requester = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			Balances::set_balance(&requester, token(100));
+			assert_noop!(
+				<Tellor as RequestTellor<_, _, _>>::tip(
+					requester,
+					H256::random(),
+					token(10),
+					query_data.clone()
+				),
+				Error::InvalidQueryId
+			);
+			assert_ok!(<Tellor as RequestTellor<_, _, _>>::tip(
+				requester,
+				query_id,
+				token(10),
+				query_data.clone()
+			));
+			assert_eq!(Balances::free_balance(requester), token(90));
+			assert_eq!(Tellor::get_current_tip(query_id), token(10));
+			System::assert_last_event(
+				Event::TipAdded { query_id, amount: token(10), query_data, tipper: requester }
+					.into(),
+			);
+		});
+	});
+}
@@ -0,0 +1,406 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+	constants::{CLAIM_PERIOD, MIN_RETENTION_PERIOD, REPORTING_LOCK},
+	Config, UsingTellor, VoteResult, DAYS,
+};
+use frame_support::traits::{fungible::Inspect, Hooks};
+use sp_core::bounded_vec;
+
+type PruningCursor = crate::pallet::PruningCursor<Test>;
+type Weights = <Test as Config>::WeightInfo;
+
+fn set_retention_period(query_id: QueryId) {
+	assert_ok!(Tellor::set_retention_period(
+		Origin::Governance.into(),
+		query_id,
+		Some(MIN_RETENTION_PERIOD)
+	));
+}
+
+fn submit_value(reporter: AccountIdOf<Test>, query_data: &QueryDataOf<Test>, value: u32) {
+	let query_id = keccak_256(query_data.as_ref()).into();
+	assert_ok!(Tellor::submit_value(
+		RuntimeOrigin::signed(reporter),
+		query_id,
+		uint_value(value),
+		Tellor::get_new_value_count_by_query_id(query_id),
+		query_data.clone()
+	));
+}
+
+#[test]
+fn set_retention_period() {
+	let query_id = H256::random();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_noop!(
+				Tellor::set_retention_period(
+					RuntimeOrigin::signed(1),
+					query_id,
+					Some(MIN_RETENTION_PERIOD)
+				),
+				BadOrigin
+			);
+			assert_noop!(
+				Tellor::set_retention_period(
+					Origin::Governance.into(),
+					query_id,
+					Some(MIN_RETENTION_PERIOD - 1)
+				),
+				Error::InvalidRetentionPeriod
+			);
+
+			assert_ok!(Tellor::set_retention_period(
+				Origin::Governance.into(),
+				query_id,
+				Some(MIN_RETENTION_PERIOD)
+			));
+			System::assert_last_event(
+				Event::RetentionPeriodSet {
+					query_id,
+					retention_period: Some(MIN_RETENTION_PERIOD),
+				}
+				.into(),
+			);
+
+			assert_ok!(Tellor::set_retention_period(Origin::Governance.into(), query_id, None));
+			System::assert_last_event(
+				Event::RetentionPeriodSet { query_id, retention_period: None }.into(),
+			);
+		})
+	});
+}
+
+#[test]
+fn prunes_values() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let first = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			set_retention_period(query_id);
+			submit_value(reporter, &query_data, 100);
+			now()
+		});
+		with_block_after(REPORTING_LOCK, || submit_value(reporter, &query_data, 101));
+		let retained = with_block_after(MIN_RETENTION_PERIOD, || {
+			submit_value(reporter, &query_data, 102);
+			now()
+		});
+
+		with_block_after(MIN_RETENTION_PERIOD, || {
+			submit_value(reporter, &query_data, 103);
+			let latest = now();
+
+			assert_eq!(
+				Tellor::on_idle(System::block_number(), Weight::MAX),
+				Weights::on_idle(0)
+					.saturating_add(Weights::push_values(0))
+					.saturating_add(Weights::prune_values(1, 2))
+					.saturating_add(Weights::prune_commits(0))
+			);
+			assert_eq!(Tellor::get_first_retained_index(query_id), 2);
+			assert!(PruningCursor::get().is_none());
+			// Index semantics preserved
+			assert_eq!(Tellor::get_new_value_count_by_query_id(query_id), 4);
+			assert_eq!(Tellor::get_timestamp_by_query_id_and_index(query_id, 0), None);
+			assert_eq!(Tellor::get_timestamp_by_query_id_and_index(query_id, 1), None);
+			assert_eq!(Tellor::get_timestamp_by_query_id_and_index(query_id, 2), Some(retained));
+			assert_eq!(Tellor::retrieve_data(query_id, first), None);
+			assert_eq!(Tellor::get_report_details(query_id, first), None);
+			assert_eq!(Tellor::get_value_proof(query_id, first), None);
+			assert!(Tellor::get_value_proof(query_id, retained).is_some());
+
+			// Searches skip pruned values
+			assert_eq!(Tellor::get_index_for_data_before(query_id, retained), None);
+			assert_eq!(Tellor::get_index_for_data_before(query_id, latest), Some(2));
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::get_index_for_data_after(
+					query_id, first
+				),
+				Some(2)
+			);
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::get_data_after(query_id, first),
+				Some((uint_value(102).into_inner(), retained))
+			);
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::get_multiple_values_before(
+					query_id,
+					latest + 1,
+					latest,
+					10
+				),
+				vec![
+					(uint_value(102).into_inner(), retained),
+					(uint_value(103).into_inner(), latest)
+				]
+			);
+		});
+	});
+}
+
+#[test]
+fn retains_disputed_values() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let (disputed, dispute_id) = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			deposit_stake(another_reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			set_retention_period(query_id);
+			Balances::set_balance(&reporter, token(1_000));
+			(now(), submit_value_and_begin_dispute(reporter, query_id, query_data.clone()))
+		});
+		with_block_after(REPORTING_LOCK, || submit_value(another_reporter, &query_data, 101));
+		with_block_after(MIN_RETENTION_PERIOD, || {
+			submit_value(another_reporter, &query_data, 102);
+			assert_ok!(Tellor::report_vote_tallied(
+				Origin::Governance.into(),
+				dispute_id,
+				VoteResult::Passed
+			));
+
+			// Disputed value retained whilst the dispute may still be escalated
+			Tellor::prune_values(now(), Weight::MAX);
+			assert_eq!(Tellor::get_first_retained_index(query_id), 0);
+			assert!(Tellor::is_in_dispute(query_id, disputed));
+		});
+
+		with_block_after(DAYS, || {
+			assert_ok!(Tellor::report_vote_executed(Origin::Governance.into(), dispute_id));
+			Tellor::prune_values(now(), Weight::MAX);
+			// Value before the latest value retained whilst the latest value can still be claimed
+			assert_eq!(Tellor::get_first_retained_index(query_id), 1);
+			assert!(!Tellor::is_in_dispute(query_id, disputed));
+		});
+	});
+}
+
+#[test]
+fn retains_values_whilst_later_values_can_be_claimed() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let feed_creator = 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let feed_id = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			set_retention_period(query_id);
+			Balances::set_balance(&feed_creator, token(100));
+			assert_ok!(Tellor::setup_data_feed(
+				RuntimeOrigin::signed(feed_creator),
+				query_id,
+				token(1),
+				now(),
+				3_600_000,
+				2,
+				1_000,
+				0,
+				query_data.clone(),
+				token(10)
+			));
+			submit_value(reporter, &query_data, 100);
+			Tellor::get_current_feeds(query_id)[0]
+		});
+		let timestamp = with_block_after(REPORTING_LOCK, || {
+			submit_value(reporter, &query_data, 101);
+			now()
+		});
+
+		with_block_after(CLAIM_PERIOD - DAYS, || {
+			// Previous value retained whilst the latest value can still be claimed, so that the
+			// price change is assessed against it
+			Tellor::prune_values(now(), Weight::MAX);
+			assert_eq!(Tellor::get_first_retained_index(query_id), 0);
+			assert_noop!(
+				Tellor::claim_tip(
+					RuntimeOrigin::signed(reporter),
+					feed_id,
+					query_id,
+					bounded_vec![timestamp.into()]
+				),
+				Error::PriceThresholdNotMet.with_weight(Weights::claim_tip(1))
+			);
+		});
+
+		with_block_after(DAYS + REPORTING_LOCK, || {
+			Tellor::prune_values(now(), Weight::MAX);
+			assert_eq!(Tellor::get_first_retained_index(query_id), 1);
+			assert_noop!(
+				Tellor::claim_tip(
+					RuntimeOrigin::signed(reporter),
+					feed_id,
+					query_id,
+					bounded_vec![timestamp.into()]
+				),
+				Error::ClaimPeriodExpired.with_weight(Weights::claim_tip(1))
+			);
+		});
+	});
+}
+
+#[test]
+fn retains_values_with_unclaimed_tips() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let tipper = 2;
+	let mut ext = new_test_ext();
+
+	let tip = |tipper: AccountIdOf<Test>| {
+		assert_ok!(Tellor::tip(
+			RuntimeOrigin::signed(tipper),
+			query_id,
+			token(1),
+			query_data.clone()
+		))
+	};
+	let claim_onetime_tip = |reporter: AccountIdOf<Test>, timestamp: Timestamp| {
+		assert_ok!(Tellor::claim_onetime_tip(
+			RuntimeOrigin::signed(reporter),
+			query_id,
+			bounded_vec![timestamp.into()]
+		));
+	};
+
+	ext.execute_with(|| {
+		let first = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			set_retention_period(query_id);
+			Balances::set_balance(&tipper, token(100));
+			tip(tipper);
+			submit_value(reporter, &query_data, 100);
+			now()
+		});
+		let second = with_block_after(REPORTING_LOCK, || {
+			tip(tipper);
+			submit_value(reporter, &query_data, 101);
+			now()
+		});
+		with_block_after(REPORTING_LOCK, || submit_value(reporter, &query_data, 102));
+
+		with_block_after(2 * MIN_RETENTION_PERIOD, || {
+			// Tip awaiting the next value keeps the tips account funded
+			tip(tipper);
+
+			// Tipped value retained until its tip is claimed
+			Tellor::prune_values(now(), Weight::MAX);
+			assert_eq!(Tellor::get_first_retained_index(query_id), 0);
+			claim_onetime_tip(reporter, first);
+
+			// Previous value retained until the tip of the next value is claimed
+			Tellor::prune_values(now(), Weight::MAX);
+			assert_eq!(Tellor::get_first_retained_index(query_id), 0);
+			let balance = Balances::balance(&Tellor::tips());
+			claim_onetime_tip(reporter, second);
+			// Only the tip earned by the value is paid
+			assert_eq!(balance - Balances::balance(&Tellor::tips()), token(1));
+
+			Tellor::prune_values(now(), Weight::MAX);
+			assert_eq!(Tellor::get_first_retained_index(query_id), 2);
+		});
+	});
+}
+
+#[test]
+fn retains_latest_value() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			set_retention_period(query_id);
+			submit_value(reporter, &query_data, 100);
+			now()
+		});
+
+		with_block_after(2 * MIN_RETENTION_PERIOD, || {
+			Tellor::on_idle(System::block_number(), Weight::MAX);
+			assert_eq!(Tellor::get_first_retained_index(query_id), 0);
+			assert_eq!(
+				Tellor::get_data_before(query_id, now()),
+				Some((uint_value(100), timestamp))
+			);
+		});
+	});
+}
+
+#[test]
+fn resumes_pruning() {
+	let reporter = 1;
+	let mut queries: Vec<(QueryId, QueryDataOf<Test>)> = ["dot", "ksm"]
+		.into_iter()
+		.map(|asset| {
+			let query_data: QueryDataOf<Test> = spot_price(asset, "usd").try_into().unwrap();
+			(keccak_256(query_data.as_ref()).into(), query_data)
+		})
+		.collect();
+	// Sort by query identifier to match the order in which retention periods are processed
+	queries.sort_by_key(|(query_id, _)| *query_id);
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			for (query_id, _) in &queries {
+				set_retention_period(*query_id);
+			}
+		});
+		for value in 0..3 {
+			for (_, query_data) in &queries {
+				with_block_after(REPORTING_LOCK, || submit_value(reporter, query_data, value));
+			}
+		}
+
+		with_block_after(MIN_RETENTION_PERIOD, || {
+			let timestamp = now();
+			// Weight limited to pruning the first query identifier, before running out on the next
+			assert_eq!(
+				Tellor::prune_values(timestamp, Weights::prune_values(2, 2)),
+				Weights::prune_values(2, 2)
+			);
+			assert_eq!(Tellor::get_first_retained_index(queries[0].0), 2);
+			assert_eq!(Tellor::get_first_retained_index(queries[1].0), 0);
+			assert_eq!(PruningCursor::get(), Some(queries[0].0));
+
+			// Pruning resumes from the next query identifier
+			assert_eq!(Tellor::prune_values(timestamp, Weight::MAX), Weights::prune_values(1, 2));
+			assert_eq!(Tellor::get_first_retained_index(queries[1].0), 2);
+			assert!(PruningCursor::get().is_none());
+
+			// Insufficient weight
+			assert_eq!(Tellor::prune_values(timestamp, Weight::zero()), Weight::zero());
+		});
+	});
+}
@@ -0,0 +1,242 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Config;
+use frame_support::traits::fungible::Inspect;
+use sp_core::bounded_vec;
+
+type RetractionPenalty = <Test as Config>::RetractionPenalty;
+type RetractionPeriod = <Test as Config>::RetractionPeriod;
+
+#[test]
+fn retract_value() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&reporter, token(10));
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+
+		with_block(|| {
+			// Reporter time locked until value retracted
+			assert_noop!(
+				Tellor::submit_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					uint_value(11),
+					0,
+					query_data.clone()
+				),
+				Error::ReporterTimeLocked
+			);
+			assert_noop!(
+				Tellor::retract_value(RuntimeOrigin::signed(another_reporter), query_id, timestamp),
+				Error::NotValueReporter
+			);
+			assert_noop!(
+				Tellor::retract_value(RuntimeOrigin::signed(reporter), query_id, timestamp + 1),
+				Error::NoValueExists
+			);
+
+			let staking_rewards = Balances::balance(&Tellor::staking_rewards());
+			assert_ok!(Tellor::retract_value(RuntimeOrigin::signed(reporter), query_id, timestamp));
+			System::assert_has_event(Event::ValueRemoved { query_id, timestamp }.into());
+			System::assert_last_event(
+				Event::ValueRetracted {
+					query_id,
+					timestamp,
+					reporter,
+					penalty: RetractionPenalty::get(),
+				}
+				.into(),
+			);
+			assert_eq!(Tellor::retrieve_data(query_id, timestamp), None);
+			assert_eq!(Tellor::get_retractions_by_reporter(&reporter), 1);
+			assert_eq!(
+				Balances::balance(&Tellor::staking_rewards()),
+				staking_rewards + RetractionPenalty::get()
+			);
+			assert_eq!(Balances::balance(&reporter), token(10) - RetractionPenalty::get());
+			assert_noop!(
+				Tellor::retract_value(RuntimeOrigin::signed(reporter), query_id, timestamp),
+				Error::ValueDisputed
+			);
+
+			// Reporting lock reset, so reporter can resubmit
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(11),
+				0,
+				query_data.clone()
+			));
+			assert_eq!(Tellor::get_current_value(query_id), Some(uint_value(11)));
+		});
+	});
+}
+
+#[test]
+fn retract_value_with_offset_timestamp() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let another_query_data: QueryDataOf<Test> = spot_price("eth", "usd").try_into().unwrap();
+	let another_query_id = keccak_256(another_query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let (timestamp, another_timestamp) = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			deposit_stake(another_reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&reporter, token(10));
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(another_reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			// First value is reported within the same second, so timestamped ahead of the current time
+			assert_ok!(Tellor::submit_values(
+				RuntimeOrigin::signed(reporter),
+				bounded_vec![
+					(query_id, uint_value(11), 0, query_data.clone()),
+					(another_query_id, uint_value(20), 0, another_query_data.clone()),
+				]
+			));
+			assert_eq!(Tellor::retrieve_data(query_id, now() + 1), Some(uint_value(11)));
+			(now() + 1, now())
+		});
+
+		with_block(|| {
+			// Reporting lock reset, as retracted value reported within reporter's last block
+			assert_ok!(Tellor::retract_value(
+				RuntimeOrigin::signed(reporter),
+				another_query_id,
+				another_timestamp
+			));
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				another_query_id,
+				uint_value(21),
+				0,
+				another_query_data.clone()
+			));
+			assert!(timestamp >= Tellor::get_reporter_last_timestamp(reporter).unwrap());
+
+			// Reporting lock not reset, as retracted value reported before reporter's last value,
+			// despite being timestamped at or after the time it was reported
+			assert_ok!(Tellor::retract_value(RuntimeOrigin::signed(reporter), query_id, timestamp));
+			assert_eq!(Tellor::retrieve_data(query_id, timestamp), None);
+			assert_noop!(
+				Tellor::submit_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					uint_value(12),
+					0,
+					query_data.clone()
+				),
+				Error::ReporterTimeLocked
+			);
+		});
+	});
+}
+
+#[test]
+fn retract_value_after_retraction_period() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&reporter, token(10));
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+
+		with_block_after(RetractionPeriod::get(), || {
+			assert_noop!(
+				Tellor::retract_value(RuntimeOrigin::signed(reporter), query_id, timestamp),
+				Error::RetractionPeriodElapsed
+			);
+			assert_eq!(Tellor::get_retractions_by_reporter(&reporter), 0);
+		});
+	});
+}
+
+#[test]
+fn retract_disputed_value() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let disputer = 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let timestamp = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			deposit_stake(disputer, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&reporter, token(10));
+			Balances::set_balance(&disputer, token(1_000));
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(10),
+				0,
+				query_data.clone()
+			));
+			now()
+		});
+
+		with_block(|| {
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(disputer),
+				query_id,
+				timestamp,
+				None
+			));
+			assert_noop!(
+				Tellor::retract_value(RuntimeOrigin::signed(reporter), query_id, timestamp),
+				Error::ValueDisputed
+			);
+			assert_eq!(Balances::balance(&reporter), token(10));
+		});
+	});
+}
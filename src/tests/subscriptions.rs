@@ -0,0 +1,607 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+	constants::{MAX_PENDING_REMOVALS, MAX_PUSH_ATTEMPTS, REPORTING_LOCK},
+	types::LargeValueOf,
+	Config, Subscription, SubscriptionId, ValueReference, HOURS,
+};
+use codec::Encode;
+use frame_support::traits::{fungible::Inspect, Hooks};
+use sp_runtime::{Permill, TokenError};
+
+type MaxLargeValueLength = <Test as Config>::MaxLargeValueLength;
+type MaxSubscriptions = <Test as Config>::MaxSubscriptions;
+type RemovalCount = crate::pallet::RemovalCount<Test>;
+type Removals = crate::pallet::Removals<Test>;
+type SubscriptionCount = crate::pallet::SubscriptionCount<Test>;
+type SubscriptionDeposit = <Test as Config>::SubscriptionDeposit;
+type SubscriptionDisputeBuffer = <Test as Config>::SubscriptionDisputeBuffer;
+type SubscriptionFee = <Test as Config>::SubscriptionFee;
+
+const CALL_INDEX: [u8; 2] = [50, 0];
+const REMOVAL_CALL_INDEX: [u8; 2] = [50, 1];
+const WEIGHT: Weight = Weight::from_parts(1_000_000_000, 65_536);
+
+fn fees() -> MultiAsset {
+	(Here, 1_000_000_000u128).into()
+}
+
+fn subscribe(
+	para_id: u32,
+	query_id: QueryId,
+	min_interval: Timestamp,
+	deviation: Permill,
+) -> SubscriptionId {
+	let subscription_id = SubscriptionCount::get();
+	// Fund the deposit held for the subscription
+	assert_ok!(Balances::mint_into(&para_id.into(), SubscriptionDeposit::get()));
+	assert_ok!(Tellor::subscribe(
+		RuntimeOrigin::signed(para_id.into()),
+		query_id,
+		min_interval,
+		deviation,
+		CALL_INDEX,
+		Some(REMOVAL_CALL_INDEX),
+		WEIGHT,
+		Box::new(fees())
+	));
+	subscription_id
+}
+
+fn submit_value(reporter: AccountIdOf<Test>, query_data: &QueryDataOf<Test>, value: u32) {
+	let query_id = keccak_256(query_data.as_ref()).into();
+	assert_ok!(Tellor::submit_value(
+		RuntimeOrigin::signed(reporter),
+		query_id,
+		uint_value(value),
+		Tellor::get_new_value_count_by_query_id(query_id),
+		query_data.clone()
+	));
+}
+
+// Returns the message expected to be sent to the subscriber when pushing a value.
+fn push(
+	query_id: QueryId,
+	timestamp: Timestamp,
+	value: u32,
+	reporter: AccountIdOf<Test>,
+) -> (MultiLocation, Xcm<()>) {
+	let mut call = CALL_INDEX.to_vec();
+	(query_id, timestamp, uint_value(value), reporter).encode_to(&mut call);
+	deliver(call)
+}
+
+// Returns the message expected to be sent to the subscriber when pushing a removal.
+fn push_removal(query_id: QueryId, timestamp: Timestamp) -> (MultiLocation, Xcm<()>) {
+	let mut call = REMOVAL_CALL_INDEX.to_vec();
+	(query_id, timestamp).encode_to(&mut call);
+	deliver(call)
+}
+
+fn deliver(call: Vec<u8>) -> (MultiLocation, Xcm<()>) {
+	(
+		MultiLocation::new(1, X1(Parachain(SIBLING_PARA_ID))),
+		Xcm(vec![
+			DescendOrigin(X1(PalletInstance(PALLET_INDEX))),
+			WithdrawAsset(fees().into()),
+			BuyExecution { fees: fees(), weight_limit: Unlimited },
+			Transact {
+				origin_kind: OriginKind::Xcm,
+				require_weight_at_most: WEIGHT,
+				call: call.into(),
+			},
+			RefundSurplus,
+			DepositAsset {
+				assets: Wild(AllCounted(1)),
+				beneficiary: MultiLocation::new(
+					1,
+					X2(Parachain(PARA_ID), PalletInstance(PALLET_INDEX)),
+				),
+			},
+		]),
+	)
+}
+
+fn on_idle() -> Weight {
+	Tellor::on_idle(System::block_number(), Weight::MAX)
+}
+
+#[test]
+fn subscribe_and_unsubscribe() {
+	let query_id = QueryId::random();
+	let subscriber: AccountIdOf<Test> = SIBLING_PARA_ID.into();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			assert_noop!(
+				Tellor::subscribe(
+					RuntimeOrigin::none(),
+					query_id,
+					0,
+					Permill::zero(),
+					CALL_INDEX,
+					Some(REMOVAL_CALL_INDEX),
+					WEIGHT,
+					Box::new(fees())
+				),
+				BadOrigin
+			);
+			// Only sibling parachains may subscribe
+			assert_noop!(
+				Tellor::subscribe(
+					RuntimeOrigin::root(),
+					query_id,
+					0,
+					Permill::zero(),
+					CALL_INDEX,
+					Some(REMOVAL_CALL_INDEX),
+					WEIGHT,
+					Box::new(fees())
+				),
+				Error::InvalidSubscriber
+			);
+			// Subscriber must fund the deposit
+			assert_noop!(
+				Tellor::subscribe(
+					RuntimeOrigin::signed(subscriber),
+					query_id,
+					0,
+					Permill::zero(),
+					CALL_INDEX,
+					Some(REMOVAL_CALL_INDEX),
+					WEIGHT,
+					Box::new(fees())
+				),
+				TokenError::FundsUnavailable
+			);
+
+			Balances::set_balance(&subscriber, token(10));
+			let subscription_id = subscribe(SIBLING_PARA_ID, query_id, HOURS, Permill::zero());
+			System::assert_last_event(
+				Event::SubscriptionRegistered {
+					subscription_id,
+					para_id: SIBLING_PARA_ID,
+					query_id,
+				}
+				.into(),
+			);
+			assert_eq!(
+				Tellor::get_subscription(subscription_id),
+				Some(Subscription {
+					para_id: SIBLING_PARA_ID,
+					query_id,
+					min_interval: HOURS,
+					deviation: Permill::zero(),
+					call_index: CALL_INDEX,
+					removal_call_index: Some(REMOVAL_CALL_INDEX),
+					weight: WEIGHT,
+					fees: fees(),
+					last_pushed: None,
+					failed: None,
+					removals: 0,
+				})
+			);
+			// Deposit held from subscriber
+			assert_eq!(Balances::balance(&subscriber), token(10));
+			assert_eq!(
+				Balances::balance(&Tellor::subscription_deposits()),
+				SubscriptionDeposit::get()
+			);
+
+			// Only the subscriber may cancel its subscription
+			assert_noop!(
+				Tellor::unsubscribe(RuntimeOrigin::signed(subscriber + 1), subscription_id),
+				Error::UnknownSubscription
+			);
+			assert_noop!(
+				Tellor::unsubscribe(RuntimeOrigin::signed(subscriber), subscription_id + 1),
+				Error::UnknownSubscription
+			);
+			assert_ok!(Tellor::unsubscribe(RuntimeOrigin::signed(subscriber), subscription_id));
+			System::assert_last_event(Event::SubscriptionCancelled { subscription_id }.into());
+			assert_eq!(Tellor::get_subscription(subscription_id), None);
+			// Deposit returned to subscriber
+			assert_eq!(Balances::balance(&subscriber), token(10) + SubscriptionDeposit::get());
+			assert_eq!(Balances::balance(&Tellor::subscription_deposits()), 0);
+		});
+	});
+}
+
+#[test]
+fn subscribe_limited() {
+	let query_id = QueryId::random();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			Balances::set_balance(&SIBLING_PARA_ID.into(), token(10));
+			for _ in 0..MaxSubscriptions::get() {
+				subscribe(SIBLING_PARA_ID, query_id, 0, Permill::zero());
+			}
+			assert_noop!(
+				Tellor::subscribe(
+					RuntimeOrigin::signed(SIBLING_PARA_ID.into()),
+					query_id,
+					0,
+					Permill::zero(),
+					CALL_INDEX,
+					Some(REMOVAL_CALL_INDEX),
+					WEIGHT,
+					Box::new(fees())
+				),
+				Error::MaxSubscriptionsReached
+			);
+		});
+	});
+}
+
+#[test]
+fn pushes_values() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let subscriber: AccountIdOf<Test> = SIBLING_PARA_ID.into();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let subscription_id = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&subscriber, token(10));
+			subscribe(SIBLING_PARA_ID, query_id, 0, Permill::zero())
+		});
+
+		let timestamp = with_block(|| {
+			submit_value(reporter, &query_data, 100);
+			// Value not pushed until dispute buffer elapsed
+			on_idle();
+			assert!(sent_xcm().is_empty());
+			now()
+		});
+
+		with_block_after(SubscriptionDisputeBuffer::get(), || {
+			let balance = Balances::balance(&Tellor::account());
+			on_idle();
+			assert_eq!(sent_xcm(), vec![push(query_id, timestamp, 100, reporter)]);
+			System::assert_has_event(
+				Event::ValuePushed {
+					subscription_id,
+					para_id: SIBLING_PARA_ID,
+					query_id,
+					timestamp,
+				}
+				.into(),
+			);
+			// Subscriber charged for push
+			assert_eq!(Balances::balance(&subscriber), token(10) - SubscriptionFee::get());
+			assert_eq!(Balances::balance(&Tellor::account()), balance + SubscriptionFee::get());
+			assert_eq!(
+				Tellor::get_subscription(subscription_id).unwrap().last_pushed,
+				Some((timestamp, Some(U256::from(100))))
+			);
+		});
+
+		with_block(|| {
+			// Value only pushed once
+			on_idle();
+			assert!(sent_xcm().is_empty());
+			assert_eq!(Balances::balance(&subscriber), token(10) - SubscriptionFee::get());
+		});
+	});
+}
+
+#[test]
+fn pushes_values_after_min_interval() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporters = [1, 2, 3];
+	let subscriber: AccountIdOf<Test> = SIBLING_PARA_ID.into();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			for reporter in reporters {
+				deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			}
+			Balances::set_balance(&subscriber, token(10));
+			subscribe(SIBLING_PARA_ID, query_id, 2 * HOURS, Permill::zero());
+		});
+
+		let timestamp = with_block(|| {
+			submit_value(reporters[0], &query_data, 100);
+			now()
+		});
+		with_block_after(SubscriptionDisputeBuffer::get(), || {
+			on_idle();
+			assert_eq!(sent_xcm(), vec![push(query_id, timestamp, 100, reporters[0])]);
+		});
+
+		// Value within minimum interval of last value pushed is not pushed
+		with_block_after(HOURS, || submit_value(reporters[1], &query_data, 200));
+		with_block_after(SubscriptionDisputeBuffer::get(), || {
+			on_idle();
+			assert!(sent_xcm().is_empty());
+		});
+
+		let timestamp = with_block_after(HOURS, || {
+			submit_value(reporters[2], &query_data, 300);
+			now()
+		});
+		with_block_after(SubscriptionDisputeBuffer::get(), || {
+			on_idle();
+			assert_eq!(sent_xcm(), vec![push(query_id, timestamp, 300, reporters[2])]);
+		});
+	});
+}
+
+#[test]
+fn pushes_values_exceeding_deviation() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let subscriber: AccountIdOf<Test> = SIBLING_PARA_ID.into();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&subscriber, token(10));
+			subscribe(SIBLING_PARA_ID, query_id, 0, Permill::from_percent(10));
+		});
+
+		for (value, pushed) in [(100, true), (109, false), (91, false), (89, true), (98, true)] {
+			let timestamp = with_block_after(REPORTING_LOCK, || {
+				submit_value(reporter, &query_data, value);
+				now()
+			});
+			with_block_after(SubscriptionDisputeBuffer::get(), || {
+				on_idle();
+				if pushed {
+					assert_eq!(sent_xcm(), vec![push(query_id, timestamp, value, reporter)]);
+				} else {
+					assert!(sent_xcm().is_empty());
+				}
+			});
+		}
+	});
+}
+
+#[test]
+fn skips_values_exceeding_max_value_length() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let subscriber: AccountIdOf<Test> = SIBLING_PARA_ID.into();
+	let value: LargeValueOf<Test> =
+		vec![1; MaxLargeValueLength::get() as usize].try_into().unwrap();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let subscription_id = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&subscriber, token(10));
+			Balances::set_balance(&reporter, token(10));
+			let subscription_id = subscribe(SIBLING_PARA_ID, query_id, 0, Permill::zero());
+			assert_ok!(Tellor::set_large_values(Origin::Governance.into(), query_id, true));
+			assert_ok!(Tellor::note_value(RuntimeOrigin::signed(reporter), value.clone()));
+			let reference =
+				ValueReference { hash: keccak_256(value.as_ref()).into(), len: value.len() as u32 };
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				reference.encode().try_into().unwrap(),
+				0,
+				query_data.clone()
+			));
+			subscription_id
+		});
+
+		// Large value cannot be received by subscriber, so is not pushed
+		with_block_after(SubscriptionDisputeBuffer::get(), || {
+			on_idle();
+			assert!(sent_xcm().is_empty());
+			assert_eq!(Tellor::get_subscription(subscription_id).unwrap().last_pushed, None);
+			assert_eq!(Balances::balance(&subscriber), token(10));
+		});
+	});
+}
+
+#[test]
+fn retries_failed_pushes() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let subscriber: AccountIdOf<Test> = SIBLING_PARA_ID.into();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let subscription_id = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			subscribe(SIBLING_PARA_ID, query_id, 0, Permill::zero())
+		});
+
+		let timestamp = with_block(|| {
+			submit_value(reporter, &query_data, 100);
+			now()
+		});
+
+		// Subscriber unable to pay subscription fee
+		for attempts in 1..=MAX_PUSH_ATTEMPTS {
+			with_block_after(SubscriptionDisputeBuffer::get(), || {
+				on_idle();
+				assert!(sent_xcm().is_empty());
+				System::assert_last_event(
+					Event::ValuePushFailed { subscription_id, query_id, timestamp, attempts }
+						.into(),
+				);
+				assert_eq!(
+					Tellor::get_subscription(subscription_id).unwrap().failed,
+					Some((timestamp, attempts))
+				);
+			});
+		}
+
+		// Value skipped once attempts exhausted
+		with_block(|| {
+			Balances::set_balance(&subscriber, token(10));
+			on_idle();
+			assert!(sent_xcm().is_empty());
+			assert_eq!(Balances::balance(&subscriber), token(10));
+		});
+
+		// Subsequent value pushed once subscriber funded
+		let timestamp = with_block_after(REPORTING_LOCK, || {
+			submit_value(reporter, &query_data, 101);
+			now()
+		});
+		with_block_after(SubscriptionDisputeBuffer::get(), || {
+			on_idle();
+			assert_eq!(sent_xcm(), vec![push(query_id, timestamp, 101, reporter)]);
+			let subscription = Tellor::get_subscription(subscription_id).unwrap();
+			assert_eq!(subscription.last_pushed, Some((timestamp, Some(U256::from(101)))));
+			assert_eq!(subscription.failed, None);
+		});
+	});
+}
+
+#[test]
+fn pushes_removals() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporters = [1, 3];
+	let disputer = 2;
+	let subscriber: AccountIdOf<Test> = SIBLING_PARA_ID.into();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let subscription_id = with_block(|| {
+			for reporter in reporters {
+				deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			}
+			deposit_stake(disputer, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&disputer, token(1_000));
+			Balances::set_balance(&subscriber, token(10));
+			subscribe(SIBLING_PARA_ID, query_id, 0, Permill::zero())
+		});
+
+		let timestamp = with_block(|| {
+			submit_value(reporters[0], &query_data, 100);
+			now()
+		});
+		with_block_after(SubscriptionDisputeBuffer::get(), || {
+			on_idle();
+			assert_eq!(sent_xcm(), vec![push(query_id, timestamp, 100, reporters[0])]);
+		});
+
+		// Removal of value pushed is pushed to subscriber
+		with_block(|| {
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(disputer),
+				query_id,
+				timestamp,
+				None
+			));
+		});
+		with_block(|| {
+			on_idle();
+			assert_eq!(sent_xcm(), vec![push_removal(query_id, timestamp)]);
+			System::assert_has_event(
+				Event::ValueRemovalPushed {
+					subscription_id,
+					para_id: SIBLING_PARA_ID,
+					query_id,
+					timestamp,
+				}
+				.into(),
+			);
+			assert_eq!(Balances::balance(&subscriber), token(10) - SubscriptionFee::get() * 2);
+			assert_eq!(Tellor::get_subscription(subscription_id).unwrap().removals, 1);
+		});
+
+		// Removal of value not yet pushed is skipped
+		let timestamp = with_block(|| {
+			submit_value(reporters[1], &query_data, 101);
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(disputer),
+				query_id,
+				now(),
+				None
+			));
+			now()
+		});
+		with_block_after(SubscriptionDisputeBuffer::get(), || {
+			on_idle();
+			assert!(sent_xcm().is_empty());
+			let subscription = Tellor::get_subscription(subscription_id).unwrap();
+			assert_eq!(subscription.removals, 2);
+			assert!(subscription.last_pushed.map_or(false, |(last, _)| last < timestamp));
+		});
+	});
+}
+
+#[test]
+fn retains_bounded_removals() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let disputer = 2;
+	let subscriber: AccountIdOf<Test> = SIBLING_PARA_ID.into();
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let subscription_id = with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			deposit_stake(disputer, MINIMUM_STAKE_AMOUNT, Address::random());
+			Balances::set_balance(&disputer, token(1_000));
+			Balances::set_balance(&subscriber, token(10));
+			subscribe(SIBLING_PARA_ID, query_id, 0, Permill::zero())
+		});
+
+		let timestamp = with_block(|| {
+			submit_value(reporter, &query_data, 100);
+			now()
+		});
+		with_block_after(SubscriptionDisputeBuffer::get(), || {
+			on_idle();
+			assert_eq!(sent_xcm(), vec![push(query_id, timestamp, 100, reporter)]);
+		});
+
+		with_block(|| {
+			// Simulate the maximum number of removals pending delivery
+			RemovalCount::insert(query_id, MAX_PENDING_REMOVALS);
+			Removals::insert(query_id, 0, timestamp);
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(disputer),
+				query_id,
+				timestamp,
+				None
+			));
+			// Oldest removal no longer retained
+			assert_eq!(RemovalCount::get(query_id), MAX_PENDING_REMOVALS + 1);
+			assert_eq!(Removals::get(query_id, 0), None);
+			assert_eq!(Removals::get(query_id, MAX_PENDING_REMOVALS), Some(timestamp));
+		});
+
+		// Removals no longer retained are skipped
+		with_block(|| {
+			on_idle();
+			assert!(sent_xcm().is_empty());
+			assert_eq!(Tellor::get_subscription(subscription_id).unwrap().removals, 2);
+		});
+	});
+}
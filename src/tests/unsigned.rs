@@ -0,0 +1,255 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{constants::REPORTING_LOCK, types::ReportPayloadOf, Config, ReportPayload, MINUTES};
+use codec::Encode;
+use frame_support::pallet_prelude::{
+	InvalidTransaction, TransactionSource, TransactionValidity, ValidTransaction, ValidateUnsigned,
+};
+
+type PalletId = <Test as Config>::PalletId;
+type UnsignedLongevity = <Test as Config>::UnsignedLongevity;
+type UnsignedPriority = <Test as Config>::UnsignedPriority;
+
+fn payload(
+	reporter: AccountIdOf<Test>,
+	query_id: QueryId,
+	value: ValueOf<Test>,
+	nonce: u32,
+	valid_until: Timestamp,
+) -> ReportPayloadOf<Test> {
+	ReportPayload { reporter, query_id, value, nonce, valid_until }
+}
+
+fn sign(payload: &ReportPayloadOf<Test>) -> TestSignature {
+	TestSignature(payload.reporter, Tellor::report_payload_message(payload))
+}
+
+fn validate(
+	payload: ReportPayloadOf<Test>,
+	query_data: QueryDataOf<Test>,
+	signature: TestSignature,
+) -> TransactionValidity {
+	Tellor::validate_unsigned(
+		TransactionSource::External,
+		&crate::Call::submit_value_unsigned { payload, query_data, signature },
+	)
+}
+
+#[test]
+fn submit_value_unsigned() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			let payload = payload(reporter, query_id, uint_value(100), 0, now() + MINUTES);
+			let signature = sign(&payload);
+			assert_noop!(
+				Tellor::submit_value_unsigned(
+					RuntimeOrigin::signed(2),
+					payload.clone(),
+					query_data.clone(),
+					signature.clone()
+				),
+				BadOrigin
+			);
+
+			assert_ok!(Tellor::submit_value_unsigned(
+				RuntimeOrigin::none(),
+				payload.clone(),
+				query_data.clone(),
+				signature.clone()
+			));
+			System::assert_last_event(
+				Event::NewReport {
+					query_id,
+					time: now(),
+					value: uint_value(100),
+					nonce: 0,
+					query_data: query_data.clone(),
+					reporter,
+				}
+				.into(),
+			);
+			assert_eq!(Tellor::retrieve_data(query_id, now()), Some(uint_value(100)));
+			assert_eq!(Tellor::get_reports_submitted_by_address(&reporter), 1);
+			assert_eq!(Tellor::get_reporter_last_timestamp(reporter), Some(now()));
+
+			// Payload cannot be replayed
+			assert_noop!(
+				Tellor::submit_value_unsigned(
+					RuntimeOrigin::none(),
+					payload,
+					query_data.clone(),
+					signature
+				),
+				Error::InvalidNonce
+			);
+		});
+
+		with_block_after(REPORTING_LOCK, || {
+			let payload = payload(reporter, query_id, uint_value(101), 1, now() - 1);
+			assert_noop!(
+				Tellor::submit_value_unsigned(
+					RuntimeOrigin::none(),
+					payload.clone(),
+					query_data.clone(),
+					sign(&payload)
+				),
+				Error::PayloadExpired
+			);
+		});
+	});
+}
+
+#[test]
+fn validate_unsigned() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			let payload = payload(reporter, query_id, uint_value(100), 0, now() + MINUTES);
+			assert_eq!(
+				validate(payload.clone(), query_data.clone(), sign(&payload)),
+				Err(InvalidTransaction::BadSigner.into())
+			);
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+
+			assert_eq!(
+				validate(payload.clone(), query_data.clone(), sign(&payload)),
+				Ok(ValidTransaction {
+					priority: UnsignedPriority::get() + 1,
+					requires: vec![],
+					provides: vec![
+						("Tellor", reporter).encode(),
+						("Tellor", (query_id, 0u32)).encode()
+					],
+					longevity: UnsignedLongevity::get(),
+					propagate: true,
+				})
+			);
+
+			// Signature must be from reporter, over the payload
+			assert_eq!(
+				validate(
+					payload.clone(),
+					query_data.clone(),
+					TestSignature(another_reporter, Tellor::report_payload_message(&payload))
+				),
+				Err(InvalidTransaction::BadProof.into())
+			);
+			assert_eq!(
+				validate(
+					payload.clone(),
+					query_data.clone(),
+					sign(&ReportPayload { value: uint_value(101), ..payload.clone() })
+				),
+				Err(InvalidTransaction::BadProof.into())
+			);
+
+			// Signature must be bound to this pallet and chain, so cannot be replayed elsewhere
+			assert_eq!(
+				validate(
+					payload.clone(),
+					query_data.clone(),
+					TestSignature(reporter, payload.encode())
+				),
+				Err(InvalidTransaction::BadProof.into())
+			);
+			let genesis_hash = System::block_hash(0);
+			for (genesis_hash, pallet_id, pallet_index) in [
+				// Another chain
+				(H256::random(), PalletId::get(), PALLET_INDEX),
+				// Another instance of the pallet on this chain
+				(genesis_hash, frame_support::PalletId(*b"py/other"), PALLET_INDEX),
+				(genesis_hash, PalletId::get(), PALLET_INDEX + 1),
+			] {
+				let message =
+					(&b"tellor:report"[..], genesis_hash, pallet_id, pallet_index, &payload);
+				assert_eq!(
+					validate(
+						payload.clone(),
+						query_data.clone(),
+						TestSignature(reporter, message.encode())
+					),
+					Err(InvalidTransaction::BadProof.into())
+				);
+			}
+			assert_eq!(
+				Tellor::report_payload_message(&payload),
+				(&b"tellor:report"[..], genesis_hash, PalletId::get(), PALLET_INDEX, &payload)
+					.encode()
+			);
+
+			// Stale, premature or invalid payloads
+			let stale = ReportPayload { valid_until: now() - 1, ..payload.clone() };
+			assert_eq!(
+				validate(stale.clone(), query_data.clone(), sign(&stale)),
+				Err(InvalidTransaction::Stale.into())
+			);
+			let future = ReportPayload { nonce: 1, ..payload.clone() };
+			assert_eq!(
+				validate(future.clone(), query_data.clone(), sign(&future)),
+				Err(InvalidTransaction::Future.into())
+			);
+			assert_eq!(
+				validate(payload.clone(), Default::default(), sign(&payload)),
+				Err(InvalidTransaction::Call.into())
+			);
+			assert_eq!(
+				Tellor::validate_unsigned(
+					TransactionSource::External,
+					&crate::Call::update_stake_amount {}
+				),
+				Err(InvalidTransaction::Call.into())
+			);
+
+			assert_ok!(Tellor::submit_value_unsigned(
+				RuntimeOrigin::none(),
+				payload.clone(),
+				query_data.clone(),
+				sign(&payload)
+			));
+			// Replayed payload
+			assert_eq!(
+				validate(payload.clone(), query_data.clone(), sign(&payload)),
+				Err(InvalidTransaction::Stale.into())
+			);
+			// Reporter time locked
+			let next = ReportPayload { nonce: 1, ..payload.clone() };
+			assert_eq!(
+				validate(next.clone(), query_data.clone(), sign(&next)),
+				Err(InvalidTransaction::Future.into())
+			);
+
+			// Larger stake increases priority and reduces longevity
+			deposit_stake(another_reporter, MINIMUM_STAKE_AMOUNT * 2, Address::random());
+			let payload = ReportPayload { reporter: another_reporter, ..next };
+			let validity = validate(payload.clone(), query_data.clone(), sign(&payload)).unwrap();
+			assert_eq!(validity.priority, UnsignedPriority::get() + 2);
+			assert_eq!(validity.longevity, UnsignedLongevity::get() / 2);
+		});
+	});
+}
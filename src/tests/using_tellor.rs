@@ -15,7 +15,10 @@
 // along with Tellor. If not, see <http://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::{constants::REPORTING_LOCK, UsingTellor};
+use crate::{
+	constants::{MINUTES, REPORTING_LOCK},
+	ReadPolicy, ReadRejection, SafeValue, UsingTellor,
+};
 use sp_core::bytes::from_hex;
 
 #[test]
@@ -771,3 +774,109 @@ fn get_reporter_by_timestamp() {
 		});
 	});
 }
+
+#[test]
+fn get_safe_value() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let another_reporter = 2;
+	let policy = ReadPolicy {
+		dispute_buffer: 15 * MINUTES,
+		max_age: REPORTING_LOCK,
+		min_reporters: 1,
+		max_open_disputes: 0,
+	};
+
+	let mut ext = new_test_ext();
+
+	// Prerequisites
+	ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			deposit_stake(another_reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+		})
+	});
+
+	ext.execute_with(|| {
+		let timestamp_1 = with_block(|| {
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(150),
+				0,
+				query_data.clone(),
+			));
+			// Value still within dispute buffer
+			assert_eq!(Tellor::get_safe_value(query_id, policy), Err(ReadRejection::NoValue));
+			now()
+		});
+
+		let timestamp_2 = with_block_after(15 * MINUTES, || {
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(another_reporter),
+				query_id,
+				uint_value(160),
+				1,
+				query_data.clone(),
+			));
+			assert_eq!(
+				Tellor::get_safe_value(query_id, policy),
+				Ok(SafeValue {
+					value: uint_value(150).into_inner(),
+					timestamp: timestamp_1,
+					reporter
+				})
+			);
+			assert_eq!(
+				Tellor::get_safe_value(query_id, ReadPolicy { max_age: 15 * MINUTES, ..policy }),
+				Err(ReadRejection::Stale { timestamp: timestamp_1 })
+			);
+			assert_eq!(
+				Tellor::get_safe_value(query_id, ReadPolicy { min_reporters: 2, ..policy }),
+				Err(ReadRejection::InsufficientReporters { reporters: 1 })
+			);
+			now()
+		});
+
+		with_block_after(15 * MINUTES, || {
+			let policy = ReadPolicy { min_reporters: 2, ..policy };
+			assert_eq!(
+				Tellor::get_safe_value(query_id, policy),
+				Ok(SafeValue {
+					value: uint_value(160).into_inner(),
+					timestamp: timestamp_2,
+					reporter: another_reporter
+				})
+			);
+
+			Balances::set_balance(&reporter, token(1_000));
+			assert_ok!(Tellor::begin_dispute(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				timestamp_2,
+				None
+			));
+			assert_eq!(
+				Tellor::get_safe_value(query_id, policy),
+				Err(ReadRejection::TooManyOpenDisputes { open_disputes: 1 })
+			);
+			// Disputed value is skipped, leaving only a single reporter within the window
+			assert_eq!(
+				Tellor::get_safe_value(query_id, ReadPolicy { max_open_disputes: 1, ..policy }),
+				Err(ReadRejection::InsufficientReporters { reporters: 1 })
+			);
+			assert_eq!(
+				Tellor::get_safe_value(
+					query_id,
+					ReadPolicy { max_open_disputes: 1, min_reporters: 1, ..policy }
+				),
+				Ok(SafeValue {
+					value: uint_value(150).into_inner(),
+					timestamp: timestamp_1,
+					reporter
+				})
+			);
+		});
+	});
+}
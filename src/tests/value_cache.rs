@@ -0,0 +1,258 @@
+// Copyright 2023 Tellor Inc.
+// This file is part of Tellor.
+
+// Tellor is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tellor is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tellor. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+	constants::{MIN_RETENTION_PERIOD, REPORTING_LOCK},
+	Config, UsingTellor,
+};
+
+type MaxCachedValues = <Test as Config>::MaxCachedValues;
+type ReportedValueData = crate::ReportedValueData<Test>;
+type ValueCaches = crate::ValueCaches<Test>;
+
+fn cached_values(query_id: QueryId) -> Option<Vec<(Timestamp, ValueOf<Test>)>> {
+	ValueCaches::get(query_id).map(|cached| cached.into_inner())
+}
+
+// Submits a series of values, returning their timestamps.
+fn submit_values(
+	reporter: AccountIdOf<Test>,
+	query_data: &QueryDataOf<Test>,
+	values: impl IntoIterator<Item = u32>,
+) -> Vec<Timestamp> {
+	let query_id = keccak_256(query_data.as_ref()).into();
+	values
+		.into_iter()
+		.map(|value| {
+			with_block_after(REPORTING_LOCK, || {
+				assert_ok!(Tellor::submit_value(
+					RuntimeOrigin::signed(reporter),
+					query_id,
+					uint_value(value),
+					0,
+					query_data.clone()
+				));
+				now()
+			})
+		})
+		.collect()
+}
+
+#[test]
+fn set_value_cache() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random()));
+		let timestamps = submit_values(reporter, &query_data, 1..=7);
+
+		with_block(|| {
+			assert_noop!(
+				Tellor::set_value_cache(RuntimeOrigin::signed(1), query_id, true),
+				BadOrigin
+			);
+
+			// Cache is seeded with the latest values
+			assert_ok!(Tellor::set_value_cache(Origin::Governance.into(), query_id, true));
+			System::assert_last_event(Event::ValueCacheSet { query_id, enabled: true }.into());
+			let max = MaxCachedValues::get() as usize;
+			assert_eq!(
+				cached_values(query_id),
+				Some((3..=7).map(|v| (timestamps[v - 1], uint_value(v as u32))).collect())
+			);
+			assert_eq!(cached_values(query_id).unwrap().len(), max);
+
+			assert_ok!(Tellor::set_value_cache(Origin::Governance.into(), query_id, false));
+			System::assert_last_event(Event::ValueCacheSet { query_id, enabled: false }.into());
+			assert_eq!(cached_values(query_id), None);
+
+			// Cache of query identifier without values is empty
+			let query_id = H256::random();
+			assert_ok!(Tellor::set_value_cache(Origin::Governance.into(), query_id, true));
+			assert_eq!(cached_values(query_id), Some(vec![]));
+		});
+	});
+}
+
+#[test]
+fn caches_reported_values() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::set_value_cache(Origin::Governance.into(), query_id, true));
+		});
+
+		let timestamps = submit_values(reporter, &query_data, 1..=3);
+		assert_eq!(
+			cached_values(query_id),
+			Some((1..=3).map(|v| (timestamps[v - 1], uint_value(v as u32))).collect())
+		);
+
+		// Oldest values are evicted once full
+		let timestamps = [timestamps, submit_values(reporter, &query_data, 4..=8)].concat();
+		assert_eq!(
+			cached_values(query_id),
+			Some((4..=8).map(|v| (timestamps[v - 1], uint_value(v as u32))).collect())
+		);
+	});
+}
+
+#[test]
+fn repairs_cache_on_value_removal() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::set_value_cache(Origin::Governance.into(), query_id, true));
+		});
+		let timestamps = submit_values(reporter, &query_data, 1..=8);
+		let expected = |values: &[usize]| {
+			Some(values.iter().map(|v| (timestamps[v - 1], uint_value(*v as u32))).collect())
+		};
+
+		with_block(|| {
+			// Removed value is replaced by the previous non-disputed value
+			assert_ok!(Tellor::remove_value(query_id, timestamps[5]));
+			assert_eq!(cached_values(query_id), expected(&[3, 4, 5, 7, 8]));
+
+			// Removal of the oldest cached value
+			assert_ok!(Tellor::remove_value(query_id, timestamps[2]));
+			assert_eq!(cached_values(query_id), expected(&[2, 4, 5, 7, 8]));
+
+			// Removal of the latest value
+			assert_ok!(Tellor::remove_value(query_id, timestamps[7]));
+			assert_eq!(cached_values(query_id), expected(&[1, 2, 4, 5, 7]));
+
+			// Removal of the earliest value leaves no previous value to replace it
+			assert_ok!(Tellor::remove_value(query_id, timestamps[0]));
+			assert_eq!(cached_values(query_id), expected(&[2, 4, 5, 7]));
+		});
+	});
+}
+
+#[test]
+fn evicts_pruned_values() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::set_value_cache(Origin::Governance.into(), query_id, true));
+			assert_ok!(Tellor::set_retention_period(
+				Origin::Governance.into(),
+				query_id,
+				Some(MIN_RETENTION_PERIOD)
+			));
+		});
+		let timestamps = submit_values(reporter, &query_data, 1..=3);
+
+		with_block_after(MIN_RETENTION_PERIOD, || {
+			assert_ok!(Tellor::submit_value(
+				RuntimeOrigin::signed(reporter),
+				query_id,
+				uint_value(4),
+				0,
+				query_data.clone()
+			));
+			let latest = now();
+
+			// Values outside of the retention period are no longer cached once pruned
+			Tellor::prune_values(now(), Weight::MAX);
+			assert_eq!(Tellor::get_first_retained_index(query_id), 2);
+			assert_eq!(
+				cached_values(query_id),
+				Some(vec![(timestamps[2], uint_value(3)), (latest, uint_value(4))])
+			);
+		});
+	});
+}
+
+#[test]
+fn reads_from_cache() {
+	let query_data: QueryDataOf<Test> = spot_price("dot", "usd").try_into().unwrap();
+	let query_id = keccak_256(query_data.as_ref()).into();
+	let reporter = 1;
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		with_block(|| {
+			deposit_stake(reporter, MINIMUM_STAKE_AMOUNT, Address::random());
+			assert_ok!(Tellor::set_value_cache(Origin::Governance.into(), query_id, true));
+		});
+		let timestamps = submit_values(reporter, &query_data, 1..=8);
+
+		with_block(|| {
+			// Clear cached values within index, so that they can only be read from cache
+			for index in 3..8 {
+				ReportedValueData::insert(query_id, index, ValueOf::<Test>::default());
+			}
+
+			assert_eq!(
+				Tellor::get_data_before(query_id, timestamps[5]),
+				Some((uint_value(5), timestamps[4]))
+			);
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::get_data_after(query_id, timestamps[3]),
+				Some((uint_value(5).to_vec(), timestamps[4]))
+			);
+			assert_eq!(
+				Tellor::get_multiple_values_before(
+					query_id,
+					timestamps[7],
+					timestamps[7] - timestamps[4],
+					10
+				),
+				vec![
+					(uint_value(6).to_vec(), timestamps[5]),
+					(uint_value(7).to_vec(), timestamps[6])
+				]
+			);
+			assert_eq!(
+				Tellor::get_multiple_values_before(query_id, now(), now(), 2),
+				vec![
+					(uint_value(7).to_vec(), timestamps[6]),
+					(uint_value(8).to_vec(), timestamps[7])
+				]
+			);
+
+			// Values prior to cache are read from index
+			assert_eq!(
+				Tellor::get_data_before(query_id, timestamps[2]),
+				Some((uint_value(2), timestamps[1]))
+			);
+			assert_eq!(
+				<Tellor as UsingTellor<AccountIdOf<Test>>>::get_data_after(query_id, timestamps[0]),
+				Some((uint_value(2).to_vec(), timestamps[1]))
+			);
+		});
+	});
+}
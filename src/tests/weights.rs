@@ -15,7 +15,11 @@
 // along with Tellor. If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-	constants::{MAX_AGGREGATE_VOTES_SENT_PER_BLOCK, MAX_ITERATIONS, MAX_VOTE_ROUNDS},
+	constants::{
+		MAX_AGGREGATE_VOTES_SENT_PER_BLOCK, MAX_FINALIZED_VALUES_PER_BLOCK,
+		MAX_HEALTH_CHECKS_PER_BLOCK, MAX_ITERATIONS, MAX_MIGRATED_VALUES_PER_BLOCK,
+		MAX_VOTE_ROUNDS,
+	},
 	mock::Test,
 	Config, WeightInfo,
 };
@@ -28,9 +32,14 @@ use frame_support::{
 };
 use sp_runtime::Perbill;
 
+type MaxCachedValues = <Test as Config>::MaxCachedValues;
 type MaxClaimTimestamps = <Test as Config>::MaxClaimTimestamps;
 type MaxDisputedTimeSeries = <Test as Config>::MaxDisputedTimeSeries;
+type MaxLargeValueLength = <Test as Config>::MaxLargeValueLength;
+type MaxPendingRequests = <Test as Config>::MaxPendingRequests;
 type MaxQueryDataLength = <Test as Config>::MaxQueryDataLength;
+type MaxSubmissionsPerBatch = <Test as Config>::MaxSubmissionsPerBatch;
+type MaxSubscriptions = <Test as Config>::MaxSubscriptions;
 type MaxValueLength = <Test as Config>::MaxValueLength;
 type MaxVotes = <Test as Config>::MaxVotes;
 type Weights = <Test as Config>::WeightInfo;
@@ -90,6 +99,30 @@ fn verify() {
 		("report_slash", Weights::report_slash(), true),
 		("report_vote_tallied", Weights::report_vote_tallied(), true),
 		("report_vote_executed", Weights::report_vote_executed(MAX_VOTE_ROUNDS.into()), true),
+		("set_commit_reveal", Weights::set_commit_reveal(), true),
+		("commit_value", Weights::commit_value(), false),
+		(
+			"reveal_value",
+			Weights::reveal_value(MaxQueryDataLength::get(), MaxValueLength::get()),
+			false,
+		),
+		(
+			"submit_values",
+			Weights::submit_values(
+				MaxSubmissionsPerBatch::get(),
+				MaxQueryDataLength::get(),
+				MaxValueLength::get(),
+			),
+			false,
+		),
+		("set_risk_class", Weights::set_risk_class(), true),
+		("set_query_risk_class", Weights::set_query_risk_class(), true),
+		(
+			"submit_value_unsigned",
+			Weights::submit_value_unsigned(MaxQueryDataLength::get(), MaxValueLength::get()),
+			false,
+		),
+		("set_retention_period", Weights::set_retention_period(), true),
 		(
 			"on_initialize",
 			Weights::on_initialize(
@@ -99,6 +132,22 @@ fn verify() {
 			),
 			false,
 		),
+		("on_idle", Weights::on_idle(MaxPendingRequests::get()), false),
+		("migrate_values", Weights::migrate_values(MAX_MIGRATED_VALUES_PER_BLOCK), false),
+		("set_value_cache", Weights::set_value_cache(MaxCachedValues::get()), true),
+		("set_large_values", Weights::set_large_values(), true),
+		("note_value", Weights::note_value(MaxLargeValueLength::get()), false),
+		("retract_value", Weights::retract_value(MaxDisputedTimeSeries::get()), false),
+		("finalize_values", Weights::finalize_values(MAX_FINALIZED_VALUES_PER_BLOCK), false),
+		("set_health_thresholds", Weights::set_health_thresholds(), true),
+		("check_health", Weights::check_health(MAX_HEALTH_CHECKS_PER_BLOCK), false),
+		("set_emergency_value", Weights::set_emergency_value(), true),
+		("set_derived_feed", Weights::set_derived_feed(), true),
+		("subscribe", Weights::subscribe(), true),
+		("unsubscribe", Weights::unsubscribe(), true),
+		("push_values", Weights::push_values(MaxSubscriptions::get()), false),
+		("query_value", Weights::query_value(MaxLargeValueLength::get()), true),
+		("unnote_value", Weights::unnote_value(), false),
 	] {
 		println!("{function}: {weight:?}",);
 		println!(
@@ -14,10 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with Tellor. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::types::{QueryId, Timestamp, U256};
-use frame_support::weights::Weight;
-#[cfg(feature = "runtime-benchmarks")]
-use frame_support::BoundedVec;
+use crate::types::{
+	governance::VoteResult,
+	oracle::{QueryHealth, ReadPolicy, ReadRejection, ReadValue, SafeValue},
+	CallbackId, QueryId, RequestId, Timestamp, U256,
+};
+use frame_support::{dispatch::DispatchResult, weights::Weight, BoundedVec};
+use sp_runtime::DispatchError;
 use sp_std::vec::Vec;
 use xcm::latest::prelude::*;
 
@@ -55,6 +58,38 @@ pub trait UsingTellor<AccountId> {
 	/// The value retrieved and its timestamp, if found.
 	fn get_data_before(query_id: QueryId, timestamp: Timestamp) -> Option<(Vec<u8>, Timestamp)>;
 
+	/// Derives the value of a derived feed, registered by governance, from the latest values of its
+	/// operands under a joint read policy.
+	/// # Arguments
+	/// * `query_id` - The synthetic query identifier of the derived feed.
+	/// * `policy` - The policy the values of both operands must satisfy.
+	/// # Returns
+	/// The derived value (ABI-encoded as an unsigned integer) along with the timestamp of the
+	/// oldest operand, or the reason the value could not be derived.
+	fn get_derived_value(
+		query_id: QueryId,
+		policy: ReadPolicy,
+	) -> Result<(Vec<u8>, Timestamp), ReadRejection>;
+
+	/// Retrieves the latest finalized value for the query identifier before the specified timestamp,
+	/// being a value whose dispute window has elapsed without dispute.
+	/// # Arguments
+	/// * `query_id` - The query identifier to look up the value for.
+	/// * `timestamp` - The timestamp before which to search for the latest finalized value.
+	/// # Returns
+	/// The finalized value retrieved and its timestamp, if found.
+	fn get_finalized_value_before(
+		query_id: QueryId,
+		timestamp: Timestamp,
+	) -> Option<(Vec<u8>, Timestamp)>;
+
+	/// Retrieves the index of the earliest value for the query identifier which has not been pruned.
+	/// # Arguments
+	/// * `query_id` - The query identifier to look up the index for.
+	/// # Returns
+	/// The index of the earliest retained value, with all prior indices having been pruned.
+	fn get_first_retained_index(query_id: QueryId) -> u32;
+
 	/// Retrieves the latest index of data after the specified timestamp for the query identifier.
 	/// # Arguments
 	/// * `query_id` - The query identifier to look up the index for.
@@ -93,6 +128,14 @@ pub trait UsingTellor<AccountId> {
 	/// Count of the number of values received for the query identifier.
 	fn get_new_value_count_by_query_id(query_id: QueryId) -> u32;
 
+	/// Retrieves the current health of the query identifier, allowing consumers to halt automatically
+	/// once degraded or frozen.
+	/// # Arguments
+	/// * `query_id` - The query identifier to look up the health for.
+	/// # Returns
+	/// The health of the query identifier, which is always healthy without health thresholds.
+	fn get_query_health(query_id: QueryId) -> QueryHealth;
+
 	/// Returns the reporter who submitted a value for a query identifier at a specific time.
 	/// # Arguments
 	/// * `query_id` - The identifier of the specific data feed.
@@ -101,6 +144,33 @@ pub trait UsingTellor<AccountId> {
 	/// Identifier of the reporter who reported the value for the query identifier at the given timestamp.
 	fn get_reporter_by_timestamp(query_id: QueryId, timestamp: Timestamp) -> Option<AccountId>;
 
+	/// Retrieves the latest value for the query identifier which satisfies the supplied read policy.
+	/// # Arguments
+	/// * `query_id` - The query identifier to look up the value for.
+	/// * `policy` - The dispute buffer, maximum age, minimum distinct reporters and maximum open
+	/// disputes the value must satisfy.
+	/// # Returns
+	/// The value along with its timestamp and reporter, or the reason the value was rejected.
+	fn get_safe_value(
+		query_id: QueryId,
+		policy: ReadPolicy,
+	) -> Result<SafeValue<AccountId>, ReadRejection>;
+
+	/// Retrieves the latest value for the query identifier which satisfies the supplied read policy,
+	/// opting in to fall back on any emergency value published by governance when no healthy
+	/// reported value exists, being when the query identifier is frozen or no value satisfies the
+	/// policy.
+	/// # Arguments
+	/// * `query_id` - The query identifier to look up the value for.
+	/// * `policy` - The policy the reported value must satisfy.
+	/// # Returns
+	/// The value flagged as either reported or emergency, or the reason the reported value was
+	/// rejected when no emergency value exists.
+	fn get_safe_value_or_emergency(
+		query_id: QueryId,
+		policy: ReadPolicy,
+	) -> Result<ReadValue<AccountId>, ReadRejection>;
+
 	/// Gets the timestamp for the value based on their index.
 	/// # Arguments
 	/// * `query_id` - The query identifier to look up.
@@ -131,9 +201,206 @@ pub trait UsingTellor<AccountId> {
 	fn retrieve_data(query_id: QueryId, timestamp: Timestamp) -> Option<Vec<u8>>;
 }
 
+/// This trait helps pallets request data from Tellor, which is then delivered via `OnResponse`.
+pub trait RequestTellor<AccountId, Balance, MaxQueryDataLength>: UsingTellor<AccountId> {
+	/// Registers a request for data, to be delivered once the first eligible value has cleared the
+	/// dispute buffer.
+	/// # Arguments
+	/// * `requester` - The account registering the request.
+	/// * `query_id` - The query identifier of the requested data.
+	/// * `earliest_timestamp` - The earliest timestamp of a value eligible for delivery, no more than
+	///   the maximum request delay ahead of the current time.
+	/// * `dispute_buffer` - Amount of time a value must have been reported for before it is delivered,
+	///   no more than the maximum request delay.
+	/// * `callback` - Identifier supplied to `OnResponse`, used to route the response.
+	/// # Returns
+	/// The identifier of the request.
+	fn request_data(
+		requester: AccountId,
+		query_id: QueryId,
+		earliest_timestamp: Timestamp,
+		dispute_buffer: Timestamp,
+		callback: CallbackId,
+	) -> Result<RequestId, DispatchError>;
+
+	/// Tips for a query identifier, incentivising reporters to fulfil a request.
+	/// # Arguments
+	/// * `tipper` - The account funding the tip.
+	/// * `query_id` - Identifier of tipped data.
+	/// * `amount` - Amount to tip.
+	/// * `query_data` - The data used by reporters to fulfil the query.
+	fn tip(
+		tipper: AccountId,
+		query_id: QueryId,
+		amount: Balance,
+		query_data: BoundedVec<u8, MaxQueryDataLength>,
+	) -> DispatchResult;
+}
+
+/// Handler for responses to data requests.
+pub trait OnResponse {
+	/// Called when a value has been found for a request and has cleared the dispute buffer.
+	/// # Arguments
+	/// * `request_id` - The identifier of the request.
+	/// * `callback` - The callback identifier supplied with the request.
+	/// * `query_id` - The query identifier of the requested data.
+	/// * `value` - The value delivered.
+	/// * `timestamp` - The timestamp of the value.
+	/// # Returns
+	/// The weight consumed, which must not exceed the `weight` upper bound.
+	fn on_response(
+		request_id: RequestId,
+		callback: CallbackId,
+		query_id: QueryId,
+		value: Vec<u8>,
+		timestamp: Timestamp,
+	) -> Weight;
+
+	/// Called when a request times out before an eligible value is found.
+	/// # Arguments
+	/// * `request_id` - The identifier of the request.
+	/// * `callback` - The callback identifier supplied with the request.
+	/// * `query_id` - The query identifier of the requested data.
+	/// # Returns
+	/// The weight consumed, which must not exceed the `weight` upper bound.
+	fn on_timeout(request_id: RequestId, callback: CallbackId, query_id: QueryId) -> Weight;
+
+	/// The upper bound of the weight consumed by either of the above calls.
+	fn weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl OnResponse for Tuple {
+	fn on_response(
+		request_id: RequestId,
+		callback: CallbackId,
+		query_id: QueryId,
+		value: Vec<u8>,
+		timestamp: Timestamp,
+	) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::on_response(request_id, callback, query_id, value.clone(), timestamp)); )* );
+		weight
+	}
+
+	fn on_timeout(request_id: RequestId, callback: CallbackId, query_id: QueryId) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::on_timeout(request_id, callback, query_id)); )* );
+		weight
+	}
+
+	fn weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::weight()); )* );
+		weight
+	}
+}
+
+/// Handler for when a new value is reported to the oracle.
+pub trait OnNewReport<AccountId> {
+	/// Called after a new value has been stored.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the reported value.
+	/// * `timestamp` - The timestamp of the reported value.
+	/// * `reporter` - The reporter of the value.
+	/// # Returns
+	/// The weight consumed, which must not exceed the `weight` upper bound.
+	fn on_new_report(query_id: QueryId, timestamp: Timestamp, reporter: &AccountId) -> Weight;
+
+	/// The upper bound of the weight consumed by `on_new_report`, charged to the reporter.
+	fn weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId> OnNewReport<AccountId> for Tuple {
+	fn on_new_report(query_id: QueryId, timestamp: Timestamp, reporter: &AccountId) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::on_new_report(query_id, timestamp, reporter)); )* );
+		weight
+	}
+
+	fn weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::weight()); )* );
+		weight
+	}
+}
+
+/// Handler for when a reported value is removed from the oracle by a dispute, as well as the
+/// subsequent outcome of the dispute.
+pub trait OnValueRemoved {
+	/// Called after a disputed value has been removed.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the removed value.
+	/// * `timestamp` - The timestamp of the removed value.
+	/// # Returns
+	/// The weight consumed, which must not exceed the `weight` upper bound.
+	fn on_value_removed(query_id: QueryId, timestamp: Timestamp) -> Weight;
+
+	/// Called after the votes on the dispute of a removed value have been tallied.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the disputed value.
+	/// * `timestamp` - The timestamp of the disputed value.
+	/// * `result` - The outcome of the vote, as determined by governance.
+	/// # Returns
+	/// The weight consumed, which must not exceed the `weight` upper bound.
+	fn on_vote_tallied(query_id: QueryId, timestamp: Timestamp, result: VoteResult) -> Weight;
+
+	/// Called after the vote on the dispute of a removed value has been executed.
+	/// # Arguments
+	/// * `query_id` - The query identifier of the disputed value.
+	/// * `timestamp` - The timestamp of the disputed value.
+	/// * `result` - The final outcome of the vote.
+	/// # Returns
+	/// The weight consumed, which must not exceed the `weight` upper bound.
+	fn on_vote_executed(query_id: QueryId, timestamp: Timestamp, result: VoteResult) -> Weight;
+
+	/// The upper bound of the weight consumed by any one of the above calls, charged to the
+	/// corresponding dispatchable.
+	fn weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl OnValueRemoved for Tuple {
+	fn on_value_removed(query_id: QueryId, timestamp: Timestamp) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::on_value_removed(query_id, timestamp)); )* );
+		weight
+	}
+
+	fn on_vote_tallied(query_id: QueryId, timestamp: Timestamp, result: VoteResult) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::on_vote_tallied(query_id, timestamp, result)); )* );
+		weight
+	}
+
+	fn on_vote_executed(query_id: QueryId, timestamp: Timestamp, result: VoteResult) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::on_vote_executed(query_id, timestamp, result)); )* );
+		weight
+	}
+
+	fn weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::weight()); )* );
+		weight
+	}
+}
+
+/// Additional requirements of the runtime when the offchain worker is enabled.
+#[cfg(feature = "offchain-worker")]
+pub trait OffchainWorkerConfig<C>: frame_system::offchain::CreateSignedTransaction<C> {}
+#[cfg(feature = "offchain-worker")]
+impl<T: frame_system::offchain::CreateSignedTransaction<C>, C> OffchainWorkerConfig<C> for T {}
+/// Additional requirements of the runtime when the offchain worker is enabled.
+#[cfg(not(feature = "offchain-worker"))]
+pub trait OffchainWorkerConfig<C> {}
+#[cfg(not(feature = "offchain-worker"))]
+impl<T, C> OffchainWorkerConfig<C> for T {}
+
 /// Helper trait for benchmarks
 #[cfg(feature = "runtime-benchmarks")]
-pub trait BenchmarkHelper<AccountId, Balance, MaxQueryDataLength> {
+pub trait BenchmarkHelper<AccountId, Balance, MaxQueryDataLength, Signature> {
 	/// Set the current time.
 	/// # Arguments
 	/// * `time_in_secs` - Time in seconds
@@ -154,6 +421,14 @@ pub trait BenchmarkHelper<AccountId, Balance, MaxQueryDataLength> {
 	/// # Returns
 	/// Bytes of query data
 	fn get_staking_to_local_token_price_query_data() -> BoundedVec<u8, MaxQueryDataLength>;
+
+	/// Sign a message on behalf of the account.
+	/// # Arguments
+	/// * `account_id` - the signing account
+	/// * `message` - the message to be signed
+	/// # Returns
+	/// The signature of the message
+	fn sign(account_id: &AccountId, message: &[u8]) -> Signature;
 }
 
 // From: https://github.com/paritytech/polkadot/blob/b1cc6fa14330261a305d56be36c04e9c99518993/xcm/xcm-executor/src/traits/weight.rs#L34
@@ -15,32 +15,62 @@
 // along with Tellor. If not, see <http://www.gnu.org/licenses/>.
 
 use super::Config;
+use crate::constants::{MAX_HEALTH_FEEDS, REPORTING_LOCK};
+use ::xcm::latest::MultiAsset;
 use frame_support::pallet_prelude::*;
 pub(crate) use governance::Tally;
 pub use sp_core::U256;
 use sp_core::{H160, H256};
 pub(crate) use sp_runtime::traits::Keccak256;
-use sp_runtime::{traits::Convert, SaturatedConversion};
+use sp_runtime::{
+	traits::{Convert, Hash},
+	Percent, Permill, SaturatedConversion,
+};
 use sp_std::vec::Vec;
 
 pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 /// Address of a reporter on controller chain.
 pub type Address = H160;
+pub(crate) type ArchivedReportOf<T> = oracle::ArchivedReport<AccountIdOf<T>, ValueOf<T>>;
 /// TRB stake amount as reported from controller chain.
 pub type Tributes = U256;
 /// Local currency used for onetime tips, funding feeds, accumulated rewards and dispute fees.
 pub(crate) type BalanceOf<T> = <T as Config>::Balance;
 pub(crate) type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
+pub(crate) type CachedValuesOf<T> =
+	BoundedVec<(Timestamp, ValueOf<T>), <T as Config>::MaxCachedValues>;
+/// Identifier supplied by a consumer with a data request, used to route the response.
+pub type CallbackId = u32;
+/// Hash of a committed value and salt, used by commit-reveal submission.
+pub type CommitHash = H256;
+pub(crate) type CommitOf<T> = oracle::Commit<BlockNumberOf<T>>;
 pub type DisputeId = H256;
 pub(crate) type DisputeOf<T> = governance::Dispute<AccountIdOf<T>, ValueOf<T>>;
+pub(crate) type EmergencyValueOf<T> = oracle::EmergencyValue<ValueOf<T>>;
 pub type FeedId = H256;
 pub(crate) type FeedOf<T> = autopay::Feed<BalanceOf<T>>;
+/// The intervals of funded feeds of a query identifier, shortest first.
+pub(crate) type FundedFeedIntervals = BoundedVec<(Timestamp, FeedId), ConstU32<MAX_HEALTH_FEEDS>>;
+pub(crate) type IndexedValueOf<T> = oracle::IndexedValue<AccountIdOf<T>>;
+pub(crate) type LargeValueOf<T> = BoundedVec<u8, <T as Config>::MaxLargeValueLength>;
 pub(crate) type Nonce = u32;
 pub(crate) type ParaId = u32;
+/// Hash of a value noted as a preimage, referenced by values of large value queries.
+pub type PreimageHash = H256;
+pub(crate) type PreimageOf<T> = oracle::Preimage<AccountIdOf<T>, BalanceOf<T>>;
 pub(crate) type QueryDataOf<T> = BoundedVec<u8, <T as Config>::MaxQueryDataLength>;
 pub type QueryId = H256;
 pub(crate) type ReportOf<T> = oracle::Report<AccountIdOf<T>, BlockNumberOf<T>>;
+pub(crate) type ReportPayloadOf<T> = oracle::ReportPayload<AccountIdOf<T>, ValueOf<T>>;
+pub type RequestId = u64;
+/// Identifier of a risk class, as assigned to query identifiers by governance.
+pub type RiskClassId = u8;
+pub(crate) type RequestOf<T> = oracle::Request<AccountIdOf<T>>;
 pub(crate) type StakeInfoOf<T> = oracle::StakeInfo<BalanceOf<T>>;
+/// Identifier of a subscription of a remote parachain to the values of a query identifier.
+pub type SubscriptionId = u64;
+/// A value submission: query identifier, value, nonce and query data.
+pub(crate) type SubmissionOf<T> = (QueryId, ValueOf<T>, Nonce, QueryDataOf<T>);
 pub type Timestamp = u64;
 pub(crate) type TipOf<T> = autopay::Tip<BalanceOf<T>>;
 pub(crate) type ValueOf<T> = BoundedVec<u8, <T as Config>::MaxValueLength>;
@@ -81,6 +111,180 @@ pub(crate) mod autopay {
 pub(crate) mod oracle {
 	use super::*;
 
+	/// A reported value archived to offchain storage via offchain indexing, retained after the value
+	/// has been pruned from the oracle.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct ArchivedReport<AccountId, Value> {
+		/// The time the value was reported.
+		pub timestamp: Timestamp,
+		/// The reported value.
+		pub value: Value,
+		/// The reporter of the value.
+		pub reporter: AccountId,
+		/// Whether the reported value has been disputed.
+		pub is_disputed: bool,
+	}
+
+	/// A commitment to a value, to be revealed within the reveal window.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Commit<BlockNumber> {
+		/// The hash of the value and salt.
+		pub(crate) hash: CommitHash,
+		/// The time of the commitment.
+		pub(crate) timestamp: Timestamp,
+		/// The block number of the commitment.
+		pub(crate) block_number: BlockNumber,
+	}
+
+	/// A value derived on-chain from the latest values of two query identifiers, as registered by
+	/// governance. Values are interpreted as unsigned integers with the specified decimals.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct DerivedFeed {
+		/// The query identifier of the first operand.
+		pub query_id_a: QueryId,
+		/// The number of decimals of values of the first operand.
+		pub decimals_a: u8,
+		/// The operation applied to the operands.
+		pub operation: DerivedOperation,
+		/// The query identifier of the second operand.
+		pub query_id_b: QueryId,
+		/// The number of decimals of values of the second operand.
+		pub decimals_b: u8,
+		/// The number of decimals of the derived value.
+		pub decimals: u8,
+	}
+
+	impl DerivedFeed {
+		/// The synthetic query identifier under which the derived value is exposed.
+		pub fn query_id(&self) -> QueryId {
+			Keccak256::hash(&(b"tellor::derived", self).encode())
+		}
+
+		/// Derives a value from the values of the operands, returning `None` when the value cannot
+		/// be represented.
+		pub(crate) fn derive(&self, a: U256, b: U256) -> Option<U256> {
+			let unit = U256::from(10).checked_pow(self.decimals.into())?;
+			let a = Self::scale(a, self.decimals_a, self.decimals)?;
+			let b = Self::scale(b, self.decimals_b, self.decimals)?;
+			match self.operation {
+				DerivedOperation::Add => a.checked_add(b),
+				DerivedOperation::Subtract => a.checked_sub(b),
+				DerivedOperation::Multiply => a.checked_mul(b)?.checked_div(unit),
+				DerivedOperation::Divide => a.checked_mul(unit)?.checked_div(b),
+			}
+		}
+
+		// Scales a value from one number of decimals to another.
+		fn scale(value: U256, from: u8, to: u8) -> Option<U256> {
+			let factor = U256::from(10).checked_pow(from.abs_diff(to).into())?;
+			if to >= from {
+				value.checked_mul(factor)
+			} else {
+				value.checked_div(factor)
+			}
+		}
+	}
+
+	/// An operation combining the values of the operands of a derived feed.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum DerivedOperation {
+		/// The sum of the operands.
+		Add,
+		/// The difference of the operands, which cannot be negative.
+		Subtract,
+		/// The product of the operands.
+		Multiply,
+		/// The quotient of the operands, e.g. a cross rate.
+		Divide,
+	}
+
+	/// An emergency value published by governance, which is never treated as a reported value.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct EmergencyValue<Value> {
+		/// The emergency value.
+		pub value: Value,
+		/// The time the value was published.
+		pub timestamp: Timestamp,
+		/// The time at which the value expires.
+		pub expires: Timestamp,
+	}
+
+	/// The limits at which a query identifier reaches a health state.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct HealthLimits {
+		/// Number of open disputes on the query identifier at which the limit is reached (0 to disable).
+		pub open_disputes: u32,
+		/// Proportion of recent values disputed at which the limit is reached (0 to disable).
+		pub disputed_ratio: Percent,
+		/// Number of shortest funded feed intervals elapsed since the last value at which the limit is
+		/// reached (0 to disable).
+		pub stale_intervals: u32,
+		/// Minimum number of distinct reporters of recent (non-disputed) values, below which the limit
+		/// is reached.
+		pub min_reporters: u32,
+	}
+
+	/// The thresholds at which a query identifier is considered degraded or frozen, as managed by governance.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct HealthThresholds {
+		/// The limits at which the query identifier is considered degraded.
+		pub degraded: HealthLimits,
+		/// The limits at which the query identifier is considered frozen.
+		pub frozen: HealthLimits,
+	}
+
+	/// The timestamp, reporter and dispute status of a reported value, stored by index. The value
+	/// itself is stored separately, so that searches by timestamp do not read it.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct IndexedValue<AccountId> {
+		/// The time the value was reported.
+		pub(crate) timestamp: Timestamp,
+		/// The reporter of the value.
+		pub(crate) reporter: AccountId,
+		/// Whether the reported value has been disputed.
+		pub(crate) is_disputed: bool,
+		/// The index of the last non-disputed value reported prior to the value, once disputed.
+		pub(crate) previous: Option<u32>,
+	}
+
+	/// A value noted as a preimage, along with the number of reported values referencing it.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Preimage<AccountId, Balance> {
+		/// The length of the value.
+		pub(crate) len: u32,
+		/// The number of reported values referencing the value.
+		pub(crate) references: u32,
+		/// The account which noted the value.
+		pub(crate) depositor: AccountId,
+		/// The amount held from the depositor whilst the value is noted.
+		pub(crate) deposit: Balance,
+	}
+
+	/// The health of a query identifier, as determined by its health thresholds.
+	#[derive(
+		Clone,
+		Copy,
+		Default,
+		Encode,
+		Decode,
+		PartialEq,
+		Eq,
+		PartialOrd,
+		Ord,
+		RuntimeDebug,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum QueryHealth {
+		/// Values can be relied upon.
+		#[default]
+		Healthy,
+		/// Values should be treated with caution.
+		Degraded,
+		/// Values should not be relied upon.
+		Frozen,
+	}
+
 	/// Information relating to a reported value.
 	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub struct Report<AccountId, BlockNumber> {
@@ -96,6 +300,21 @@ pub(crate) mod oracle {
 		pub(crate) previous: Option<Timestamp>,
 	}
 
+	/// A value report signed by a staked reporter, which can be submitted by anyone without fees.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ReportPayload<AccountId, Value> {
+		/// The reporter who signed the payload.
+		pub reporter: AccountId,
+		/// Identifier of the specific data feed.
+		pub query_id: QueryId,
+		/// Value the reporter submits to the oracle.
+		pub value: Value,
+		/// The current value count for the query identifier.
+		pub nonce: Nonce,
+		/// Time after which the payload can no longer be submitted.
+		pub valid_until: Timestamp,
+	}
+
 	#[derive(
 		Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
 	)]
@@ -127,6 +346,130 @@ pub(crate) mod oracle {
 			Self { address, ..Default::default() }
 		}
 	}
+
+	/// The policy applied when safely reading a value from the oracle.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ReadPolicy {
+		/// Amount of time a value must have been reported for before it is read, to allow time for disputes.
+		pub dispute_buffer: Timestamp,
+		/// Maximum age of a value, relative to the current time.
+		pub max_age: Timestamp,
+		/// Minimum number of distinct reporters of non-disputed values within the window.
+		pub min_reporters: u32,
+		/// Maximum number of open disputes on the query identifier.
+		pub max_open_disputes: u32,
+	}
+
+	/// The reason a value was rejected by a read policy.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum ReadRejection {
+		/// No (non-disputed) value exists before the dispute buffer.
+		NoValue,
+		/// The latest value is older than the maximum age.
+		Stale { timestamp: Timestamp },
+		/// Too few distinct reporters reported within the window.
+		InsufficientReporters { reporters: u32 },
+		/// Too many disputes are open on the query identifier.
+		TooManyOpenDisputes { open_disputes: u32 },
+		/// The query identifier is frozen by its health thresholds.
+		QueryFrozen,
+		/// No derived feed exists for the query identifier.
+		NotDerivedFeed,
+		/// The derived value cannot be represented, e.g. when dividing by zero.
+		DerivationFailed,
+	}
+
+	/// A value read with an emergency fallback, flagged by its source.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum ReadValue<AccountId> {
+		/// A reported value which satisfied the read policy.
+		Reported(SafeValue<AccountId>),
+		/// An emergency value published by governance, as no healthy reported value exists.
+		Emergency { value: Vec<u8>, timestamp: Timestamp, expires: Timestamp },
+	}
+
+	/// The reporter requirements of a class of query identifiers, as managed by governance.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct RiskClass {
+		/// Base amount of time before a reporter is able to submit a value again.
+		pub reporting_lock: Timestamp,
+		/// Multiple of the stake amount required to report, which is also the amount slashed on dispute.
+		pub stake_multiple: u32,
+	}
+
+	impl Default for RiskClass {
+		fn default() -> Self {
+			Self { reporting_lock: REPORTING_LOCK, stake_multiple: 1 }
+		}
+	}
+
+	/// A request for data, delivered to the consumer once the first eligible value clears the dispute buffer.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Request<AccountId> {
+		/// The identifier of the request.
+		pub(crate) id: RequestId,
+		/// The account which registered the request.
+		pub(crate) requester: AccountId,
+		/// The query identifier of the requested data.
+		pub(crate) query_id: QueryId,
+		/// The earliest timestamp of a value eligible for delivery.
+		pub(crate) earliest_timestamp: Timestamp,
+		/// Amount of time a value must have been reported for before it is delivered, to allow time for disputes.
+		pub(crate) dispute_buffer: Timestamp,
+		/// The callback identifier supplied by the consumer.
+		pub(crate) callback: CallbackId,
+		/// Time after which the request times out if not yet fulfilled.
+		pub(crate) deadline: Timestamp,
+	}
+
+	/// A subscription of a remote parachain to the values of a query identifier, which are pushed to
+	/// the parachain via XCM once they clear the dispute buffer.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Subscription {
+		/// The identifier of the subscribing parachain.
+		pub(crate) para_id: ParaId,
+		/// The query identifier of the subscribed data.
+		pub(crate) query_id: QueryId,
+		/// Minimum amount of time between the timestamps of values pushed.
+		pub(crate) min_interval: Timestamp,
+		/// Minimum change from the last value pushed for a subsequent value to be pushed, with zero
+		/// pushing every value.
+		pub(crate) deviation: Permill,
+		/// The pallet and call indices of the call receiving values on the subscribing parachain.
+		pub(crate) call_index: [u8; 2],
+		/// The pallet and call indices of the call receiving removals of disputed values, if any.
+		pub(crate) removal_call_index: Option<[u8; 2]>,
+		/// The maximum weight of the receiving call.
+		pub(crate) weight: Weight,
+		/// The fees withdrawn on the subscribing parachain to pay for execution of each push.
+		pub(crate) fees: MultiAsset,
+		/// The timestamp of the last value pushed, along with the value as an unsigned integer.
+		pub(crate) last_pushed: Option<(Timestamp, Option<U256>)>,
+		/// The timestamp of a value which failed to be pushed, along with the number of attempts.
+		pub(crate) failed: Option<(Timestamp, u8)>,
+		/// The number of removals of values of the query identifier processed for the subscriber.
+		pub(crate) removals: u32,
+	}
+
+	/// A value which satisfied a read policy.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct SafeValue<AccountId> {
+		/// The value retrieved.
+		pub value: Vec<u8>,
+		/// The timestamp of the value.
+		pub timestamp: Timestamp,
+		/// The reporter of the value.
+		pub reporter: AccountId,
+	}
+
+	/// The value reported for a large value query, referencing a value noted as a preimage.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ValueReference {
+		/// The hash of the noted value.
+		pub hash: PreimageHash,
+		/// The length of the noted value.
+		pub len: u32,
+	}
 }
 
 pub(crate) mod governance {
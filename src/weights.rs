@@ -22,6 +22,16 @@
 // --output=../tellor-pallet/src/weights.rs
 // --template=./.maintain/frame-weight-template.hbs
 
+// PROVISIONAL: the weights of the following functions were estimated by hand from the storage
+// accessed, rather than measured, and must be regenerated using the command above before release:
+// begin_dispute, check_health, claim_onetime_tip, claim_tip, commit_value, finalize_values,
+// fund_feed, get_data_before, get_multiple_values_before, migrate_values, note_value, on_idle,
+// on_initialize, prune_commits, prune_values, push_values, query_value, retract_value,
+// reveal_value, set_commit_reveal, set_derived_feed, set_emergency_value, set_health_thresholds,
+// set_large_values, set_query_risk_class, set_retention_period, set_risk_class, set_value_cache,
+// setup_data_feed, submit_value, submit_value_unsigned, submit_values, subscribe, tip,
+// unnote_value, unsubscribe, update_stake_amount.
+
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -51,7 +61,35 @@ pub trait WeightInfo {
 	fn report_slash() -> Weight;
 	fn report_vote_tallied() -> Weight;
 	fn report_vote_executed(r: u32, ) -> Weight;
+	fn set_commit_reveal() -> Weight;
+	fn commit_value() -> Weight;
+	fn reveal_value(q: u32, v: u32, ) -> Weight;
+	fn submit_values(n: u32, q: u32, v: u32, ) -> Weight;
+	fn set_risk_class() -> Weight;
+	fn set_query_risk_class() -> Weight;
+	fn submit_value_unsigned(q: u32, v: u32, ) -> Weight;
+	fn set_retention_period() -> Weight;
 	fn on_initialize(s: u32, l: u32, v: u32, ) -> Weight;
+	fn on_idle(r: u32, ) -> Weight;
+	fn prune_values(q: u32, p: u32, ) -> Weight;
+	fn prune_commits(c: u32, ) -> Weight;
+	fn migrate_values(n: u32, ) -> Weight;
+	fn get_data_before(s: u32, ) -> Weight;
+	fn get_multiple_values_before(n: u32, ) -> Weight;
+	fn set_value_cache(n: u32, ) -> Weight;
+	fn set_large_values() -> Weight;
+	fn note_value(n: u32, ) -> Weight;
+	fn retract_value(d: u32, ) -> Weight;
+	fn finalize_values(n: u32, ) -> Weight;
+	fn set_health_thresholds() -> Weight;
+	fn check_health(n: u32, ) -> Weight;
+	fn set_emergency_value() -> Weight;
+	fn set_derived_feed() -> Weight;
+	fn subscribe() -> Weight;
+	fn unsubscribe() -> Weight;
+	fn push_values(s: u32, ) -> Weight;
+	fn query_value(n: u32, ) -> Weight;
+	fn unnote_value() -> Weight;
 }
 
 /// Weights for `tellor` using the Substrate node and recommended hardware.
@@ -94,8 +132,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Tellor AccumulatedRewardPerShare (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Tellor TotalRewardDebt (r:1 w:0)
 	/// Proof: Tellor TotalRewardDebt (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
-	/// Storage: Tellor LastReportedTimestamp (r:1 w:0)
-	/// Proof: Tellor LastReportedTimestamp (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: Tellor QueryIdsWithFunding (r:0 w:1)
 	/// Proof: Tellor QueryIdsWithFunding (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
 	/// The range of component `t` is `[1, 100]`.
@@ -121,8 +159,6 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	/// Storage: Tellor DataFeedRewardClaimed (r:100 w:100)
 	/// Proof: Tellor DataFeedRewardClaimed (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedValuesByTimestamp (r:101 w:0)
-	/// Proof: Tellor ReportedValuesByTimestamp (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
 	/// Storage: System Account (r:3 w:3)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Tellor TimeOfLastAllocation (r:1 w:1)
@@ -135,6 +171,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Tellor AccumulatedRewardPerShare (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Tellor TotalRewardDebt (r:1 w:0)
 	/// Proof: Tellor TotalRewardDebt (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tellor FundedFeedIntervals (r:1 w:1)
+	/// Proof: Tellor FundedFeedIntervals (max_values: None, max_size: Some(433), added: 2908, mode: MaxEncodedLen)
 	/// Storage: Tellor FeedsWithFunding (r:0 w:1)
 	/// Proof: Tellor FeedsWithFunding (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
 	/// The range of component `t` is `[1, 100]`.
@@ -146,9 +184,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(142_455_120, 8799)
 			// Standard Error: 150_889
 			.saturating_add(Weight::from_parts(26_041_098, 0).saturating_mul(t.into()))
-			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
 			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(t.into())))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(t.into())))
 			.saturating_add(Weight::from_parts(0, 2789).saturating_mul(t.into()))
 	}
@@ -158,6 +196,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Tellor UserTipsTotal (r:1 w:1)
 	/// Proof: Tellor UserTipsTotal (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Tellor FundedFeedIntervals (r:1 w:1)
+	/// Proof: Tellor FundedFeedIntervals (max_values: None, max_size: Some(433), added: 2908, mode: MaxEncodedLen)
 	/// Storage: Tellor FeedsWithFunding (r:0 w:1)
 	/// Proof: Tellor FeedsWithFunding (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
 	fn fund_feed() -> Weight {
@@ -166,8 +206,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `6196`
 		// Minimum execution time: 58_000_000 picoseconds.
 		Weight::from_parts(59_000_000, 6196)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Tellor DataFeeds (r:1 w:1)
 	/// Proof: Tellor DataFeeds (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
@@ -175,6 +215,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Tellor UserTipsTotal (r:1 w:1)
 	/// Proof: Tellor UserTipsTotal (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Tellor FundedFeedIntervals (r:1 w:1)
+	/// Proof: Tellor FundedFeedIntervals (max_values: None, max_size: Some(433), added: 2908, mode: MaxEncodedLen)
 	/// Storage: Tellor FeedsWithFunding (r:0 w:1)
 	/// Proof: Tellor FeedsWithFunding (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
 	/// Storage: Tellor QueryData (r:0 w:1)
@@ -190,15 +232,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(76_863_625, 6196)
 			// Standard Error: 447
 			.saturating_add(Weight::from_parts(1_672, 0).saturating_mul(q.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: Tellor TipCount (r:1 w:1)
 	/// Proof: Tellor TipCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: Tellor LastReportedTimestamp (r:1 w:0)
-	/// Proof: Tellor LastReportedTimestamp (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Tellor UserTipsTotal (r:1 w:1)
@@ -254,41 +296,57 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Tellor Reports (r:1 w:1)
 	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
-	/// Storage: Tellor LastReportedTimestamp (r:1 w:1)
-	/// Proof: Tellor LastReportedTimestamp (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:1)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: Tellor StakerReportsSubmittedByQueryId (r:1 w:1)
 	/// Proof: Tellor StakerReportsSubmittedByQueryId (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedTimestampsByIndex (r:0 w:1)
-	/// Proof: Tellor ReportedTimestampsByIndex (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:0 w:1)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:0 w:1)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
 	/// Storage: Tellor TimeOfLastNewValue (r:0 w:1)
 	/// Proof: Tellor TimeOfLastNewValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedValuesByTimestamp (r:0 w:1)
-	/// Proof: Tellor ReportedValuesByTimestamp (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryRiskClass (r:1 w:0)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor ValueCaches (r:1 w:1)
+	/// Proof: Tellor ValueCaches (max_values: None, max_size: Some(2783), added: 5258, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeafCount (r:1 w:1)
+	/// Proof: Tellor CommitmentLeafCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeaves (r:1 w:1)
+	/// Proof: Tellor CommitmentLeaves (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentNodes (r:64 w:64)
+	/// Proof: Tellor CommitmentNodes (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Tellor PendingCommitment (r:0 w:1)
+	/// Proof: Tellor PendingCommitment (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: Tellor ReporterLastBlock (r:0 w:1)
+	/// Proof: Tellor ReporterLastBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// The range of component `q` is `[1, 1024]`.
 	/// The range of component `v` is `[1, 256]`.
 	fn submit_value(q: u32, v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `419`
-		//  Estimated: `3646`
+		//  Measured:  `7679`
+		//  Estimated: `163038`
 		// Minimum execution time: 37_000_000 picoseconds.
-		Weight::from_parts(38_023_107, 3646)
+		Weight::from_parts(115_187_562, 163038)
 			// Standard Error: 215
 			.saturating_add(Weight::from_parts(1_842, 0).saturating_mul(q.into()))
 			// Standard Error: 861
 			.saturating_add(Weight::from_parts(2_564, 0).saturating_mul(v.into()))
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().reads(76_u64))
+			.saturating_add(T::DbWeight::get().writes(78_u64))
 	}
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: Tellor LastReportedTimestamp (r:2 w:0)
-	/// Proof: Tellor LastReportedTimestamp (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedTimestampsByIndex (r:4 w:0)
-	/// Proof: Tellor ReportedTimestampsByIndex (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:2 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:4 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:2 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
 	/// Storage: Tellor Reports (r:2 w:0)
 	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedValuesByTimestamp (r:2 w:0)
-	/// Proof: Tellor ReportedValuesByTimestamp (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
 	/// Storage: Tellor StakeAmount (r:1 w:1)
 	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
 	/// Storage: Tellor DisputeFee (r:1 w:1)
@@ -305,12 +363,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(4_191_831, 0).saturating_mul(s.into()))
 			// Standard Error: 1_581_388
 			.saturating_add(Weight::from_parts(7_450_664, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Tellor StakerDetails (r:1 w:0)
 	/// Proof: Tellor StakerDetails (max_values: None, max_size: Some(181), added: 2656, mode: MaxEncodedLen)
-	/// Storage: Tellor Reports (r:99 w:99)
+	/// Storage: Tellor Reports (r:101 w:99)
 	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	/// Storage: ParachainInfo ParachainId (r:1 w:0)
 	/// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
@@ -322,17 +380,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Tellor DisputeFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Tellor OpenDisputesOnId (r:1 w:1)
 	/// Proof: Tellor OpenDisputesOnId (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedValuesByTimestamp (r:1 w:1)
-	/// Proof: Tellor ReportedValuesByTimestamp (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
 	/// Storage: Tellor StakeAmount (r:1 w:0)
 	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedTimestampsByIndex (r:100 w:0)
-	/// Proof: Tellor ReportedTimestampsByIndex (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
-	/// Storage: Tellor LastReportedTimestamp (r:1 w:1)
-	/// Proof: Tellor LastReportedTimestamp (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:101 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:2 w:1)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:1)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: Tellor VoteCount (r:1 w:1)
 	/// Proof: Tellor VoteCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
+	/// Storage: System Account (r:4 w:4)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
 	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
@@ -346,18 +404,40 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Tellor DisputeIdsByReporter (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Tellor DisputeInfo (r:0 w:1)
 	/// Proof: Tellor DisputeInfo (max_values: None, max_size: Some(394), added: 2869, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryRiskClass (r:1 w:0)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor ValueCaches (r:1 w:1)
+	/// Proof: Tellor ValueCaches (max_values: None, max_size: Some(2783), added: 5258, mode: MaxEncodedLen)
+	/// Storage: Tellor LastFinalized (r:1 w:0)
+	/// Proof: Tellor LastFinalized (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeafCount (r:1 w:0)
+	/// Proof: Tellor CommitmentLeafCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeaves (r:1 w:1)
+	/// Proof: Tellor CommitmentLeaves (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentNodes (r:64 w:64)
+	/// Proof: Tellor CommitmentNodes (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Tellor PendingCommitment (r:0 w:1)
+	/// Proof: Tellor PendingCommitment (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: Tellor CounterForSubscriptions (r:1 w:0)
+	/// Proof: Tellor CounterForSubscriptions (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tellor RemovalCount (r:1 w:1)
+	/// Proof: Tellor RemovalCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor Removals (r:0 w:2)
+	/// Proof: Tellor Removals (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	/// The range of component `d` is `[1, 100]`.
 	fn begin_dispute(d: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1076 + d * (539 ±0)`
-		//  Estimated: `6196 + d * (2529 ±1)`
+		//  Measured:  `8380 + d * (539 ±0)`
+		//  Estimated: `163038 + d * (2529 ±1)`
 		// Minimum execution time: 136_000_000 picoseconds.
-		Weight::from_parts(121_792_867, 6196)
+		Weight::from_parts(205_962_151, 163038)
 			// Standard Error: 44_823
 			.saturating_add(Weight::from_parts(8_502_405, 0).saturating_mul(d.into()))
-			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads(92_u64))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
-			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().writes(84_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
 			.saturating_add(Weight::from_parts(0, 2529).saturating_mul(d.into()))
 	}
@@ -597,18 +677,250 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 2717).saturating_mul(r.into()))
 	}
+	/// Storage: Tellor CommitRevealQueries (r:0 w:1)
+	/// Proof: Tellor CommitRevealQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn set_commit_reveal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tellor CommitRevealQueries (r:1 w:0)
+	/// Proof: Tellor CommitRevealQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerDetails (r:1 w:1)
+	/// Proof: Tellor StakerDetails (max_values: None, max_size: Some(181), added: 2656, mode: MaxEncodedLen)
+	/// Storage: Tellor StakeAmount (r:1 w:0)
+	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor Commits (r:0 w:1)
+	/// Proof: Tellor Commits (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryRiskClass (r:1 w:0)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor ReporterLastBlock (r:0 w:1)
+	/// Proof: Tellor ReporterLastBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn commit_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `385`
+		//  Estimated: `3646`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 3646)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:1)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor Commits (r:1 w:1)
+	/// Proof: Tellor Commits (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerDetails (r:1 w:1)
+	/// Proof: Tellor StakerDetails (max_values: None, max_size: Some(181), added: 2656, mode: MaxEncodedLen)
+	/// Storage: Tellor StakeAmount (r:1 w:0)
+	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:1 w:1)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:1)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerReportsSubmittedByQueryId (r:1 w:1)
+	/// Proof: Tellor StakerReportsSubmittedByQueryId (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:0 w:1)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:0 w:1)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor TimeOfLastNewValue (r:0 w:1)
+	/// Proof: Tellor TimeOfLastNewValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryRiskClass (r:1 w:0)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeafCount (r:1 w:1)
+	/// Proof: Tellor CommitmentLeafCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeaves (r:1 w:1)
+	/// Proof: Tellor CommitmentLeaves (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentNodes (r:64 w:64)
+	/// Proof: Tellor CommitmentNodes (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Tellor PendingCommitment (r:0 w:1)
+	/// Proof: Tellor PendingCommitment (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: Tellor ReporterLastBlock (r:0 w:1)
+	/// Proof: Tellor ReporterLastBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `q` is `[1, 1024]`.
+	/// The range of component `v` is `[1, 256]`.
+	fn reveal_value(q: u32, v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6431`
+		//  Estimated: `163038`
+		// Minimum execution time: 43_000_000 picoseconds.
+		Weight::from_parts(115_187_512, 163038)
+			// Standard Error: 231
+			.saturating_add(Weight::from_parts(1_858, 0).saturating_mul(q.into()))
+			// Standard Error: 925
+			.saturating_add(Weight::from_parts(4_011, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(76_u64))
+			.saturating_add(T::DbWeight::get().writes(78_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerDetails (r:1 w:1)
+	/// Proof: Tellor StakerDetails (max_values: None, max_size: Some(181), added: 2656, mode: MaxEncodedLen)
+	/// Storage: Tellor StakeAmount (r:1 w:0)
+	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:10 w:10)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitRevealQueries (r:10 w:0)
+	/// Proof: Tellor CommitRevealQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:10 w:10)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:10 w:10)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerReportsSubmittedByQueryId (r:10 w:10)
+	/// Proof: Tellor StakerReportsSubmittedByQueryId (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:0 w:10)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:0 w:10)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor TimeOfLastNewValue (r:0 w:1)
+	/// Proof: Tellor TimeOfLastNewValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryRiskClass (r:1 w:0)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeafCount (r:1 w:1)
+	/// Proof: Tellor CommitmentLeafCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeaves (r:10 w:10)
+	/// Proof: Tellor CommitmentLeaves (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentNodes (r:84 w:84)
+	/// Proof: Tellor CommitmentNodes (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Tellor PendingCommitment (r:0 w:1)
+	/// Proof: Tellor PendingCommitment (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: Tellor ReporterLastBlock (r:0 w:10)
+	/// Proof: Tellor ReporterLastBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 10]`.
+	/// The range of component `q` is `[1, 1024]`.
+	/// The range of component `v` is `[1, 256]`.
+	fn submit_values(n: u32, q: u32, v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6285`
+		//  Estimated: `163038 + n * (2581 ±0)`
+		// Minimum execution time: 39_000_000 picoseconds.
+		Weight::from_parts(83_468_201, 163038)
+			// Standard Error: 52_103
+			.saturating_add(Weight::from_parts(27_614_930, 0).saturating_mul(n.into()))
+			// Standard Error: 509
+			.saturating_add(Weight::from_parts(18_412, 0).saturating_mul(q.into()))
+			// Standard Error: 2_036
+			.saturating_add(Weight::from_parts(25_307, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(68_u64))
+			.saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(68_u64))
+			.saturating_add(T::DbWeight::get().writes((11_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2581).saturating_mul(n.into()))
+	}
+	/// Storage: Tellor RiskClasses (r:0 w:1)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	fn set_risk_class() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryRiskClass (r:0 w:1)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	fn set_query_risk_class() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `106`
+		//  Estimated: `3478`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3478)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:1)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitRevealQueries (r:1 w:0)
+	/// Proof: Tellor CommitRevealQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerDetails (r:1 w:1)
+	/// Proof: Tellor StakerDetails (max_values: None, max_size: Some(181), added: 2656, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryRiskClass (r:1 w:0)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor StakeAmount (r:1 w:0)
+	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:1 w:1)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:1)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerReportsSubmittedByQueryId (r:1 w:1)
+	/// Proof: Tellor StakerReportsSubmittedByQueryId (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:0 w:1)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:0 w:1)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor TimeOfLastNewValue (r:0 w:1)
+	/// Proof: Tellor TimeOfLastNewValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeafCount (r:1 w:1)
+	/// Proof: Tellor CommitmentLeafCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeaves (r:1 w:1)
+	/// Proof: Tellor CommitmentLeaves (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentNodes (r:64 w:64)
+	/// Proof: Tellor CommitmentNodes (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Tellor PendingCommitment (r:0 w:1)
+	/// Proof: Tellor PendingCommitment (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: Tellor ReporterLastBlock (r:0 w:1)
+	/// Proof: Tellor ReporterLastBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `q` is `[1, 1024]`.
+	/// The range of component `v` is `[1, 256]`.
+	fn submit_value_unsigned(q: u32, v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6319`
+		//  Estimated: `163038`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(110_412_870, 163038)
+			// Standard Error: 221
+			.saturating_add(Weight::from_parts(1_851, 0).saturating_mul(q.into()))
+			// Standard Error: 884
+			.saturating_add(Weight::from_parts(2_630, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(76_u64))
+			.saturating_add(T::DbWeight::get().writes(77_u64))
+	}
+	/// Storage: Tellor QueryRiskClass (r:1 w:0)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor RetentionPeriods (r:0 w:1)
+	/// Proof: Tellor RetentionPeriods (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn set_retention_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `4996`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4996)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Tellor LastStakeAmountUpdate (r:1 w:1)
 	/// Proof: Tellor LastStakeAmountUpdate (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: Tellor LastReportedTimestamp (r:2 w:0)
-	/// Proof: Tellor LastReportedTimestamp (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedTimestampsByIndex (r:4 w:0)
-	/// Proof: Tellor ReportedTimestampsByIndex (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:2 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:4 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:2 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
 	/// Storage: Tellor Reports (r:2 w:0)
 	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedValuesByTimestamp (r:2 w:0)
-	/// Proof: Tellor ReportedValuesByTimestamp (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
 	/// Storage: Tellor StakeAmount (r:1 w:1)
 	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
 	/// Storage: Tellor DisputeFee (r:1 w:1)
@@ -638,7 +950,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(3_013_191, 0).saturating_mul(l.into()))
 			// Standard Error: 4_546_881
 			.saturating_add(Weight::from_parts(22_146_935, 0).saturating_mul(v.into()))
-			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().reads(20_u64))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(v.into())))
@@ -646,6 +958,524 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 407).saturating_mul(s.into()))
 			.saturating_add(Weight::from_parts(0, 2717).saturating_mul(v.into()))
 	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor RequestScanCursor (r:1 w:1)
+	/// Proof: Tellor RequestScanCursor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor PendingRequests (r:1 w:1)
+	/// Proof: Tellor PendingRequests (max_values: Some(1), max_size: Some(10002), added: 10497, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:100 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:100 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:100 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:100 w:0)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// The range of component `r` is `[1, 100]`.
+	fn on_idle(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `451 + r * (100 ±0)`
+		//  Estimated: `11487`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(36_519_870, 11487)
+			// Standard Error: 21_104
+			.saturating_add(Weight::from_parts(19_862_432, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Tellor PruningCursor (r:1 w:1)
+	/// Proof: Tellor PruningCursor (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor RetentionPeriods (r:1001 w:0)
+	/// Proof: Tellor RetentionPeriods (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:1000 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor FirstRetainedIndex (r:1000 w:1000)
+	/// Proof: Tellor FirstRetainedIndex (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:1000 w:1000)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:1000 w:1000)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:0 w:1000)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeaves (r:0 w:1000)
+	/// Proof: Tellor CommitmentLeaves (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tellor ValueCaches (r:1000 w:1000)
+	/// Proof: Tellor ValueCaches (max_values: None, max_size: Some(2783), added: 5258, mode: MaxEncodedLen)
+	/// The range of component `q` is `[0, 1000]`.
+	/// The range of component `p` is `[0, 1000]`.
+	fn prune_values(q: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `132 + p * (62 ±0) + q * (1484 ±0)`
+		//  Estimated: `1517 + p * (2535 ±0) + q * (12784 ±0)`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 1517)
+			// Standard Error: 9_218
+			.saturating_add(Weight::from_parts(12_367_905, 0).saturating_mul(q.into()))
+			// Standard Error: 9_218
+			.saturating_add(Weight::from_parts(8_271_006, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(q.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(q.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 12784).saturating_mul(q.into()))
+			.saturating_add(Weight::from_parts(0, 2535).saturating_mul(p.into()))
+	}
+	/// Storage: Tellor CommitPruningCursor (r:1 w:1)
+	/// Proof: Tellor CommitPruningCursor (max_values: Some(1), max_size: Some(64), added: 559, mode: MaxEncodedLen)
+	/// Storage: Tellor Commits (r:1001 w:1000)
+	/// Proof: Tellor Commits (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitRevealQueries (r:1000 w:0)
+	/// Proof: Tellor CommitRevealQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 1000]`.
+	fn prune_commits(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `145 + c * (112 ±0)`
+		//  Estimated: `1549 + c * (2587 ±0)`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 1549)
+			// Standard Error: 4_512
+			.saturating_add(Weight::from_parts(6_873_209, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2587).saturating_mul(c.into()))
+	}
+	/// Storage: Tellor ReportedTimestampsByIndex (r:101 w:100)
+	/// Proof Skipped: Tellor ReportedTimestampsByIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tellor ReportedValuesByTimestamp (r:100 w:100)
+	/// Proof Skipped: Tellor ReportedValuesByTimestamp (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tellor ReportedValues (r:100 w:100)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:0 w:100)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:100 w:0)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReportedTimestamp (r:1 w:0)
+	/// Proof Skipped: Tellor LastReportedTimestamp (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tellor MigrationInProgress (r:0 w:1)
+	/// Proof: Tellor MigrationInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn migrate_values(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `179 + n * (421 ±0)`
+		//  Estimated: `3644 + n * (5276 ±0)`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_412_508, 3644)
+			// Standard Error: 6_822
+			.saturating_add(Weight::from_parts(17_338_120, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5276).saturating_mul(n.into()))
+	}
+	/// Storage: Tellor MigrationInProgress (r:1 w:0)
+	/// Proof: Tellor MigrationInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor FirstRetainedIndex (r:1 w:0)
+	/// Proof: Tellor FirstRetainedIndex (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:11 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:1 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 10]`.
+	fn get_data_before(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `625 + s * (86 ±0)`
+		//  Estimated: `6249 + s * (2565 ±0)`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(11_974_261, 6249)
+			// Standard Error: 3_104
+			.saturating_add(Weight::from_parts(2_061_735, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2565).saturating_mul(s.into()))
+	}
+	/// Storage: Tellor MigrationInProgress (r:1 w:0)
+	/// Proof: Tellor MigrationInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor FirstRetainedIndex (r:1 w:0)
+	/// Proof: Tellor FirstRetainedIndex (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:114 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:100 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn get_multiple_values_before(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1_204 + n * (350 ±0)`
+		//  Estimated: `36617 + n * (5313 ±0)`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(36_918_447, 36617)
+			// Standard Error: 2_418
+			.saturating_add(Weight::from_parts(2_374_560, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5313).saturating_mul(n.into()))
+	}
+	/// Storage: Tellor LastReported (r:1 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor MigrationInProgress (r:1 w:0)
+	/// Proof: Tellor MigrationInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:10 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:10 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:20 w:0)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Tellor ValueCaches (r:0 w:1)
+	/// Proof: Tellor ValueCaches (max_values: None, max_size: Some(2783), added: 5258, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 10]`.
+	fn set_value_cache(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102 + n * (528 ±0)`
+		//  Estimated: `3509 + n * (5491 ±0)`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_135_811, 3509)
+			// Standard Error: 7_455
+			.saturating_add(Weight::from_parts(7_918_246, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 5491).saturating_mul(n.into()))
+	}
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor LargeValueQueries (r:0 w:1)
+	/// Proof: Tellor LargeValueQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn set_large_values() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `3501`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 3501)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tellor StakerDetails (r:1 w:0)
+	/// Proof: Tellor StakerDetails (max_values: None, max_size: Some(181), added: 2656, mode: MaxEncodedLen)
+	/// Storage: Tellor StakeAmount (r:1 w:0)
+	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor Preimages (r:1 w:1)
+	/// Proof: Tellor Preimages (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Tellor PreimageValues (r:0 w:1)
+	/// Proof: Tellor PreimageValues (max_values: None, max_size: Some(4130), added: 6605, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4096]`.
+	fn note_value(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `295`
+		//  Estimated: `6196`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_180_443, 6196)
+			// Standard Error: 14
+			.saturating_add(Weight::from_parts(1_603, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Tellor Reports (r:2 w:2)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:4 w:4)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tellor TotalStakeAmount (r:1 w:0)
+	/// Proof: Tellor TotalStakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor RewardRate (r:1 w:1)
+	/// Proof: Tellor RewardRate (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tellor AccumulatedRewardPerShare (r:1 w:0)
+	/// Proof: Tellor AccumulatedRewardPerShare (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tellor TotalRewardDebt (r:1 w:0)
+	/// Proof: Tellor TotalRewardDebt (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:2 w:1)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:1 w:1)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:1)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor LargeValueQueries (r:1 w:0)
+	/// Proof: Tellor LargeValueQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Tellor ValueCaches (r:1 w:0)
+	/// Proof: Tellor ValueCaches (max_values: None, max_size: Some(2783), added: 5258, mode: MaxEncodedLen)
+	/// Storage: Tellor RetractionsByReporter (r:1 w:1)
+	/// Proof: Tellor RetractionsByReporter (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerDetails (r:1 w:1)
+	/// Proof: Tellor StakerDetails (max_values: None, max_size: Some(181), added: 2656, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeafCount (r:1 w:0)
+	/// Proof: Tellor CommitmentLeafCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeaves (r:1 w:1)
+	/// Proof: Tellor CommitmentLeaves (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentNodes (r:64 w:64)
+	/// Proof: Tellor CommitmentNodes (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Tellor PendingCommitment (r:0 w:1)
+	/// Proof: Tellor PendingCommitment (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: Tellor CounterForSubscriptions (r:1 w:0)
+	/// Proof: Tellor CounterForSubscriptions (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tellor RemovalCount (r:1 w:1)
+	/// Proof: Tellor RemovalCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor Removals (r:0 w:2)
+	/// Proof: Tellor Removals (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Tellor ReporterLastBlock (r:1 w:0)
+	/// Proof: Tellor ReporterLastBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `d` is `[1, 100]`.
+	fn retract_value(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6932 + d * (539 ±0)`
+		//  Estimated: `163038 + d * (2529 ±1)`
+		// Minimum execution time: 84_000_000 picoseconds.
+		Weight::from_parts(147_418_250, 163038)
+			// Standard Error: 41_072
+			.saturating_add(Weight::from_parts(8_397_112, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(88_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(81_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2529).saturating_mul(d.into()))
+	}
+	/// Storage: Tellor FinalityQueueRange (r:101 w:100)
+	/// Proof: Tellor FinalityQueueRange (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Tellor FinalityQueue (r:100 w:100)
+	/// Proof: Tellor FinalityQueue (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:100 w:0)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Tellor LastFinalized (r:100 w:100)
+	/// Proof: Tellor LastFinalized (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn finalize_values(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `140 + n * (255 ±0)`
+		//  Estimated: `1501 + n * (2697 ±0)`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(5_212_904, 1501)
+			// Standard Error: 5_318
+			.saturating_add(Weight::from_parts(11_284_517, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2697).saturating_mul(n.into()))
+	}
+	/// Storage: Tellor OpenDisputesOnId (r:1 w:0)
+	/// Proof: Tellor OpenDisputesOnId (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor FirstRetainedIndex (r:1 w:0)
+	/// Proof: Tellor FirstRetainedIndex (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:10 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:10 w:0)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Tellor FundedFeedIntervals (r:1 w:0)
+	/// Proof: Tellor FundedFeedIntervals (max_values: None, max_size: Some(433), added: 2908, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryHealthStates (r:1 w:1)
+	/// Proof: Tellor QueryHealthStates (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryHealthThresholds (r:0 w:1)
+	/// Proof: Tellor QueryHealthThresholds (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	fn set_health_thresholds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3648`
+		//  Estimated: `27880`
+		// Minimum execution time: 128_000_000 picoseconds.
+		Weight::from_parts(131_000_000, 27880)
+			.saturating_add(T::DbWeight::get().reads(26_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Tellor HealthCheckCursor (r:1 w:1)
+	/// Proof: Tellor HealthCheckCursor (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryHealthThresholds (r:11 w:0)
+	/// Proof: Tellor QueryHealthThresholds (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: Tellor OpenDisputesOnId (r:10 w:0)
+	/// Proof: Tellor OpenDisputesOnId (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:10 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor FirstRetainedIndex (r:10 w:0)
+	/// Proof: Tellor FirstRetainedIndex (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:100 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor FundedFeedIntervals (r:10 w:0)
+	/// Proof: Tellor FundedFeedIntervals (max_values: None, max_size: Some(433), added: 2908, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:10 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryHealthStates (r:10 w:10)
+	/// Proof: Tellor QueryHealthStates (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 10]`.
+	fn check_health(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `172 + n * (2443 ±0)`
+		//  Estimated: `1517 + n * (27880 ±0)`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(4_652_109, 1517)
+			// Standard Error: 48_213
+			.saturating_add(Weight::from_parts(112_904_318, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((17_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 27880).saturating_mul(n.into()))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor EmergencyValues (r:0 w:1)
+	/// Proof: Tellor EmergencyValues (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	fn set_emergency_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1493`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 1493)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tellor DerivedFeeds (r:2 w:1)
+	/// Proof: Tellor DerivedFeeds (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn set_derived_feed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `7640`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 7640)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tellor CounterForSubscriptions (r:1 w:1)
+	/// Proof: Tellor CounterForSubscriptions (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tellor RemovalCount (r:1 w:0)
+	/// Proof: Tellor RemovalCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor SubscriptionCount (r:1 w:1)
+	/// Proof: Tellor SubscriptionCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tellor SubscriptionDeposits (r:0 w:1)
+	/// Proof: Tellor SubscriptionDeposits (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Tellor Subscriptions (r:1 w:1)
+	/// Proof: Tellor Subscriptions (max_values: None, max_size: Some(722), added: 3197, mode: MaxEncodedLen)
+	fn subscribe() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: Tellor Subscriptions (r:1 w:1)
+	/// Proof: Tellor Subscriptions (max_values: None, max_size: Some(722), added: 3197, mode: MaxEncodedLen)
+	/// Storage: Tellor CounterForSubscriptions (r:1 w:1)
+	/// Proof: Tellor CounterForSubscriptions (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tellor SubscriptionDeposits (r:1 w:1)
+	/// Proof: Tellor SubscriptionDeposits (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn unsubscribe() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `424`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Tellor PushCursor (r:1 w:1)
+	/// Proof: Tellor PushCursor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor Subscriptions (r:101 w:100)
+	/// Proof: Tellor Subscriptions (max_values: None, max_size: Some(722), added: 3197, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:100 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:100 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:100 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:100 w:0)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: Tellor RemovalCount (r:100 w:0)
+	/// Proof: Tellor RemovalCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor Removals (r:100 w:0)
+	/// Proof: Tellor Removals (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: System Account (r:400 w:400)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ParachainInfo ParachainId (r:1 w:0)
+	/// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	/// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `s` is `[0, 100]`.
+	fn push_values(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `224 + s * (1251 ±0)`
+		//  Estimated: `1493 + s * (23935 ±0)`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(12_318_420, 1493)
+			// Standard Error: 31_562
+			.saturating_add(Weight::from_parts(171_604_817, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((11_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 23935).saturating_mul(s.into()))
+	}
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:1 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:1 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:1 w:0)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: ParachainInfo ParachainId (r:1 w:0)
+	/// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	/// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tellor LargeValueQueries (r:1 w:0)
+	/// Proof: Tellor LargeValueQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Tellor PreimageValues (r:1 w:0)
+	/// Proof: Tellor PreimageValues (max_values: None, max_size: Some(4130), added: 6605, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4096]`.
+	fn query_value(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1388 + n * (1 ±0)`
+		//  Estimated: `7595`
+		// Minimum execution time: 98_000_000 picoseconds.
+		Weight::from_parts(100_412_260, 7595)
+			// Standard Error: 21
+			.saturating_add(Weight::from_parts(2_716, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Tellor Preimages (r:1 w:1)
+	/// Proof: Tellor Preimages (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tellor PreimageValues (r:0 w:1)
+	/// Proof: Tellor PreimageValues (max_values: None, max_size: Some(4130), added: 6605, mode: MaxEncodedLen)
+	fn unnote_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `486`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -687,8 +1517,8 @@ impl WeightInfo for () {
 	/// Proof: Tellor AccumulatedRewardPerShare (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Tellor TotalRewardDebt (r:1 w:0)
 	/// Proof: Tellor TotalRewardDebt (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
-	/// Storage: Tellor LastReportedTimestamp (r:1 w:0)
-	/// Proof: Tellor LastReportedTimestamp (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: Tellor QueryIdsWithFunding (r:0 w:1)
 	/// Proof: Tellor QueryIdsWithFunding (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
 	/// The range of component `t` is `[1, 100]`.
@@ -714,8 +1544,6 @@ impl WeightInfo for () {
 	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	/// Storage: Tellor DataFeedRewardClaimed (r:100 w:100)
 	/// Proof: Tellor DataFeedRewardClaimed (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedValuesByTimestamp (r:101 w:0)
-	/// Proof: Tellor ReportedValuesByTimestamp (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
 	/// Storage: System Account (r:3 w:3)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Tellor TimeOfLastAllocation (r:1 w:1)
@@ -728,6 +1556,8 @@ impl WeightInfo for () {
 	/// Proof: Tellor AccumulatedRewardPerShare (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Tellor TotalRewardDebt (r:1 w:0)
 	/// Proof: Tellor TotalRewardDebt (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tellor FundedFeedIntervals (r:1 w:1)
+	/// Proof: Tellor FundedFeedIntervals (max_values: None, max_size: Some(433), added: 2908, mode: MaxEncodedLen)
 	/// Storage: Tellor FeedsWithFunding (r:0 w:1)
 	/// Proof: Tellor FeedsWithFunding (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
 	/// The range of component `t` is `[1, 100]`.
@@ -739,9 +1569,9 @@ impl WeightInfo for () {
 		Weight::from_parts(142_455_120, 8799)
 			// Standard Error: 150_889
 			.saturating_add(Weight::from_parts(26_041_098, 0).saturating_mul(t.into()))
-			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
 			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(t.into())))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(t.into())))
 			.saturating_add(Weight::from_parts(0, 2789).saturating_mul(t.into()))
 	}
@@ -751,6 +1581,8 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Tellor UserTipsTotal (r:1 w:1)
 	/// Proof: Tellor UserTipsTotal (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Tellor FundedFeedIntervals (r:1 w:1)
+	/// Proof: Tellor FundedFeedIntervals (max_values: None, max_size: Some(433), added: 2908, mode: MaxEncodedLen)
 	/// Storage: Tellor FeedsWithFunding (r:0 w:1)
 	/// Proof: Tellor FeedsWithFunding (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
 	fn fund_feed() -> Weight {
@@ -759,8 +1591,8 @@ impl WeightInfo for () {
 		//  Estimated: `6196`
 		// Minimum execution time: 58_000_000 picoseconds.
 		Weight::from_parts(59_000_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Tellor DataFeeds (r:1 w:1)
 	/// Proof: Tellor DataFeeds (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
@@ -768,6 +1600,8 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Tellor UserTipsTotal (r:1 w:1)
 	/// Proof: Tellor UserTipsTotal (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Tellor FundedFeedIntervals (r:1 w:1)
+	/// Proof: Tellor FundedFeedIntervals (max_values: None, max_size: Some(433), added: 2908, mode: MaxEncodedLen)
 	/// Storage: Tellor FeedsWithFunding (r:0 w:1)
 	/// Proof: Tellor FeedsWithFunding (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
 	/// Storage: Tellor QueryData (r:0 w:1)
@@ -783,15 +1617,15 @@ impl WeightInfo for () {
 		Weight::from_parts(76_863_625, 6196)
 			// Standard Error: 447
 			.saturating_add(Weight::from_parts(1_672, 0).saturating_mul(q.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: Tellor TipCount (r:1 w:1)
 	/// Proof: Tellor TipCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: Tellor LastReportedTimestamp (r:1 w:0)
-	/// Proof: Tellor LastReportedTimestamp (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Tellor UserTipsTotal (r:1 w:1)
@@ -847,41 +1681,57 @@ impl WeightInfo for () {
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Tellor Reports (r:1 w:1)
 	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
-	/// Storage: Tellor LastReportedTimestamp (r:1 w:1)
-	/// Proof: Tellor LastReportedTimestamp (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:1)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: Tellor StakerReportsSubmittedByQueryId (r:1 w:1)
 	/// Proof: Tellor StakerReportsSubmittedByQueryId (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedTimestampsByIndex (r:0 w:1)
-	/// Proof: Tellor ReportedTimestampsByIndex (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:0 w:1)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:0 w:1)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
 	/// Storage: Tellor TimeOfLastNewValue (r:0 w:1)
 	/// Proof: Tellor TimeOfLastNewValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedValuesByTimestamp (r:0 w:1)
-	/// Proof: Tellor ReportedValuesByTimestamp (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryRiskClass (r:1 w:0)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor ValueCaches (r:1 w:1)
+	/// Proof: Tellor ValueCaches (max_values: None, max_size: Some(2783), added: 5258, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeafCount (r:1 w:1)
+	/// Proof: Tellor CommitmentLeafCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeaves (r:1 w:1)
+	/// Proof: Tellor CommitmentLeaves (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentNodes (r:64 w:64)
+	/// Proof: Tellor CommitmentNodes (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Tellor PendingCommitment (r:0 w:1)
+	/// Proof: Tellor PendingCommitment (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: Tellor ReporterLastBlock (r:0 w:1)
+	/// Proof: Tellor ReporterLastBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// The range of component `q` is `[1, 1024]`.
 	/// The range of component `v` is `[1, 256]`.
 	fn submit_value(q: u32, v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `419`
-		//  Estimated: `3646`
+		//  Measured:  `7679`
+		//  Estimated: `163038`
 		// Minimum execution time: 37_000_000 picoseconds.
-		Weight::from_parts(38_023_107, 3646)
+		Weight::from_parts(115_187_562, 163038)
 			// Standard Error: 215
 			.saturating_add(Weight::from_parts(1_842, 0).saturating_mul(q.into()))
 			// Standard Error: 861
 			.saturating_add(Weight::from_parts(2_564, 0).saturating_mul(v.into()))
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().reads(76_u64))
+			.saturating_add(RocksDbWeight::get().writes(78_u64))
 	}
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: Tellor LastReportedTimestamp (r:2 w:0)
-	/// Proof: Tellor LastReportedTimestamp (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedTimestampsByIndex (r:4 w:0)
-	/// Proof: Tellor ReportedTimestampsByIndex (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:2 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:4 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:2 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
 	/// Storage: Tellor Reports (r:2 w:0)
 	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedValuesByTimestamp (r:2 w:0)
-	/// Proof: Tellor ReportedValuesByTimestamp (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
 	/// Storage: Tellor StakeAmount (r:1 w:1)
 	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
 	/// Storage: Tellor DisputeFee (r:1 w:1)
@@ -898,12 +1748,12 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(4_191_831, 0).saturating_mul(s.into()))
 			// Standard Error: 1_581_388
 			.saturating_add(Weight::from_parts(7_450_664, 0).saturating_mul(l.into()))
-			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Tellor StakerDetails (r:1 w:0)
 	/// Proof: Tellor StakerDetails (max_values: None, max_size: Some(181), added: 2656, mode: MaxEncodedLen)
-	/// Storage: Tellor Reports (r:99 w:99)
+	/// Storage: Tellor Reports (r:101 w:99)
 	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	/// Storage: ParachainInfo ParachainId (r:1 w:0)
 	/// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
@@ -915,17 +1765,17 @@ impl WeightInfo for () {
 	/// Proof: Tellor DisputeFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Tellor OpenDisputesOnId (r:1 w:1)
 	/// Proof: Tellor OpenDisputesOnId (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedValuesByTimestamp (r:1 w:1)
-	/// Proof: Tellor ReportedValuesByTimestamp (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
 	/// Storage: Tellor StakeAmount (r:1 w:0)
 	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedTimestampsByIndex (r:100 w:0)
-	/// Proof: Tellor ReportedTimestampsByIndex (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
-	/// Storage: Tellor LastReportedTimestamp (r:1 w:1)
-	/// Proof: Tellor LastReportedTimestamp (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:101 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:2 w:1)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:1)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: Tellor VoteCount (r:1 w:1)
 	/// Proof: Tellor VoteCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
+	/// Storage: System Account (r:4 w:4)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
 	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
@@ -939,18 +1789,40 @@ impl WeightInfo for () {
 	/// Proof: Tellor DisputeIdsByReporter (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Tellor DisputeInfo (r:0 w:1)
 	/// Proof: Tellor DisputeInfo (max_values: None, max_size: Some(394), added: 2869, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryRiskClass (r:1 w:0)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor ValueCaches (r:1 w:1)
+	/// Proof: Tellor ValueCaches (max_values: None, max_size: Some(2783), added: 5258, mode: MaxEncodedLen)
+	/// Storage: Tellor LastFinalized (r:1 w:0)
+	/// Proof: Tellor LastFinalized (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeafCount (r:1 w:0)
+	/// Proof: Tellor CommitmentLeafCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeaves (r:1 w:1)
+	/// Proof: Tellor CommitmentLeaves (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentNodes (r:64 w:64)
+	/// Proof: Tellor CommitmentNodes (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Tellor PendingCommitment (r:0 w:1)
+	/// Proof: Tellor PendingCommitment (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: Tellor CounterForSubscriptions (r:1 w:0)
+	/// Proof: Tellor CounterForSubscriptions (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tellor RemovalCount (r:1 w:1)
+	/// Proof: Tellor RemovalCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor Removals (r:0 w:2)
+	/// Proof: Tellor Removals (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	/// The range of component `d` is `[1, 100]`.
 	fn begin_dispute(d: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1076 + d * (539 ±0)`
-		//  Estimated: `6196 + d * (2529 ±1)`
+		//  Measured:  `8380 + d * (539 ±0)`
+		//  Estimated: `163038 + d * (2529 ±1)`
 		// Minimum execution time: 136_000_000 picoseconds.
-		Weight::from_parts(121_792_867, 6196)
+		Weight::from_parts(205_962_151, 163038)
 			// Standard Error: 44_823
 			.saturating_add(Weight::from_parts(8_502_405, 0).saturating_mul(d.into()))
-			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads(92_u64))
 			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(d.into())))
-			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(84_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
 			.saturating_add(Weight::from_parts(0, 2529).saturating_mul(d.into()))
 	}
@@ -1190,18 +2062,250 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 2717).saturating_mul(r.into()))
 	}
+	/// Storage: Tellor CommitRevealQueries (r:0 w:1)
+	/// Proof: Tellor CommitRevealQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn set_commit_reveal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tellor CommitRevealQueries (r:1 w:0)
+	/// Proof: Tellor CommitRevealQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerDetails (r:1 w:1)
+	/// Proof: Tellor StakerDetails (max_values: None, max_size: Some(181), added: 2656, mode: MaxEncodedLen)
+	/// Storage: Tellor StakeAmount (r:1 w:0)
+	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor Commits (r:0 w:1)
+	/// Proof: Tellor Commits (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryRiskClass (r:1 w:0)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor ReporterLastBlock (r:0 w:1)
+	/// Proof: Tellor ReporterLastBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn commit_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `385`
+		//  Estimated: `3646`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 3646)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:1)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor Commits (r:1 w:1)
+	/// Proof: Tellor Commits (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerDetails (r:1 w:1)
+	/// Proof: Tellor StakerDetails (max_values: None, max_size: Some(181), added: 2656, mode: MaxEncodedLen)
+	/// Storage: Tellor StakeAmount (r:1 w:0)
+	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:1 w:1)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:1)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerReportsSubmittedByQueryId (r:1 w:1)
+	/// Proof: Tellor StakerReportsSubmittedByQueryId (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:0 w:1)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:0 w:1)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor TimeOfLastNewValue (r:0 w:1)
+	/// Proof: Tellor TimeOfLastNewValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryRiskClass (r:1 w:0)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeafCount (r:1 w:1)
+	/// Proof: Tellor CommitmentLeafCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeaves (r:1 w:1)
+	/// Proof: Tellor CommitmentLeaves (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentNodes (r:64 w:64)
+	/// Proof: Tellor CommitmentNodes (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Tellor PendingCommitment (r:0 w:1)
+	/// Proof: Tellor PendingCommitment (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: Tellor ReporterLastBlock (r:0 w:1)
+	/// Proof: Tellor ReporterLastBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `q` is `[1, 1024]`.
+	/// The range of component `v` is `[1, 256]`.
+	fn reveal_value(q: u32, v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6431`
+		//  Estimated: `163038`
+		// Minimum execution time: 43_000_000 picoseconds.
+		Weight::from_parts(115_187_512, 163038)
+			// Standard Error: 231
+			.saturating_add(Weight::from_parts(1_858, 0).saturating_mul(q.into()))
+			// Standard Error: 925
+			.saturating_add(Weight::from_parts(4_011, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(76_u64))
+			.saturating_add(RocksDbWeight::get().writes(78_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerDetails (r:1 w:1)
+	/// Proof: Tellor StakerDetails (max_values: None, max_size: Some(181), added: 2656, mode: MaxEncodedLen)
+	/// Storage: Tellor StakeAmount (r:1 w:0)
+	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:10 w:10)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitRevealQueries (r:10 w:0)
+	/// Proof: Tellor CommitRevealQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:10 w:10)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:10 w:10)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerReportsSubmittedByQueryId (r:10 w:10)
+	/// Proof: Tellor StakerReportsSubmittedByQueryId (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:0 w:10)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:0 w:10)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor TimeOfLastNewValue (r:0 w:1)
+	/// Proof: Tellor TimeOfLastNewValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryRiskClass (r:1 w:0)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeafCount (r:1 w:1)
+	/// Proof: Tellor CommitmentLeafCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeaves (r:10 w:10)
+	/// Proof: Tellor CommitmentLeaves (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentNodes (r:84 w:84)
+	/// Proof: Tellor CommitmentNodes (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Tellor PendingCommitment (r:0 w:1)
+	/// Proof: Tellor PendingCommitment (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: Tellor ReporterLastBlock (r:0 w:10)
+	/// Proof: Tellor ReporterLastBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 10]`.
+	/// The range of component `q` is `[1, 1024]`.
+	/// The range of component `v` is `[1, 256]`.
+	fn submit_values(n: u32, q: u32, v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6285`
+		//  Estimated: `163038 + n * (2581 ±0)`
+		// Minimum execution time: 39_000_000 picoseconds.
+		Weight::from_parts(83_468_201, 163038)
+			// Standard Error: 52_103
+			.saturating_add(Weight::from_parts(27_614_930, 0).saturating_mul(n.into()))
+			// Standard Error: 509
+			.saturating_add(Weight::from_parts(18_412, 0).saturating_mul(q.into()))
+			// Standard Error: 2_036
+			.saturating_add(Weight::from_parts(25_307, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(68_u64))
+			.saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(68_u64))
+			.saturating_add(RocksDbWeight::get().writes((11_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2581).saturating_mul(n.into()))
+	}
+	/// Storage: Tellor RiskClasses (r:0 w:1)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	fn set_risk_class() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryRiskClass (r:0 w:1)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	fn set_query_risk_class() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `106`
+		//  Estimated: `3478`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3478)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:1)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitRevealQueries (r:1 w:0)
+	/// Proof: Tellor CommitRevealQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerDetails (r:1 w:1)
+	/// Proof: Tellor StakerDetails (max_values: None, max_size: Some(181), added: 2656, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryRiskClass (r:1 w:0)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor StakeAmount (r:1 w:0)
+	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:1 w:1)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:1)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerReportsSubmittedByQueryId (r:1 w:1)
+	/// Proof: Tellor StakerReportsSubmittedByQueryId (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:0 w:1)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:0 w:1)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor TimeOfLastNewValue (r:0 w:1)
+	/// Proof: Tellor TimeOfLastNewValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeafCount (r:1 w:1)
+	/// Proof: Tellor CommitmentLeafCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeaves (r:1 w:1)
+	/// Proof: Tellor CommitmentLeaves (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentNodes (r:64 w:64)
+	/// Proof: Tellor CommitmentNodes (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Tellor PendingCommitment (r:0 w:1)
+	/// Proof: Tellor PendingCommitment (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: Tellor ReporterLastBlock (r:0 w:1)
+	/// Proof: Tellor ReporterLastBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `q` is `[1, 1024]`.
+	/// The range of component `v` is `[1, 256]`.
+	fn submit_value_unsigned(q: u32, v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6319`
+		//  Estimated: `163038`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(110_412_870, 163038)
+			// Standard Error: 221
+			.saturating_add(Weight::from_parts(1_851, 0).saturating_mul(q.into()))
+			// Standard Error: 884
+			.saturating_add(Weight::from_parts(2_630, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(76_u64))
+			.saturating_add(RocksDbWeight::get().writes(77_u64))
+	}
+	/// Storage: Tellor QueryRiskClass (r:1 w:0)
+	/// Proof: Tellor QueryRiskClass (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor RiskClasses (r:1 w:0)
+	/// Proof: Tellor RiskClasses (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: Tellor RetentionPeriods (r:0 w:1)
+	/// Proof: Tellor RetentionPeriods (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn set_retention_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `4996`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4996)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Tellor LastStakeAmountUpdate (r:1 w:1)
 	/// Proof: Tellor LastStakeAmountUpdate (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: Tellor LastReportedTimestamp (r:2 w:0)
-	/// Proof: Tellor LastReportedTimestamp (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedTimestampsByIndex (r:4 w:0)
-	/// Proof: Tellor ReportedTimestampsByIndex (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:2 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:4 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:2 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
 	/// Storage: Tellor Reports (r:2 w:0)
 	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
-	/// Storage: Tellor ReportedValuesByTimestamp (r:2 w:0)
-	/// Proof: Tellor ReportedValuesByTimestamp (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
 	/// Storage: Tellor StakeAmount (r:1 w:1)
 	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
 	/// Storage: Tellor DisputeFee (r:1 w:1)
@@ -1231,7 +2335,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(3_013_191, 0).saturating_mul(l.into()))
 			// Standard Error: 4_546_881
 			.saturating_add(Weight::from_parts(22_146_935, 0).saturating_mul(v.into()))
-			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
 			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(v.into())))
@@ -1239,4 +2343,522 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 407).saturating_mul(s.into()))
 			.saturating_add(Weight::from_parts(0, 2717).saturating_mul(v.into()))
 	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor RequestScanCursor (r:1 w:1)
+	/// Proof: Tellor RequestScanCursor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor PendingRequests (r:1 w:1)
+	/// Proof: Tellor PendingRequests (max_values: Some(1), max_size: Some(10002), added: 10497, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:100 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:100 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:100 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:100 w:0)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// The range of component `r` is `[1, 100]`.
+	fn on_idle(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `451 + r * (100 ±0)`
+		//  Estimated: `11487`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(36_519_870, 11487)
+			// Standard Error: 21_104
+			.saturating_add(Weight::from_parts(19_862_432, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Tellor PruningCursor (r:1 w:1)
+	/// Proof: Tellor PruningCursor (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor RetentionPeriods (r:1001 w:0)
+	/// Proof: Tellor RetentionPeriods (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:1000 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor FirstRetainedIndex (r:1000 w:1000)
+	/// Proof: Tellor FirstRetainedIndex (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:1000 w:1000)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:1000 w:1000)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:0 w:1000)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeaves (r:0 w:1000)
+	/// Proof: Tellor CommitmentLeaves (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tellor ValueCaches (r:1000 w:1000)
+	/// Proof: Tellor ValueCaches (max_values: None, max_size: Some(2783), added: 5258, mode: MaxEncodedLen)
+	/// The range of component `q` is `[0, 1000]`.
+	/// The range of component `p` is `[0, 1000]`.
+	fn prune_values(q: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `132 + p * (62 ±0) + q * (1484 ±0)`
+		//  Estimated: `1517 + p * (2535 ±0) + q * (12784 ±0)`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 1517)
+			// Standard Error: 9_218
+			.saturating_add(Weight::from_parts(12_367_905, 0).saturating_mul(q.into()))
+			// Standard Error: 9_218
+			.saturating_add(Weight::from_parts(8_271_006, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(q.into())))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(q.into())))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 12784).saturating_mul(q.into()))
+			.saturating_add(Weight::from_parts(0, 2535).saturating_mul(p.into()))
+	}
+	/// Storage: Tellor CommitPruningCursor (r:1 w:1)
+	/// Proof: Tellor CommitPruningCursor (max_values: Some(1), max_size: Some(64), added: 559, mode: MaxEncodedLen)
+	/// Storage: Tellor Commits (r:1001 w:1000)
+	/// Proof: Tellor Commits (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitRevealQueries (r:1000 w:0)
+	/// Proof: Tellor CommitRevealQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 1000]`.
+	fn prune_commits(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `145 + c * (112 ±0)`
+		//  Estimated: `1549 + c * (2587 ±0)`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 1549)
+			// Standard Error: 4_512
+			.saturating_add(Weight::from_parts(6_873_209, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2587).saturating_mul(c.into()))
+	}
+	/// Storage: Tellor ReportedTimestampsByIndex (r:101 w:100)
+	/// Proof Skipped: Tellor ReportedTimestampsByIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tellor ReportedValuesByTimestamp (r:100 w:100)
+	/// Proof Skipped: Tellor ReportedValuesByTimestamp (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tellor ReportedValues (r:100 w:100)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:0 w:100)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:100 w:0)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReportedTimestamp (r:1 w:0)
+	/// Proof Skipped: Tellor LastReportedTimestamp (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tellor MigrationInProgress (r:0 w:1)
+	/// Proof: Tellor MigrationInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn migrate_values(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `179 + n * (421 ±0)`
+		//  Estimated: `3644 + n * (5276 ±0)`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_412_508, 3644)
+			// Standard Error: 6_822
+			.saturating_add(Weight::from_parts(17_338_120, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5276).saturating_mul(n.into()))
+	}
+	/// Storage: Tellor MigrationInProgress (r:1 w:0)
+	/// Proof: Tellor MigrationInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor FirstRetainedIndex (r:1 w:0)
+	/// Proof: Tellor FirstRetainedIndex (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:11 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:1 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 10]`.
+	fn get_data_before(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `625 + s * (86 ±0)`
+		//  Estimated: `6249 + s * (2565 ±0)`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(11_974_261, 6249)
+			// Standard Error: 3_104
+			.saturating_add(Weight::from_parts(2_061_735, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2565).saturating_mul(s.into()))
+	}
+	/// Storage: Tellor MigrationInProgress (r:1 w:0)
+	/// Proof: Tellor MigrationInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor FirstRetainedIndex (r:1 w:0)
+	/// Proof: Tellor FirstRetainedIndex (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:114 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:100 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn get_multiple_values_before(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1_204 + n * (350 ±0)`
+		//  Estimated: `36617 + n * (5313 ±0)`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(36_918_447, 36617)
+			// Standard Error: 2_418
+			.saturating_add(Weight::from_parts(2_374_560, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5313).saturating_mul(n.into()))
+	}
+	/// Storage: Tellor LastReported (r:1 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor MigrationInProgress (r:1 w:0)
+	/// Proof: Tellor MigrationInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:10 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:10 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:20 w:0)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Tellor ValueCaches (r:0 w:1)
+	/// Proof: Tellor ValueCaches (max_values: None, max_size: Some(2783), added: 5258, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 10]`.
+	fn set_value_cache(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102 + n * (528 ±0)`
+		//  Estimated: `3509 + n * (5491 ±0)`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_135_811, 3509)
+			// Standard Error: 7_455
+			.saturating_add(Weight::from_parts(7_918_246, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 5491).saturating_mul(n.into()))
+	}
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor LargeValueQueries (r:0 w:1)
+	/// Proof: Tellor LargeValueQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn set_large_values() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `3501`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 3501)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tellor StakerDetails (r:1 w:0)
+	/// Proof: Tellor StakerDetails (max_values: None, max_size: Some(181), added: 2656, mode: MaxEncodedLen)
+	/// Storage: Tellor StakeAmount (r:1 w:0)
+	/// Proof: Tellor StakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor Preimages (r:1 w:1)
+	/// Proof: Tellor Preimages (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Tellor PreimageValues (r:0 w:1)
+	/// Proof: Tellor PreimageValues (max_values: None, max_size: Some(4130), added: 6605, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4096]`.
+	fn note_value(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `295`
+		//  Estimated: `6196`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_180_443, 6196)
+			// Standard Error: 14
+			.saturating_add(Weight::from_parts(1_603, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Tellor Reports (r:2 w:2)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:4 w:4)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tellor TotalStakeAmount (r:1 w:0)
+	/// Proof: Tellor TotalStakeAmount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor RewardRate (r:1 w:1)
+	/// Proof: Tellor RewardRate (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tellor AccumulatedRewardPerShare (r:1 w:0)
+	/// Proof: Tellor AccumulatedRewardPerShare (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tellor TotalRewardDebt (r:1 w:0)
+	/// Proof: Tellor TotalRewardDebt (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:2 w:1)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:1 w:1)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:1)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor LargeValueQueries (r:1 w:0)
+	/// Proof: Tellor LargeValueQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Tellor ValueCaches (r:1 w:0)
+	/// Proof: Tellor ValueCaches (max_values: None, max_size: Some(2783), added: 5258, mode: MaxEncodedLen)
+	/// Storage: Tellor RetractionsByReporter (r:1 w:1)
+	/// Proof: Tellor RetractionsByReporter (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Tellor StakerDetails (r:1 w:1)
+	/// Proof: Tellor StakerDetails (max_values: None, max_size: Some(181), added: 2656, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeafCount (r:1 w:0)
+	/// Proof: Tellor CommitmentLeafCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentLeaves (r:1 w:1)
+	/// Proof: Tellor CommitmentLeaves (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tellor CommitmentNodes (r:64 w:64)
+	/// Proof: Tellor CommitmentNodes (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Tellor PendingCommitment (r:0 w:1)
+	/// Proof: Tellor PendingCommitment (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: Tellor CounterForSubscriptions (r:1 w:0)
+	/// Proof: Tellor CounterForSubscriptions (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tellor RemovalCount (r:1 w:1)
+	/// Proof: Tellor RemovalCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor Removals (r:0 w:2)
+	/// Proof: Tellor Removals (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Tellor ReporterLastBlock (r:1 w:0)
+	/// Proof: Tellor ReporterLastBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `d` is `[1, 100]`.
+	fn retract_value(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6932 + d * (539 ±0)`
+		//  Estimated: `163038 + d * (2529 ±1)`
+		// Minimum execution time: 84_000_000 picoseconds.
+		Weight::from_parts(147_418_250, 163038)
+			// Standard Error: 41_072
+			.saturating_add(Weight::from_parts(8_397_112, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(88_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(81_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2529).saturating_mul(d.into()))
+	}
+	/// Storage: Tellor FinalityQueueRange (r:101 w:100)
+	/// Proof: Tellor FinalityQueueRange (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Tellor FinalityQueue (r:100 w:100)
+	/// Proof: Tellor FinalityQueue (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:100 w:0)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Tellor LastFinalized (r:100 w:100)
+	/// Proof: Tellor LastFinalized (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn finalize_values(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `140 + n * (255 ±0)`
+		//  Estimated: `1501 + n * (2697 ±0)`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(5_212_904, 1501)
+			// Standard Error: 5_318
+			.saturating_add(Weight::from_parts(11_284_517, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2697).saturating_mul(n.into()))
+	}
+	/// Storage: Tellor OpenDisputesOnId (r:1 w:0)
+	/// Proof: Tellor OpenDisputesOnId (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor FirstRetainedIndex (r:1 w:0)
+	/// Proof: Tellor FirstRetainedIndex (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:10 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:10 w:0)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Tellor FundedFeedIntervals (r:1 w:0)
+	/// Proof: Tellor FundedFeedIntervals (max_values: None, max_size: Some(433), added: 2908, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:1 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryHealthStates (r:1 w:1)
+	/// Proof: Tellor QueryHealthStates (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryHealthThresholds (r:0 w:1)
+	/// Proof: Tellor QueryHealthThresholds (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	fn set_health_thresholds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3648`
+		//  Estimated: `27880`
+		// Minimum execution time: 128_000_000 picoseconds.
+		Weight::from_parts(131_000_000, 27880)
+			.saturating_add(RocksDbWeight::get().reads(26_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Tellor HealthCheckCursor (r:1 w:1)
+	/// Proof: Tellor HealthCheckCursor (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryHealthThresholds (r:11 w:0)
+	/// Proof: Tellor QueryHealthThresholds (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: Tellor OpenDisputesOnId (r:10 w:0)
+	/// Proof: Tellor OpenDisputesOnId (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:10 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor FirstRetainedIndex (r:10 w:0)
+	/// Proof: Tellor FirstRetainedIndex (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:100 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor FundedFeedIntervals (r:10 w:0)
+	/// Proof: Tellor FundedFeedIntervals (max_values: None, max_size: Some(433), added: 2908, mode: MaxEncodedLen)
+	/// Storage: Tellor LastReported (r:10 w:0)
+	/// Proof: Tellor LastReported (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tellor QueryHealthStates (r:10 w:10)
+	/// Proof: Tellor QueryHealthStates (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 10]`.
+	fn check_health(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `172 + n * (2443 ±0)`
+		//  Estimated: `1517 + n * (27880 ±0)`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(4_652_109, 1517)
+			// Standard Error: 48_213
+			.saturating_add(Weight::from_parts(112_904_318, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((17_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 27880).saturating_mul(n.into()))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor EmergencyValues (r:0 w:1)
+	/// Proof: Tellor EmergencyValues (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	fn set_emergency_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1493`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 1493)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tellor DerivedFeeds (r:2 w:1)
+	/// Proof: Tellor DerivedFeeds (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn set_derived_feed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `7640`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 7640)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Tellor CounterForSubscriptions (r:1 w:1)
+	/// Proof: Tellor CounterForSubscriptions (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tellor RemovalCount (r:1 w:0)
+	/// Proof: Tellor RemovalCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor SubscriptionCount (r:1 w:1)
+	/// Proof: Tellor SubscriptionCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tellor SubscriptionDeposits (r:0 w:1)
+	/// Proof: Tellor SubscriptionDeposits (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Tellor Subscriptions (r:1 w:1)
+	/// Proof: Tellor Subscriptions (max_values: None, max_size: Some(722), added: 3197, mode: MaxEncodedLen)
+	fn subscribe() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Tellor Subscriptions (r:1 w:1)
+	/// Proof: Tellor Subscriptions (max_values: None, max_size: Some(722), added: 3197, mode: MaxEncodedLen)
+	/// Storage: Tellor CounterForSubscriptions (r:1 w:1)
+	/// Proof: Tellor CounterForSubscriptions (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tellor SubscriptionDeposits (r:1 w:1)
+	/// Proof: Tellor SubscriptionDeposits (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn unsubscribe() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `424`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Tellor PushCursor (r:1 w:1)
+	/// Proof: Tellor PushCursor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tellor Subscriptions (r:101 w:100)
+	/// Proof: Tellor Subscriptions (max_values: None, max_size: Some(722), added: 3197, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:100 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:100 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:100 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:100 w:0)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: Tellor RemovalCount (r:100 w:0)
+	/// Proof: Tellor RemovalCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor Removals (r:100 w:0)
+	/// Proof: Tellor Removals (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: System Account (r:400 w:400)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ParachainInfo ParachainId (r:1 w:0)
+	/// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	/// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `s` is `[0, 100]`.
+	fn push_values(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `224 + s * (1251 ±0)`
+		//  Estimated: `1493 + s * (23935 ±0)`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(12_318_420, 1493)
+			// Standard Error: 31_562
+			.saturating_add(Weight::from_parts(171_604_817, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((11_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 23935).saturating_mul(s.into()))
+	}
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedTimestampCount (r:1 w:0)
+	/// Proof: Tellor ReportedTimestampCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValues (r:1 w:0)
+	/// Proof: Tellor ReportedValues (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tellor ReportedValueData (r:1 w:0)
+	/// Proof: Tellor ReportedValueData (max_values: None, max_size: Some(273), added: 2748, mode: MaxEncodedLen)
+	/// Storage: Tellor Reports (r:1 w:0)
+	/// Proof: Tellor Reports (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: ParachainInfo ParachainId (r:1 w:0)
+	/// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	/// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tellor LargeValueQueries (r:1 w:0)
+	/// Proof: Tellor LargeValueQueries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Tellor PreimageValues (r:1 w:0)
+	/// Proof: Tellor PreimageValues (max_values: None, max_size: Some(4130), added: 6605, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 4096]`.
+	fn query_value(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1388 + n * (1 ±0)`
+		//  Estimated: `7595`
+		// Minimum execution time: 98_000_000 picoseconds.
+		Weight::from_parts(100_412_260, 7595)
+			// Standard Error: 21
+			.saturating_add(Weight::from_parts(2_716, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Tellor Preimages (r:1 w:1)
+	/// Proof: Tellor Preimages (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tellor PreimageValues (r:0 w:1)
+	/// Proof: Tellor PreimageValues (max_values: None, max_size: Some(4130), added: 6605, mode: MaxEncodedLen)
+	fn unnote_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `486`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
@@ -177,6 +177,35 @@ pub(crate) fn transact<T: Config>(
 	}
 }
 
+/// Constructs XCM message for delivering a value to a sibling parachain, by remote transact of the
+/// receiving call.
+/// # Arguments
+/// * `fees` - The fees withdrawn on the receiving parachain to pay for execution.
+/// * `weight` - The maximum weight of the receiving call.
+/// * `call` - The encoded receiving call.
+/// # Returns
+/// A XCM message for remote transact, with any surplus fees deposited back to this pallet.
+pub(crate) fn deliver<T: Config>(fees: &MultiAsset, weight: Weight, call: Vec<u8>) -> Xcm<()> {
+	Xcm(vec![
+		WithdrawAsset(fees.clone().into()),
+		BuyExecution { fees: fees.clone(), weight_limit: Unlimited },
+		// Dispatch with the location of this pallet as origin, so the receiver can authenticate it
+		Transact {
+			origin_kind: OriginKind::Xcm,
+			require_weight_at_most: weight,
+			call: call.into(),
+		},
+		RefundSurplus,
+		DepositAsset {
+			assets: Wild(AllCounted(1)),
+			beneficiary: MultiLocation::new(
+				1,
+				X2(Parachain(T::ParachainId::get()), PalletInstance(Pallet::<T>::index() as u8)),
+			),
+		},
+	])
+}
+
 pub(crate) fn weight_to_fee<T: Config>(weight: Weight) -> u128 {
 	(weight.ref_time() as u128).saturating_mul(T::XcmWeightToAsset::get())
 }
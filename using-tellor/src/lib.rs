@@ -6,7 +6,7 @@ pub use pallet::*;
 pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
-	use tellor::{traits::UsingTellor, QueryId, HOURS, MINUTES, U256};
+	use tellor::{traits::UsingTellor, QueryId, ReadPolicy, HOURS, MINUTES, U256};
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -91,21 +91,21 @@ pub mod pallet {
 
 	const FIFTEEN_MINUTES: u64 = 15 * MINUTES;
 	const ONE_DAY: u64 = 24 * HOURS;
+	const READ_POLICY: ReadPolicy = ReadPolicy {
+		// Retrieve data at least 15 minutes old to allow time for disputes
+		dispute_buffer: FIFTEEN_MINUTES,
+		// Check that the data is not too old
+		max_age: ONE_DAY,
+		min_reporters: 1,
+		max_open_disputes: u32::MAX,
+	};
 
 	impl<T: Config> Pallet<T> {
 		fn get_price(query_id: QueryId) -> Option<U256> {
-			let timestamp = T::Tellor::now();
-			// Retrieve data at least 15 minutes old to allow time for disputes
-			T::Tellor::get_data_before(query_id, timestamp.saturating_sub(FIFTEEN_MINUTES.into()))
-				.and_then(|(value, timestamp_retrieved)| {
-					// Check that the data is not too old
-					if timestamp.saturating_sub(timestamp_retrieved) < ONE_DAY.into() {
-						// Use the helper function to parse the value to an unsigned integer
-						T::Tellor::bytes_to_uint(value)
-					} else {
-						None
-					}
-				})
+			T::Tellor::get_safe_value(query_id, READ_POLICY)
+				.ok()
+				// Use the helper function to parse the value to an unsigned integer
+				.and_then(|safe_value| T::Tellor::bytes_to_uint(safe_value.value))
 		}
 	}
 }